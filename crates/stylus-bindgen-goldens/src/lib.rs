@@ -0,0 +1,125 @@
+//! The golden outputs of `stylus-bindgen/tests/expected`, compiled against `stylus-interfaces`
+//! and stylus-sdk.
+//!
+//! The golden tests only compare text; this crate fails to build when a golden stops compiling,
+//! and its tests run the generated code.
+
+// Generated names keep the ABI's parameter names and the `__0x..` selector suffixes.
+#![allow(non_snake_case)]
+
+use alloy_primitives::U256;
+use core::borrow::Borrow;
+
+pub use stylus_interfaces;
+pub use stylus_sdk;
+
+/// The `crate::TokenId` of `erc721_types.rs` (`--type '*.tokenId=crate::TokenId'`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TokenId(pub U256);
+
+impl Borrow<U256> for TokenId {
+    fn borrow(&self) -> &U256 {
+        &self.0
+    }
+}
+
+impl From<U256> for TokenId {
+    fn from(value: U256) -> Self {
+        Self(value)
+    }
+}
+
+#[rustfmt::skip]
+#[path = "../../stylus-bindgen/tests/expected/erc721_types.rs"]
+pub mod erc721_types;
+
+#[rustfmt::skip]
+#[path = "../../stylus-bindgen/tests/expected/erc721_overloads.rs"]
+pub mod erc721_overloads;
+
+#[rustfmt::skip]
+#[path = "../../stylus-bindgen/tests/expected/exchange.rs"]
+pub mod exchange;
+
+#[rustfmt::skip]
+#[path = "../../stylus-bindgen/tests/expected/keywords.rs"]
+pub mod keywords;
+
+#[rustfmt::skip]
+#[path = "../../stylus-bindgen/tests/expected/pairs_lazy.rs"]
+pub mod pairs_lazy;
+
+#[rustfmt::skip]
+#[path = "../../stylus-bindgen/tests/expected/rates.rs"]
+pub mod rates;
+
+#[rustfmt::skip]
+#[path = "../../stylus-bindgen/tests/expected/router.rs"]
+pub mod router;
+
+#[cfg(feature = "export-abi")]
+#[rustfmt::skip]
+#[path = "../../stylus-bindgen/tests/expected/erc721_implement.rs"]
+pub mod erc721_implement;
+
+/// Every function of the implementation skeleton reverts.
+#[cfg(feature = "export-abi")]
+mod erc721_reverts {
+    use crate::erc721_implement::{Erc721, Erc721Interface};
+    use stylus_sdk::abi::Bytes;
+    use stylus_sdk::alloy_primitives::{Address, U256};
+
+    impl Erc721Interface for Erc721 {
+        fn approve__0x095ea7b3(&mut self, _: Address, _: U256) -> Result<(), Vec<u8>> {
+            Err(Vec::new())
+        }
+
+        fn balance_of__0x70a08231(&self, _: Address) -> Result<U256, Vec<u8>> {
+            Err(Vec::new())
+        }
+
+        fn get_approved__0x081812fc(&self, _: U256) -> Result<Address, Vec<u8>> {
+            Err(Vec::new())
+        }
+
+        fn is_approved_for_all__0xe985e9c5(&self, _: Address, _: Address) -> Result<bool, Vec<u8>> {
+            Err(Vec::new())
+        }
+
+        fn owner_of__0x6352211e(&self, _: U256) -> Result<Address, Vec<u8>> {
+            Err(Vec::new())
+        }
+
+        fn safe_transfer_from__0x42842e0e(
+            &mut self,
+            _: Address,
+            _: Address,
+            _: U256,
+        ) -> Result<(), Vec<u8>> {
+            Err(Vec::new())
+        }
+
+        fn safe_transfer_from__0xb88d4fde(
+            &mut self,
+            _: Address,
+            _: Address,
+            _: U256,
+            _: Bytes,
+        ) -> Result<(), Vec<u8>> {
+            Err(Vec::new())
+        }
+
+        fn set_approval_for_all__0xa22cb465(&mut self, _: Address, _: bool) -> Result<(), Vec<u8>> {
+            Err(Vec::new())
+        }
+
+        fn transfer_from__0x23b872dd(
+            &mut self,
+            _: Address,
+            _: Address,
+            _: U256,
+        ) -> Result<(), Vec<u8>> {
+            Err(Vec::new())
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! A `--type` whose Rust type lacks the conversions the bindings need fails to compile, at the
+//! checks next to the imports.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// `cargo check`s `erc721_types.rs` in a scratch crate, with `token_id` as `crate::TokenId`.
+fn check(dir: &Path, token_id: &str) -> Output {
+    let manifest = env!("CARGO_MANIFEST_DIR");
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"bad-type\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\nstylus-bindgen-goldens = {{ path = {:?} }}\n\n[workspace]\n",
+            manifest
+        ),
+    )
+    .unwrap();
+    // Resolve the same versions as the workspace, without the network.
+    let lock = Path::new(manifest).join("../../Cargo.lock");
+    if lock.exists() {
+        fs::copy(lock, dir.join("Cargo.lock")).unwrap();
+    }
+    let golden = fs::read_to_string(
+        Path::new(manifest).join("../stylus-bindgen/tests/expected/erc721_types.rs"),
+    )
+    .unwrap();
+    fs::write(
+        dir.join("src/lib.rs"),
+        format!(
+            "{}\nuse stylus_bindgen_goldens::{{stylus_interfaces, stylus_sdk}};\n\n{}\n",
+            golden, token_id
+        ),
+    )
+    .unwrap();
+    Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args(["check", "--offline", "--quiet"])
+        .current_dir(dir)
+        .env("CARGO_TARGET_DIR", dir.join("target"))
+        .output()
+        .expect("Failed to execute cargo")
+}
+
+#[test]
+fn type_without_conversions_fails_at_the_checks() {
+    let dir = std::env::temp_dir().join(format!("stylus_bindgen_bad_type_{}", std::process::id()));
+    let good = check(&dir, "pub use stylus_bindgen_goldens::TokenId;");
+    assert!(
+        good.status.success(),
+        "{}",
+        String::from_utf8_lossy(&good.stderr)
+    );
+
+    let bad = check(
+        &dir,
+        "pub struct TokenId(pub stylus_sdk::alloy_primitives::U256);",
+    );
+    let stderr = String::from_utf8_lossy(&bad.stderr);
+    assert!(!bad.status.success());
+    // The encode and decode checks of `crate::TokenId` report it.
+    let locations: Vec<&str> = stderr
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("--> src/lib.rs:"))
+        .map(|location| location.split(':').next().unwrap())
+        .collect();
+    assert!(locations.contains(&"9"), "{}", stderr);
+    assert!(locations.contains(&"10"), "{}", stderr);
+    fs::remove_dir_all(dir).unwrap();
+}
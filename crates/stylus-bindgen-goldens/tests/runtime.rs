@@ -0,0 +1,77 @@
+//! The generated code of the goldens, run on an `InProcessTransport`.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use alloy_primitives::{Address, U256};
+use stylus_bindgen_goldens::{pairs_lazy, router};
+use stylus_interfaces::transport::{CallKind, InProcessTransport};
+use stylus_sdk::alloy_sol_types::{sol_data, SolType};
+
+const ROUTER: Address = Address::repeat_byte(0x11);
+const HOOK: Address = Address::repeat_byte(0x22);
+const SELECTOR: [u8; 4] = [0x12, 0x34, 0x56, 0x78];
+
+#[test]
+fn function_values_call_and_static_call() {
+    let mut transport = InProcessTransport::new();
+    transport.register(ROUTER, |_, request| {
+        assert_eq!(
+            request.calldata[..4],
+            router::selectors::hook_of__0xc8d5d871
+        );
+        // A `function` is its address and selector, left-aligned in one word.
+        let mut word = [0; 32];
+        word[..20].copy_from_slice(HOOK.as_slice());
+        word[20..24].copy_from_slice(&SELECTOR);
+        Ok(word.to_vec())
+    });
+    let requests = Rc::new(RefCell::new(Vec::new()));
+    let seen = requests.clone();
+    transport.register(HOOK, move |_, request| {
+        seen.borrow_mut()
+            .push((request.kind, request.calldata.to_vec()));
+        Ok(vec![0x2a])
+    });
+
+    let hook = router::Contract::with_transport(ROUTER, &transport)
+        .hook_of__0xc8d5d871(Address::ZERO)
+        .unwrap();
+    assert_eq!(
+        hook,
+        router::ExternalFunction {
+            address: HOOK,
+            selector: SELECTOR
+        }
+    );
+    assert_eq!(hook.call(&transport, &[1]).unwrap(), [0x2a]);
+    assert_eq!(hook.static_call(&transport, &[2]).unwrap(), [0x2a]);
+    assert_eq!(
+        *requests.borrow(),
+        [
+            (CallKind::Call, vec![0x12, 0x34, 0x56, 0x78, 1]),
+            (CallKind::StaticCall, vec![0x12, 0x34, 0x56, 0x78, 2]),
+        ]
+    );
+}
+
+#[test]
+fn lazy_outputs_after_a_static_array() {
+    type Returns = (
+        sol_data::FixedArray<sol_data::Uint<256>, 2>,
+        sol_data::Array<sol_data::Uint<256>>,
+        sol_data::Uint<256>,
+    );
+    let data = Returns::abi_encode_params(&(
+        [U256::from(1), U256::from(2)],
+        vec![U256::from(3), U256::from(4), U256::from(5)],
+        U256::from(6),
+    ));
+    let returns = pairs_lazy::decode_pair_of__0xa7465bdb_returns(data).unwrap();
+    assert_eq!(returns.pair().unwrap(), [U256::from(1), U256::from(2)]);
+    assert_eq!(
+        returns.ids().unwrap().to_vec().unwrap(),
+        [U256::from(3), U256::from(4), U256::from(5)]
+    );
+    assert_eq!(returns.total().unwrap(), U256::from(6));
+}
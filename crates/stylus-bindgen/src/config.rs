@@ -0,0 +1,363 @@
+//! `sipb.toml`: a project's binding setup, so that `stylus-bindgen` without arguments
+//! regenerates every binding it lists.
+//!
+//! Each `[[target]]` takes the generation flags as keys, and relative paths are resolved
+//! against the file's directory:
+//!
+//! ```toml
+//! [[target]]
+//! input = "abis/erc20.json"
+//! output = "src/erc20.rs"
+//! lazy = ["balanceOf"]
+//! types = { "*.tokenId" = "crate::TokenId" }
+//!
+//! [[target]]
+//! input-dir = "abis/partners"
+//! out-dir = "src/partners"
+//! naming = "overloads"
+//! modules = { IPartnerVault = "vault" }
+//!
+//! [lint]
+//! unnamed-param = "allow"
+//! ```
+
+use crate::internal_types::EnumVariants;
+use crate::overrides::Override;
+use crate::{lint, Dependency, Mode, ModuleName, Naming, Target};
+use clap::ValueEnum;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Configuration read when no file is given.
+pub(crate) const FILE: &str = "sipb.toml";
+
+const TARGET_KEYS: &[&str] = &[
+    "input",
+    "output",
+    "input-dir",
+    "out-dir",
+    "lib",
+    "crate",
+    "interfaces-dep",
+    "name",
+    "mode",
+    "naming",
+    "modules",
+    "delegate",
+    "call-context",
+    "lazy",
+    "include",
+    "exclude",
+    "only-view",
+    "subset",
+    "types",
+    "enums",
+    "pack-handles",
+    "rustfmt",
+];
+
+pub(crate) struct Config {
+    /// Where the configuration was read from.
+    pub(crate) path: PathBuf,
+    pub(crate) targets: Vec<Target>,
+    pub(crate) lint: lint::Config,
+}
+
+fn string<'a>(key: &str, item: &'a toml_edit::Item) -> anyhow::Result<&'a str> {
+    item.as_str()
+        .ok_or_else(|| anyhow::anyhow!("`{}` must be a string", key))
+}
+
+fn boolean(key: &str, item: &toml_edit::Item) -> anyhow::Result<bool> {
+    item.as_bool()
+        .ok_or_else(|| anyhow::anyhow!("`{}` must be true or false", key))
+}
+
+fn strings(key: &str, item: &toml_edit::Item) -> anyhow::Result<Vec<String>> {
+    let array = item
+        .as_array()
+        .ok_or_else(|| anyhow::anyhow!("`{}` must be an array of strings", key))?;
+    array
+        .iter()
+        .map(|value| {
+            value
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| anyhow::anyhow!("`{}` must be an array of strings", key))
+        })
+        .collect()
+}
+
+/// `types = { KEY = "TYPE" }`, the table form of `--type KEY=TYPE`.
+fn types(item: &toml_edit::Item) -> anyhow::Result<Vec<Override>> {
+    let table = item
+        .as_table_like()
+        .ok_or_else(|| anyhow::anyhow!("`types` must be a table of Rust types"))?;
+    table
+        .iter()
+        .map(|(key, item)| Override::new(key, string(key, item)?))
+        .collect()
+}
+
+/// `enums = { NAME = ["A", ..] }`, the table form of `--enum NAME=A,..`.
+fn enums(item: &toml_edit::Item) -> anyhow::Result<Vec<EnumVariants>> {
+    let table = item
+        .as_table_like()
+        .ok_or_else(|| anyhow::anyhow!("`enums` must be a table of variant arrays"))?;
+    table
+        .iter()
+        .map(|(name, item)| EnumVariants::new(name, &strings(name, item)?))
+        .collect()
+}
+
+/// `modules = { STEM = "MODULE" }`, the table form of `--module STEM=MODULE`.
+fn modules(item: &toml_edit::Item) -> anyhow::Result<Vec<ModuleName>> {
+    let table = item
+        .as_table_like()
+        .ok_or_else(|| anyhow::anyhow!("`modules` must be a table of module names"))?;
+    table
+        .iter()
+        .map(|(stem, item)| ModuleName::new(stem, string(stem, item)?))
+        .collect()
+}
+
+/// One `[[target]]`, with the same rules clap applies to the flags.
+fn parse_target(table: &toml_edit::Table, base: &Path) -> anyhow::Result<Target> {
+    let mut target = Target::default();
+    for (key, item) in table.iter() {
+        match key {
+            "input" => target.input = Some(base.join(string(key, item)?)),
+            "output" => target.output = Some(base.join(string(key, item)?)),
+            "input-dir" => target.input_dir = Some(base.join(string(key, item)?)),
+            "out-dir" => target.out_dir = Some(base.join(string(key, item)?)),
+            "lib" => target.lib = boolean(key, item)?,
+            "crate" => target.crate_name = Some(string(key, item)?.to_string()),
+            "interfaces-dep" => {
+                target.interfaces_dep = Some(Dependency::parse(string(key, item)?)?)
+            }
+            "name" => target.name = Some(string(key, item)?.to_string()),
+            "mode" => {
+                target.mode = Mode::from_str(string(key, item)?, false)
+                    .map_err(|_| anyhow::anyhow!("`mode` must be \"bindings\" or \"implement\""))?
+            }
+            "naming" => {
+                target.naming = Naming::from_str(string(key, item)?, false).map_err(|_| {
+                    anyhow::anyhow!("`naming` must be \"selector\" or \"overloads\"")
+                })?
+            }
+            "modules" => target.modules = modules(item)?,
+            "delegate" => target.delegate = boolean(key, item)?,
+            "call-context" => target.call_context = boolean(key, item)?,
+            "lazy" => target.lazy = strings(key, item)?,
+            "include" => target.include = strings(key, item)?,
+            "exclude" => target.exclude = strings(key, item)?,
+            "only-view" => target.only_view = boolean(key, item)?,
+            "subset" => target.subset = boolean(key, item)?,
+            "types" => target.types = types(item)?,
+            "enums" => target.enums = enums(item)?,
+            "pack-handles" => target.pack_handles = boolean(key, item)?,
+            "rustfmt" => target.rustfmt = boolean(key, item)?,
+            _ => anyhow::bail!("unknown key `{}` (known: {})", key, TARGET_KEYS.join(", ")),
+        }
+    }
+    match (&target.input, &target.input_dir) {
+        (Some(_), Some(_)) => anyhow::bail!("`input` and `input-dir` are exclusive"),
+        (None, None) => anyhow::bail!("needs `input` or `input-dir`"),
+        (Some(_), None) if target.output.is_none() => anyhow::bail!("`input` needs `output`"),
+        (None, Some(_)) if target.out_dir.is_none() => {
+            anyhow::bail!("`input-dir` needs `out-dir`")
+        }
+        _ => {}
+    }
+    let single = target.input.is_some();
+    if single
+        && (target.out_dir.is_some()
+            || target.lib
+            || target.crate_name.is_some()
+            || !target.modules.is_empty())
+    {
+        anyhow::bail!("`out-dir`, `lib`, `crate` and `modules` only apply to `input-dir`");
+    }
+    if !single && (target.output.is_some() || target.name.is_some()) {
+        anyhow::bail!("`output` and `name` only apply to `input`");
+    }
+    if target.lib && target.crate_name.is_some() {
+        anyhow::bail!("`lib` and `crate` are exclusive");
+    }
+    if target.interfaces_dep.is_some() && target.crate_name.is_none() {
+        anyhow::bail!("`interfaces-dep` needs `crate`");
+    }
+    Ok(target)
+}
+
+impl Config {
+    /// Parses a `sipb.toml` whose relative paths are relative to `base`.
+    pub(crate) fn parse(toml: &str, base: &Path) -> anyhow::Result<Self> {
+        let document: toml_edit::Document<String> = toml.parse()?;
+        let root = document.as_table();
+        for (key, _) in root.iter() {
+            if key != "target" && key != "lint" {
+                anyhow::bail!("unknown table `{}` (known: target, lint)", key);
+            }
+        }
+        let mut targets = Vec::new();
+        if let Some(item) = root.get("target") {
+            let tables = item
+                .as_array_of_tables()
+                .ok_or_else(|| anyhow::anyhow!("`target` must be written [[target]]"))?;
+            for (index, table) in tables.iter().enumerate() {
+                let target = parse_target(table, base)
+                    .map_err(|e| anyhow::anyhow!("[[target]] {}: {}", index + 1, e))?;
+                targets.push(target);
+            }
+        }
+        Ok(Self {
+            path: PathBuf::new(),
+            targets,
+            lint: lint::Config::from_table(root.get("lint"))?,
+        })
+    }
+}
+
+/// Loads `path`, or `sipb.toml` in the current directory; `None` when neither is given.
+pub(crate) fn load(path: Option<&Path>) -> anyhow::Result<Option<Config>> {
+    let path = match path {
+        Some(path) => path,
+        None if Path::new(FILE).exists() => Path::new(FILE),
+        None => return Ok(None),
+    };
+    let toml =
+        fs::read_to_string(path).map_err(|e| anyhow::anyhow!("{}: {}", path.display(), e))?;
+    let base = path.parent().unwrap_or(Path::new(""));
+    let mut config =
+        Config::parse(&toml, base).map_err(|e| anyhow::anyhow!("{}: {}", path.display(), e))?;
+    config.path = path.to_path_buf();
+    Ok(Some(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Config;
+    use crate::internal_types::EnumVariants;
+    use crate::overrides::Override;
+    use crate::{Dependency, Mode, ModuleName, Naming};
+    use std::path::{Path, PathBuf};
+
+    fn parse_error(toml: &str) -> String {
+        Config::parse(toml, Path::new(""))
+            .err()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn targets_resolve_paths_against_the_file() {
+        let config = Config::parse(
+            r#"
+[[target]]
+input = "abis/erc20.json"
+output = "src/erc20.rs"
+name = "Token"
+lazy = ["balanceOf"]
+include = ["balanceOf", "transfer*"]
+subset = true
+rustfmt = true
+types = { "*.tokenId" = "crate::TokenId" }
+enums = { Side = ["Buy", "Sell"] }
+pack-handles = true
+
+[[target]]
+input-dir = "abis/partners"
+out-dir = "partners"
+crate = "partner-bindings"
+interfaces-dep = "path=../stylus-interfaces"
+mode = "bindings"
+naming = "overloads"
+modules = { IPartnerVault = "vault" }
+call-context = true
+"#,
+            Path::new("project"),
+        )
+        .unwrap();
+        assert_eq!(config.targets.len(), 2);
+        let single = &config.targets[0];
+        assert_eq!(single.input, Some(PathBuf::from("project/abis/erc20.json")));
+        assert_eq!(single.output, Some(PathBuf::from("project/src/erc20.rs")));
+        assert_eq!(single.name.as_deref(), Some("Token"));
+        assert_eq!(single.lazy, ["balanceOf"]);
+        assert_eq!(single.include, ["balanceOf", "transfer*"]);
+        assert!(single.subset && !single.only_view);
+        assert_eq!(
+            single.types,
+            [Override::new("*.tokenId", "crate::TokenId").unwrap()]
+        );
+        assert_eq!(
+            single.enums,
+            [EnumVariants::new("Side", &["Buy".to_string(), "Sell".to_string()]).unwrap()]
+        );
+        assert!(single.rustfmt && single.pack_handles);
+        let batch = &config.targets[1];
+        assert_eq!(batch.out_dir, Some(PathBuf::from("project/partners")));
+        assert_eq!(batch.crate_name.as_deref(), Some("partner-bindings"));
+        assert_eq!(
+            batch.interfaces_dep,
+            Some(Dependency::Path("../stylus-interfaces".to_string()))
+        );
+        assert_eq!(batch.mode, Mode::Bindings);
+        assert!(batch.call_context && !batch.pack_handles);
+        assert_eq!(batch.naming, Naming::Overloads);
+        assert_eq!(
+            batch.modules,
+            [ModuleName::new("IPartnerVault", "vault").unwrap()]
+        );
+        assert_eq!(single.naming, Naming::Selector);
+    }
+
+    #[test]
+    fn lint_levels_share_the_file() {
+        let config = Config::parse("[lint]\nunnamed-param = \"deny\"\n", Path::new("")).unwrap();
+        assert!(config.targets.is_empty());
+        assert_eq!(
+            config.lint.level(crate::lint::UNNAMED_PARAM),
+            crate::lint::Level::Deny
+        );
+    }
+
+    #[test]
+    fn invalid_targets_are_rejected() {
+        assert_eq!(
+            parse_error("[[target]]\ninput = \"a.json\"\n"),
+            "[[target]] 1: `input` needs `output`"
+        );
+        assert_eq!(
+            parse_error("[[target]]\ninput = \"a.json\"\noutput = \"a.rs\"\nout = \"b\"\n"),
+            "[[target]] 1: unknown key `out` (known: input, output, input-dir, out-dir, lib, crate, interfaces-dep, name, mode, naming, modules, delegate, call-context, lazy, include, exclude, only-view, subset, types, enums, pack-handles, rustfmt)"
+        );
+        assert_eq!(
+            parse_error("[[target]]\ninput-dir = \"abis\"\nout-dir = \"src\"\nname = \"X\"\n"),
+            "[[target]] 1: `output` and `name` only apply to `input`"
+        );
+        assert_eq!(
+            parse_error("[[target]]\ninput = \"a.json\"\noutput = \"a.rs\"\ndelegate = \"yes\"\n"),
+            "[[target]] 1: `delegate` must be true or false"
+        );
+        assert_eq!(
+            parse_error("[[target]]\ninput-dir = \"abis\"\nout-dir = \"src\"\nmodules = { IERC20 = \"erc-20\" }\n"),
+            "[[target]] 1: `IERC20`: `erc-20` is not a module name"
+        );
+        assert_eq!(
+            parse_error(
+                "[[target]]\ninput-dir = \"abis\"\nout-dir = \"src\"\ninterfaces-dep = \"0.1\"\n"
+            ),
+            "[[target]] 1: `interfaces-dep` needs `crate`"
+        );
+        assert_eq!(
+            parse_error("[target]\ninput = \"a.json\"\n"),
+            "`target` must be written [[target]]"
+        );
+        assert_eq!(
+            parse_error("[chains]\n"),
+            "unknown table `chains` (known: target, lint)"
+        );
+    }
+}
@@ -0,0 +1,395 @@
+//! `stylus-bindgen conforms`: checks an ABI against one of the standard interfaces in `abis/`.
+//!
+//! Extra functions and events are fine; only what the standard declares is checked.
+
+use crate::Format;
+use alloy_json_abi::{Event, Function, JsonAbi, StateMutability};
+use clap::ValueEnum;
+use serde_json::json;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(clap::Args)]
+pub(crate) struct Args {
+    /// ABI JSON to check
+    abi: PathBuf,
+
+    /// Standard interface to check against
+    #[arg(long, value_enum)]
+    to: Standard,
+
+    /// Report format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Standard {
+    Erc20,
+    Erc721,
+    Erc1155,
+    Ierc165,
+}
+
+impl Standard {
+    pub(crate) const ALL: &'static [Self] =
+        &[Self::Erc20, Self::Erc721, Self::Erc1155, Self::Ierc165];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Erc20 => "erc20",
+            Self::Erc721 => "erc721",
+            Self::Erc1155 => "erc1155",
+            Self::Ierc165 => "ierc165",
+        }
+    }
+
+    /// The standard's ABI, embedded from `abis/` so the check works from any directory.
+    pub(crate) fn abi(self) -> JsonAbi {
+        let json = match self {
+            Self::Erc20 => include_str!("../../../abis/erc20.json"),
+            Self::Erc721 => include_str!("../../../abis/erc721.json"),
+            Self::Erc1155 => include_str!("../../../abis/erc1155.json"),
+            Self::Ierc165 => include_str!("../../../abis/ierc165.json"),
+        };
+        serde_json::from_str(json).expect("abis/ must hold valid ABI JSON")
+    }
+}
+
+/// One way an ABI falls short of a standard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Issue {
+    /// No function with the standard's signature; `similar` lists same-named overloads.
+    MissingFunction {
+        signature: String,
+        similar: Vec<String>,
+    },
+    /// The function exists but returns other types, e.g. USDT's `transfer` without `bool`.
+    WrongReturns {
+        signature: String,
+        expected: String,
+        found: String,
+    },
+    /// The function is less strict than the standard, so callers' calls may fail.
+    WrongMutability {
+        signature: String,
+        expected: StateMutability,
+        found: StateMutability,
+    },
+    MissingEvent {
+        signature: String,
+    },
+    /// The event exists but indexes other parameters, so its topics differ.
+    WrongIndexed {
+        signature: String,
+        expected: String,
+        found: String,
+    },
+}
+
+impl Issue {
+    /// Stable identifier used in JSON reports.
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::MissingFunction { .. } => "missing_function",
+            Self::WrongReturns { .. } => "wrong_returns",
+            Self::WrongMutability { .. } => "wrong_mutability",
+            Self::MissingEvent { .. } => "missing_event",
+            Self::WrongIndexed { .. } => "wrong_indexed",
+        }
+    }
+
+    fn signature(&self) -> &str {
+        match self {
+            Self::MissingFunction { signature, .. }
+            | Self::WrongReturns { signature, .. }
+            | Self::WrongMutability { signature, .. }
+            | Self::MissingEvent { signature }
+            | Self::WrongIndexed { signature, .. } => signature,
+        }
+    }
+
+    /// What the standard wants and what the ABI has, for mismatches.
+    fn expected_found(&self) -> Option<(String, String)> {
+        match self {
+            Self::WrongReturns {
+                expected, found, ..
+            }
+            | Self::WrongIndexed {
+                expected, found, ..
+            } => Some((expected.clone(), found.clone())),
+            Self::WrongMutability {
+                expected, found, ..
+            } => Some((
+                mutability_name(*expected).to_string(),
+                mutability_name(*found).to_string(),
+            )),
+            Self::MissingFunction { .. } | Self::MissingEvent { .. } => None,
+        }
+    }
+}
+
+impl core::fmt::Display for Issue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let label = match self {
+            Self::MissingFunction { .. } => "missing function",
+            Self::WrongReturns { .. } => "wrong returns",
+            Self::WrongMutability { .. } => "wrong mutability",
+            Self::MissingEvent { .. } => "missing event",
+            Self::WrongIndexed { .. } => "wrong indexed",
+        };
+        write!(f, "{:<17}{}", label, self.signature())?;
+        if let Some((expected, found)) = self.expected_found() {
+            write!(f, ": expected {}, found {}", expected, found)?;
+        }
+        if let Self::MissingFunction { similar, .. } = self {
+            if !similar.is_empty() {
+                write!(f, " (found {})", similar.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn mutability_name(mutability: StateMutability) -> &'static str {
+    match mutability {
+        StateMutability::Pure => "pure",
+        StateMutability::View => "view",
+        StateMutability::NonPayable => "nonpayable",
+        StateMutability::Payable => "payable",
+    }
+}
+
+/// Whether a function declared `found` can stand in for one the standard declares `expected`,
+/// following Solidity's override rules: stricter is fine, `payable` only matches itself.
+pub(crate) fn satisfies(expected: StateMutability, found: StateMutability) -> bool {
+    use StateMutability::*;
+    expected == found || matches!((expected, found), (View, Pure) | (NonPayable, View | Pure))
+}
+
+/// Output types of `function`, e.g. `(bool)` or `()`.
+pub(crate) fn returns(function: &Function) -> String {
+    let types: Vec<&str> = function.outputs.iter().map(|o| o.ty.as_str()).collect();
+    format!("({})", types.join(","))
+}
+
+/// Parameter types of `event` with their `indexed` flags, e.g. `(address indexed,uint256)`.
+pub(crate) fn indexed(event: &Event) -> String {
+    let params: Vec<String> = event
+        .inputs
+        .iter()
+        .map(|input| {
+            if input.indexed {
+                format!("{} indexed", input.ty)
+            } else {
+                input.ty.clone()
+            }
+        })
+        .collect();
+    format!("({})", params.join(","))
+}
+
+/// Every way `abi` falls short of `standard`: functions first, then events, each sorted by name.
+pub(crate) fn check(standard: &JsonAbi, abi: &JsonAbi) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    for expected in standard.functions() {
+        let signature = expected.signature();
+        let Some(found) = abi.functions().find(|f| f.signature() == signature) else {
+            let similar = abi
+                .functions()
+                .filter(|f| f.name == expected.name)
+                .map(Function::signature)
+                .collect();
+            issues.push(Issue::MissingFunction { signature, similar });
+            continue;
+        };
+        if returns(found) != returns(expected) {
+            issues.push(Issue::WrongReturns {
+                signature: signature.clone(),
+                expected: returns(expected),
+                found: returns(found),
+            });
+        }
+        if !satisfies(expected.state_mutability, found.state_mutability) {
+            issues.push(Issue::WrongMutability {
+                signature,
+                expected: expected.state_mutability,
+                found: found.state_mutability,
+            });
+        }
+    }
+    for expected in standard.events() {
+        let signature = expected.signature();
+        match abi.events().find(|e| e.signature() == signature) {
+            None => issues.push(Issue::MissingEvent { signature }),
+            Some(found) if indexed(found) != indexed(expected) => {
+                issues.push(Issue::WrongIndexed {
+                    signature,
+                    expected: indexed(expected),
+                    found: indexed(found),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    issues
+}
+
+fn render_text(args: &Args, issues: &[Issue]) -> String {
+    let abi = args.abi.display();
+    if issues.is_empty() {
+        return format!("{} conforms to {}\n", abi, args.to.name());
+    }
+    let mut report = format!(
+        "{} does not conform to {} ({} issue{}):\n",
+        abi,
+        args.to.name(),
+        issues.len(),
+        if issues.len() == 1 { "" } else { "s" }
+    );
+    for issue in issues {
+        report.push_str(&format!("  {}\n", issue));
+    }
+    report
+}
+
+fn render_json(args: &Args, issues: &[Issue]) -> serde_json::Value {
+    let issues: Vec<serde_json::Value> = issues
+        .iter()
+        .map(|issue| {
+            let mut value = json!({ "kind": issue.kind(), "signature": issue.signature() });
+            if let Some((expected, found)) = issue.expected_found() {
+                value["expected"] = json!(expected);
+                value["found"] = json!(found);
+            }
+            if let Issue::MissingFunction { similar, .. } = issue {
+                value["similar"] = json!(similar);
+            }
+            value
+        })
+        .collect();
+    json!({
+        "abi": args.abi.display().to_string(),
+        "standard": args.to.name(),
+        "conforms": issues.is_empty(),
+        "issues": issues,
+    })
+}
+
+/// Prints the report; returns whether the ABI conforms.
+pub(crate) fn run(args: &Args) -> anyhow::Result<bool> {
+    let content = fs::read_to_string(&args.abi)?;
+    let abi: JsonAbi = serde_json::from_str(&content)?;
+    let issues = check(&args.to.abi(), &abi);
+    match args.format {
+        Format::Text => print!("{}", render_text(args, &issues)),
+        Format::Json => println!("{:#}", render_json(args, &issues)),
+    }
+    Ok(issues.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, satisfies, Issue, Standard};
+    use crate::tests::parse_abi;
+    use alloy_json_abi::StateMutability;
+
+    #[test]
+    fn standards_conform_to_themselves() {
+        for standard in Standard::ALL {
+            assert_eq!(check(&standard.abi(), &standard.abi()), []);
+        }
+    }
+
+    #[test]
+    fn usdt_style_transfer_without_bool() {
+        let abi = parse_abi(&[
+            "function transfer(address to, uint256 value)",
+            "function balanceOf(address owner) view returns (uint256)",
+            "function approve(address spender, uint256 value) returns (bool)",
+            "function transferFrom(address from, address to, uint256 value) returns (bool)",
+            "function allowance(address owner, address spender) view returns (uint256)",
+            "function totalSupply() view returns (uint256)",
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "event Approval(address indexed owner, address indexed spender, uint256 value)",
+        ]);
+        assert_eq!(
+            check(&Standard::Erc20.abi(), &abi),
+            [Issue::WrongReturns {
+                signature: "transfer(address,uint256)".into(),
+                expected: "(bool)".into(),
+                found: "()".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn erc20_requires_transfer_from_allowance_and_total_supply() {
+        let abi = parse_abi(&[
+            "function transfer(address to, uint256 value) returns (bool)",
+            "function balanceOf(address owner) view returns (uint256)",
+            "function approve(address spender, uint256 value) returns (bool)",
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "event Approval(address indexed owner, address indexed spender, uint256 value)",
+        ]);
+        let missing: Vec<String> = check(&Standard::Erc20.abi(), &abi)
+            .iter()
+            .map(|issue| issue.signature().to_string())
+            .collect();
+        assert_eq!(
+            missing,
+            [
+                "allowance(address,address)",
+                "totalSupply()",
+                "transferFrom(address,address,uint256)",
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_missing_items_mutability_and_indexing() {
+        let abi = parse_abi(&[
+            "function balanceOf(address owner) returns (uint256)",
+            "function ownerOf(uint256 tokenId) view returns (address)",
+            "function safeTransferFrom(address from, address to, uint256 tokenId)",
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+        ]);
+        let issues = check(&Standard::Erc721.abi(), &abi);
+        assert!(issues.contains(&Issue::WrongMutability {
+            signature: "balanceOf(address)".into(),
+            expected: StateMutability::View,
+            found: StateMutability::NonPayable,
+        }));
+        assert!(issues.contains(&Issue::MissingFunction {
+            signature: "safeTransferFrom(address,address,uint256,bytes)".into(),
+            similar: vec!["safeTransferFrom(address,address,uint256)".into()],
+        }));
+        assert!(issues.contains(&Issue::WrongIndexed {
+            signature: "Transfer(address,address,uint256)".into(),
+            expected: "(address indexed,address indexed,uint256 indexed)".into(),
+            found: "(address indexed,address indexed,uint256)".into(),
+        }));
+        assert!(issues.contains(&Issue::MissingEvent {
+            signature: "ApprovalForAll(address,address,bool)".into(),
+        }));
+        let overload = issues
+            .iter()
+            .find(|issue| issue.signature().starts_with("safeTransferFrom"))
+            .unwrap();
+        assert_eq!(
+            overload.to_string(),
+            "missing function safeTransferFrom(address,address,uint256,bytes) (found safeTransferFrom(address,address,uint256))"
+        );
+    }
+
+    #[test]
+    fn stricter_mutability_satisfies() {
+        use StateMutability::*;
+        assert!(satisfies(View, Pure));
+        assert!(satisfies(NonPayable, View));
+        assert!(satisfies(Payable, Payable));
+        assert!(!satisfies(View, NonPayable));
+        assert!(!satisfies(Payable, NonPayable));
+        assert!(!satisfies(NonPayable, Payable));
+    }
+}
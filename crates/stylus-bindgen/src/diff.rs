@@ -0,0 +1,510 @@
+//! `stylus-bindgen diff`: what changes between two versions of an ABI, and what breaks.
+//!
+//! Functions and errors are keyed by selector and events by topic 0, so a changed signature
+//! shows up as one item removed and another added. A change is breaking when code built
+//! against the old ABI can fail against the new one: a selector disappears, outputs change,
+//! mutability no longer satisfies what callers assumed, or event topics move.
+
+use crate::conforms::{indexed, mutability_name, returns, satisfies};
+use crate::source::read_abi;
+use crate::{plain_names, safe_name, variant_name, Naming, Options};
+use alloy_json_abi::{Error, Event, Function, JsonAbi};
+use alloy_primitives::hex;
+use clap::ValueEnum;
+use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+#[derive(clap::Args)]
+pub(crate) struct Args {
+    /// ABI JSON before the change
+    old: PathBuf,
+
+    /// ABI JSON after the change
+    new: PathBuf,
+
+    /// Report format
+    #[arg(long, value_enum, default_value_t = Format::Markdown)]
+    format: Format,
+
+    /// Naming of the bindings whose Rust names are reported
+    #[arg(long, value_enum, default_value_t = Naming::Selector)]
+    naming: Naming,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Markdown,
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Item {
+    Function,
+    Event,
+    Error,
+}
+
+impl Item {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Function => "function",
+            Self::Event => "event",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Added,
+    Removed,
+    OutputsChanged { old: String, new: String },
+    MutabilityChanged { old: String, new: String },
+    IndexedChanged { old: String, new: String },
+    InputsRenamed { old: String, new: String },
+}
+
+impl Kind {
+    /// Stable identifier used in JSON reports.
+    fn id(&self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Removed => "removed",
+            Self::OutputsChanged { .. } => "outputs_changed",
+            Self::MutabilityChanged { .. } => "mutability_changed",
+            Self::IndexedChanged { .. } => "indexed_changed",
+            Self::InputsRenamed { .. } => "inputs_renamed",
+        }
+    }
+
+    fn old_new(&self) -> Option<(&str, &str)> {
+        match self {
+            Self::Added | Self::Removed => None,
+            Self::OutputsChanged { old, new }
+            | Self::MutabilityChanged { old, new }
+            | Self::IndexedChanged { old, new }
+            | Self::InputsRenamed { old, new } => Some((old, new)),
+        }
+    }
+}
+
+impl core::fmt::Display for Kind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let label = match self {
+            Self::Added => "added",
+            Self::Removed => "removed",
+            Self::OutputsChanged { .. } => "outputs changed",
+            Self::MutabilityChanged { .. } => "mutability changed",
+            Self::IndexedChanged { .. } => "indexed changed",
+            Self::InputsRenamed { .. } => "inputs renamed",
+        };
+        f.write_str(label)?;
+        if let Some((old, new)) = self.old_new() {
+            write!(f, ": `{}` → `{}`", old, new)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Change {
+    pub(crate) item: Item,
+    /// `0x` selector, or topic 0 for events.
+    pub(crate) selector: String,
+    pub(crate) signature: String,
+    pub(crate) kind: Kind,
+    pub(crate) breaking: bool,
+}
+
+/// Items of `old` and `new` paired by `key`, in key order.
+fn pair<'a, T>(
+    old: impl Iterator<Item = &'a T>,
+    new: impl Iterator<Item = &'a T>,
+    key: impl Fn(&T) -> Vec<u8>,
+) -> BTreeMap<Vec<u8>, (Option<&'a T>, Option<&'a T>)> {
+    let mut pairs: BTreeMap<Vec<u8>, (Option<&T>, Option<&T>)> = BTreeMap::new();
+    for item in old {
+        pairs.entry(key(item)).or_default().0 = Some(item);
+    }
+    for item in new {
+        pairs.entry(key(item)).or_default().1 = Some(item);
+    }
+    pairs
+}
+
+/// `(a, b)` names of `params`, with `_` for unnamed ones.
+fn names<'a>(params: impl Iterator<Item = &'a str>) -> String {
+    let names: Vec<&str> = params
+        .map(|name| if name.is_empty() { "_" } else { name })
+        .collect();
+    format!("({})", names.join(", "))
+}
+
+/// Renamed inputs, which only rename the `Calls` variant's fields.
+fn renamed<'a>(
+    old: impl Iterator<Item = &'a str>,
+    new: impl Iterator<Item = &'a str>,
+) -> Option<(Kind, bool)> {
+    let (old, new) = (names(old), names(new));
+    (old != new).then_some((Kind::InputsRenamed { old, new }, false))
+}
+
+/// Changes between two versions of an item with the same key, as `(kind, breaking)`.
+fn item_changes<T>(
+    old: Option<&T>,
+    new: Option<&T>,
+    compare: impl Fn(&T, &T) -> Vec<(Kind, bool)>,
+) -> Vec<(Kind, bool)> {
+    match (old, new) {
+        (Some(_), None) => vec![(Kind::Removed, true)],
+        (None, Some(_)) => vec![(Kind::Added, false)],
+        (Some(old), Some(new)) => compare(old, new),
+        (None, None) => Vec::new(),
+    }
+}
+
+fn function_changes(old: &Function, new: &Function) -> Vec<(Kind, bool)> {
+    let mut changes = Vec::new();
+    if returns(old) != returns(new) {
+        changes.push((
+            Kind::OutputsChanged {
+                old: returns(old),
+                new: returns(new),
+            },
+            true,
+        ));
+    }
+    if old.state_mutability != new.state_mutability {
+        changes.push((
+            Kind::MutabilityChanged {
+                old: mutability_name(old.state_mutability).to_string(),
+                new: mutability_name(new.state_mutability).to_string(),
+            },
+            !satisfies(old.state_mutability, new.state_mutability),
+        ));
+    }
+    changes.extend(renamed(
+        old.inputs.iter().map(|p| p.name.as_str()),
+        new.inputs.iter().map(|p| p.name.as_str()),
+    ));
+    changes
+}
+
+fn event_changes(old: &Event, new: &Event) -> Vec<(Kind, bool)> {
+    let mut changes = Vec::new();
+    if indexed(old) != indexed(new) {
+        changes.push((
+            Kind::IndexedChanged {
+                old: indexed(old),
+                new: indexed(new),
+            },
+            true,
+        ));
+    }
+    changes.extend(renamed(
+        old.inputs.iter().map(|p| p.name.as_str()),
+        new.inputs.iter().map(|p| p.name.as_str()),
+    ));
+    changes
+}
+
+fn error_changes(old: &Error, new: &Error) -> Vec<(Kind, bool)> {
+    renamed(
+        old.inputs.iter().map(|p| p.name.as_str()),
+        new.inputs.iter().map(|p| p.name.as_str()),
+    )
+    .into_iter()
+    .collect()
+}
+
+/// Every change from `old` to `new`: functions, then events, then errors, each by selector.
+pub(crate) fn diff(old: &JsonAbi, new: &JsonAbi) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let mut push = |item: Item, key: &[u8], signature: String, found: Vec<(Kind, bool)>| {
+        for (kind, breaking) in found {
+            changes.push(Change {
+                item,
+                selector: format!("0x{}", hex::encode(key)),
+                signature: signature.clone(),
+                kind,
+                breaking,
+            });
+        }
+    };
+    for (key, (o, n)) in pair(old.functions(), new.functions(), |f| f.selector().to_vec()) {
+        let signature = o.or(n).map(Function::signature).unwrap_or_default();
+        push(
+            Item::Function,
+            &key,
+            signature,
+            item_changes(o, n, function_changes),
+        );
+    }
+    for (key, (o, n)) in pair(old.events(), new.events(), |e| e.selector().to_vec()) {
+        let signature = o.or(n).map(Event::signature).unwrap_or_default();
+        push(
+            Item::Event,
+            &key,
+            signature,
+            item_changes(o, n, event_changes),
+        );
+    }
+    for (key, (o, n)) in pair(old.errors(), new.errors(), |e| e.selector().to_vec()) {
+        let signature = o.or(n).map(Error::signature).unwrap_or_default();
+        push(
+            Item::Error,
+            &key,
+            signature,
+            item_changes(o, n, error_changes),
+        );
+    }
+    changes
+}
+
+/// Rust items generated for `abi` by `--mode bindings --naming <naming>`: methods, encoders,
+/// decoders, mock setters and `Calls` variants.
+pub(crate) fn rust_names(abi: &JsonAbi, naming: Naming) -> BTreeSet<String> {
+    let options = Options {
+        plain: plain_names(abi, naming),
+        ..Options::default()
+    };
+    let mut names = BTreeSet::new();
+    for function in abi.functions() {
+        let name = safe_name(function, &options);
+        names.insert(format!("encode_{}", name));
+        names.insert(format!("decode_{}_returns", name));
+        if !function.outputs.is_empty() {
+            names.insert(format!("returns_{}", name));
+        }
+        names.insert(format!("Calls::{}", variant_name(function, &options)));
+        names.insert(name);
+    }
+    names
+}
+
+struct Report {
+    changes: Vec<Change>,
+    removed_names: Vec<String>,
+    added_names: Vec<String>,
+}
+
+fn markdown_table(text: &mut String, changes: &[&Change]) {
+    text.push_str("| Item | Selector | Signature | Change |\n");
+    text.push_str("|------|----------|-----------|--------|\n");
+    for change in changes {
+        text.push_str(&format!(
+            "| {} | `{}` | `{}` | {} |\n",
+            change.item.name(),
+            change.selector,
+            change.signature,
+            change.kind
+        ));
+    }
+}
+
+fn render_markdown(args: &Args, report: &Report) -> String {
+    let (breaking, compatible): (Vec<&Change>, Vec<&Change>) =
+        report.changes.iter().partition(|change| change.breaking);
+    let mut text = format!(
+        "# ABI diff: `{}` → `{}`\n\n{} breaking, {} non-breaking change(s).\n",
+        args.old.display(),
+        args.new.display(),
+        breaking.len(),
+        compatible.len()
+    );
+    if !breaking.is_empty() {
+        text.push_str("\n## Breaking\n\n");
+        markdown_table(&mut text, &breaking);
+    }
+    if !compatible.is_empty() {
+        text.push_str("\n## Non-breaking\n\n");
+        markdown_table(&mut text, &compatible);
+    }
+    if !report.removed_names.is_empty() || !report.added_names.is_empty() {
+        text.push_str("\n## Generated Rust names\n\n");
+        for name in &report.removed_names {
+            text.push_str(&format!("- removed `{}`\n", name));
+        }
+        for name in &report.added_names {
+            text.push_str(&format!("- added `{}`\n", name));
+        }
+    }
+    text
+}
+
+fn render_json(args: &Args, report: &Report) -> serde_json::Value {
+    let changes: Vec<serde_json::Value> = report
+        .changes
+        .iter()
+        .map(|change| {
+            let mut value = json!({
+                "item": change.item.name(),
+                "selector": change.selector,
+                "signature": change.signature,
+                "change": change.kind.id(),
+                "breaking": change.breaking,
+            });
+            if let Some((old, new)) = change.kind.old_new() {
+                value["old"] = json!(old);
+                value["new"] = json!(new);
+            }
+            value
+        })
+        .collect();
+    json!({
+        "old": args.old.display().to_string(),
+        "new": args.new.display().to_string(),
+        "breaking": report.changes.iter().any(|change| change.breaking),
+        "changes": changes,
+        "rust_names": {
+            "removed": report.removed_names,
+            "added": report.added_names,
+        },
+    })
+}
+
+/// Prints the report; returns whether no change is breaking.
+pub(crate) fn run(args: &Args) -> anyhow::Result<bool> {
+    let (old, new) = (read_abi(&args.old)?, read_abi(&args.new)?);
+    let (old_names, new_names) = (rust_names(&old, args.naming), rust_names(&new, args.naming));
+    let report = Report {
+        changes: diff(&old, &new),
+        removed_names: old_names.difference(&new_names).cloned().collect(),
+        added_names: new_names.difference(&old_names).cloned().collect(),
+    };
+    match args.format {
+        Format::Markdown => print!("{}", render_markdown(args, &report)),
+        Format::Json => println!("{:#}", render_json(args, &report)),
+    }
+    Ok(!report.changes.iter().any(|change| change.breaking))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, rust_names, Item, Kind};
+    use crate::tests::parse_abi;
+    use crate::Naming;
+    use alloy_json_abi::JsonAbi;
+
+    fn kinds(old: &[&str], new: &[&str]) -> Vec<(Kind, bool)> {
+        let (old, new) = (parse_abi(old), parse_abi(new));
+        diff(&old, &new)
+            .into_iter()
+            .map(|change| (change.kind, change.breaking))
+            .collect()
+    }
+
+    #[test]
+    fn identical_abis_have_no_changes() {
+        let abi = ["function transfer(address to, uint256 value) returns (bool)"];
+        assert_eq!(kinds(&abi, &abi), []);
+    }
+
+    #[test]
+    fn removed_selectors_break_and_added_ones_do_not() {
+        let old = JsonAbi::parse(["function transfer(address to, uint256 value)"]).unwrap();
+        let new = JsonAbi::parse(["function transfer(address to, uint128 value)"]).unwrap();
+        let changes = diff(&old, &new);
+        assert_eq!(changes.len(), 2);
+        let removed = changes.iter().find(|c| c.kind == Kind::Removed).unwrap();
+        assert_eq!(removed.signature, "transfer(address,uint256)");
+        assert_eq!(removed.selector, "0xa9059cbb");
+        assert!(removed.breaking);
+        let added = changes.iter().find(|c| c.kind == Kind::Added).unwrap();
+        assert_eq!(added.signature, "transfer(address,uint128)");
+        assert!(!added.breaking);
+    }
+
+    #[test]
+    fn outputs_changes_break() {
+        assert_eq!(
+            kinds(
+                &["function transfer(address to, uint256 value) returns (bool)"],
+                &["function transfer(address to, uint256 value)"]
+            ),
+            [(
+                Kind::OutputsChanged {
+                    old: "(bool)".into(),
+                    new: "()".into()
+                },
+                true
+            )]
+        );
+    }
+
+    #[test]
+    fn mutability_breaks_only_when_callers_can_fail() {
+        let breaking = |old: &str, new: &str| {
+            kinds(
+                &[&format!("function f() {}", old)],
+                &[&format!("function f() {}", new)],
+            )[0]
+            .1
+        };
+        assert!(breaking("payable", ""));
+        assert!(breaking("view", ""));
+        assert!(!breaking("", "view"));
+        assert!(!breaking("view", "pure"));
+    }
+
+    #[test]
+    fn events_and_errors_are_keyed_by_selector() {
+        let old = JsonAbi::parse([
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "error Unauthorized(address caller)",
+        ])
+        .unwrap();
+        let new = JsonAbi::parse([
+            "event Transfer(address indexed from, address indexed to, uint256 indexed value)",
+            "error Unauthorized(address account)",
+        ])
+        .unwrap();
+        let changes = diff(&old, &new);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].item, Item::Event);
+        assert!(changes[0].breaking);
+        assert_eq!(changes[1].item, Item::Error);
+        assert_eq!(
+            changes[1].kind,
+            Kind::InputsRenamed {
+                old: "(caller)".into(),
+                new: "(account)".into()
+            }
+        );
+        assert!(!changes[1].breaking);
+    }
+
+    #[test]
+    fn rust_names_follow_selectors() {
+        let abi = parse_abi(&["function balanceOf(address owner) view returns (uint256)"]);
+        let names: Vec<String> = rust_names(&abi, Naming::Selector).into_iter().collect();
+        assert_eq!(
+            names,
+            [
+                "Calls::BalanceOf__0x70a08231",
+                "balance_of__0x70a08231",
+                "decode_balance_of__0x70a08231_returns",
+                "encode_balance_of__0x70a08231",
+                "returns_balance_of__0x70a08231",
+            ]
+        );
+    }
+
+    #[test]
+    fn overloads_rename_plain_names() {
+        let old = JsonAbi::parse(["function mint(address to)", "function pause()"]).unwrap();
+        let new = JsonAbi::parse([
+            "function mint(address to)",
+            "function mint(address to, uint256 id)",
+            "function pause()",
+        ])
+        .unwrap();
+        let old = rust_names(&old, Naming::Overloads);
+        let new = rust_names(&new, Naming::Overloads);
+        assert!(old.contains("mint") && old.contains("Calls::Mint"));
+        assert!(!new.contains("mint"));
+        assert!(new.contains("mint__0x6a627842") && new.contains("mint__0x40c10f19"));
+        assert!(old.contains("pause") && new.contains("pause"));
+    }
+}
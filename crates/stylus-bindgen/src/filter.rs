@@ -0,0 +1,144 @@
+//! Generating part of an ABI: functions picked by name glob, signature or selector, and by
+//! mutability, so large ABIs only cost code for what a contract calls.
+
+use crate::{is_view, names_function};
+use alloy_json_abi::{Function, JsonAbi};
+
+/// Whether `text` matches `pattern`, where `*` matches any run of characters and `?` any one.
+pub(crate) fn glob_matches(pattern: &str, text: &str) -> bool {
+    let (pattern, text): (Vec<char>, Vec<char>) =
+        (pattern.chars().collect(), text.chars().collect());
+    let (mut p, mut t) = (0, 0);
+    // Position after the last `*` and the text position it currently stands for.
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p + 1, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                // Let the last `*` absorb one more character and retry.
+                Some((after, absorbed)) => {
+                    star = Some((after, absorbed + 1));
+                    p = after;
+                    t = absorbed + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Which functions to generate; everything when empty.
+pub(crate) struct Filter<'a> {
+    /// Keep only functions named by one of these, when not empty.
+    pub(crate) include: &'a [String],
+    pub(crate) exclude: &'a [String],
+    /// Keep only `view` and `pure` functions.
+    pub(crate) only_view: bool,
+}
+
+impl Filter<'_> {
+    fn keeps(&self, function: &Function) -> bool {
+        let named = |patterns: &[String]| {
+            patterns
+                .iter()
+                .any(|pattern| names_function(pattern, function))
+        };
+        (self.include.is_empty() || named(self.include))
+            && !named(self.exclude)
+            && (!self.only_view || is_view(function))
+    }
+
+    /// Removes the functions the filter drops from `abi`.
+    pub(crate) fn apply(&self, abi: &mut JsonAbi) {
+        for overloads in abi.functions.values_mut() {
+            overloads.retain(|function| self.keeps(function));
+        }
+        abi.functions.retain(|_, overloads| !overloads.is_empty());
+    }
+}
+
+/// ERC-165 interface ID of `abi`'s functions: the XOR of their selectors.
+pub(crate) fn interface_id(abi: &JsonAbi) -> [u8; 4] {
+    abi.functions().fold([0; 4], |id, function| {
+        let selector = function.selector();
+        core::array::from_fn(|i| id[i] ^ selector[i])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{glob_matches, interface_id, Filter};
+    use crate::tests::parse_abi;
+    use alloy_json_abi::JsonAbi;
+
+    #[test]
+    fn globs() {
+        assert!(glob_matches("transfer", "transfer"));
+        assert!(!glob_matches("transfer", "transferFrom"));
+        assert!(glob_matches("transfer*", "transferFrom"));
+        assert!(glob_matches("*Approv*", "setApprovalForAll"));
+        assert!(glob_matches("get?pproved", "getApproved"));
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("a*b*c", "aXbYbZc"));
+        assert!(!glob_matches("a*b*c", "aXbYbZ"));
+        assert!(!glob_matches("?", ""));
+    }
+
+    fn abi() -> JsonAbi {
+        parse_abi(&[
+            "function balanceOf(address owner) view returns (uint256)",
+            "function ownerOf(uint256 tokenId) view returns (address)",
+            "function safeTransferFrom(address from, address to, uint256 tokenId)",
+            "function safeTransferFrom(address from, address to, uint256 tokenId, bytes data)",
+            "function transferFrom(address from, address to, uint256 tokenId)",
+        ])
+    }
+
+    fn kept(include: &[&str], exclude: &[&str], only_view: bool) -> Vec<String> {
+        let (include, exclude): (Vec<String>, Vec<String>) = (
+            include.iter().map(|p| p.to_string()).collect(),
+            exclude.iter().map(|p| p.to_string()).collect(),
+        );
+        let mut abi = abi();
+        Filter {
+            include: &include,
+            exclude: &exclude,
+            only_view,
+        }
+        .apply(&mut abi);
+        abi.functions()
+            .map(|function| function.signature())
+            .collect()
+    }
+
+    #[test]
+    fn include_exclude_and_mutability() {
+        assert_eq!(kept(&[], &[], false).len(), 5);
+        assert_eq!(
+            kept(&["*TransferFrom"], &["0xb88d4fde"], false),
+            ["safeTransferFrom(address,address,uint256)"]
+        );
+        assert_eq!(
+            kept(&[], &["safeTransferFrom"], true),
+            ["balanceOf(address)", "ownerOf(uint256)"]
+        );
+        assert_eq!(
+            kept(&["transferFrom(address,address,uint256)"], &[], false),
+            ["transferFrom(address,address,uint256)"]
+        );
+    }
+
+    #[test]
+    fn interface_id_of_the_erc721_core() {
+        let abi: JsonAbi = serde_json::from_str(include_str!("../../../abis/erc721.json")).unwrap();
+        assert_eq!(interface_id(&abi), [0x80, 0xac, 0x58, 0xcd]);
+    }
+}
@@ -0,0 +1,522 @@
+//! Rust types for the `internalType` the compiler records next to each ABI type: a
+//! `#[repr(u8)]` enum for `enum Side`, a typed address for `contract IOracle` (with
+//! `--pack-handles`, the pack's `Contract` for the standards in `stylus-interfaces`), and a
+//! newtype for a user-defined value type such as `Price`.
+//!
+//! ABIs don't list enum variants, so `--enum Side=Buy,Sell` names them, and decoding rejects any
+//! other value. Enums without variants stay `u8`. Every type encodes as its Solidity type
+//! through the `Borrow` impl `--type` overrides use. Arrays keep their element types, and so do
+//! value types over `bool`, which alloy only encodes from `bool` itself.
+
+use crate::{overrides, to_rust_type};
+use alloy_json_abi::{InternalType as Internal, JsonAbi, Param};
+use std::collections::BTreeMap;
+
+/// `--enum NAME=A,B,..`: the variants of an enum, in declaration order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct EnumVariants {
+    /// `Side`, or `Exchange.Side` to pick one of several enums of that name.
+    pub(crate) name: String,
+    pub(crate) variants: Vec<String>,
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl EnumVariants {
+    pub(crate) fn new(name: &str, variants: &[String]) -> anyhow::Result<Self> {
+        let name = name.trim();
+        if !name.split('.').all(is_identifier) {
+            anyhow::bail!("`{}` is not an enum name", name);
+        }
+        if variants.is_empty() || variants.len() > 256 {
+            anyhow::bail!("enum {} needs 1 to 256 variants", name);
+        }
+        let variants: Vec<String> = variants.iter().map(|v| v.trim().to_string()).collect();
+        if let Some(bad) = variants.iter().find(|v| !is_identifier(v)) {
+            anyhow::bail!("enum {}: `{}` is not a variant name", name, bad);
+        }
+        if let Some(repeated) = variants
+            .iter()
+            .enumerate()
+            .find(|(index, v)| variants[..*index].contains(v))
+        {
+            anyhow::bail!("enum {}: `{}` is repeated", name, repeated.1);
+        }
+        Ok(Self {
+            name: name.to_string(),
+            variants,
+        })
+    }
+
+    /// Parses `NAME=A,B,..`, as given to `--enum`.
+    pub(crate) fn parse(spec: &str) -> anyhow::Result<Self> {
+        let (name, variants) = spec
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("`{}` is not NAME=VARIANT,..", spec))?;
+        let variants: Vec<String> = variants.split(',').map(str::to_string).collect();
+        Self::new(name, &variants)
+    }
+
+    fn names(&self, contract: Option<&str>, name: &str) -> bool {
+        self.name == name || contract.is_some_and(|c| self.name == format!("{}.{}", c, name))
+    }
+}
+
+/// What an `internalType` maps to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Kind {
+    /// `enum Side`, with the variants `--enum` gave it.
+    Enum(Vec<String>),
+    /// `contract IERC20` with `--pack-handles`: the `Contract` of this `stylus_interfaces` module.
+    Pack(&'static str),
+    /// Any other `contract`: a generated address newtype.
+    Contract,
+    /// User-defined value type over this Solidity type.
+    Value(String),
+}
+
+/// Rust type of one `internalType`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct InternalType {
+    /// Rust name: the Solidity name without its contract.
+    name: String,
+    /// The `internalType`, e.g. `enum Exchange.Side`.
+    solidity: String,
+    kind: Kind,
+}
+
+/// `stylus_interfaces` modules for the standard interfaces' contract types.
+const PACKS: [(&str, &str); 4] = [
+    ("IERC20", "erc20"),
+    ("IERC721", "erc721"),
+    ("IERC1155", "erc1155"),
+    ("IERC165", "ierc165"),
+];
+
+/// The Rust type for `param`'s `internalType`, if it has one. `packs` types the standard
+/// interfaces as their pack's `Contract`.
+pub(crate) fn lookup(param: &Param, enums: &[EnumVariants], packs: bool) -> Option<InternalType> {
+    if param.ty.ends_with(']') {
+        return None;
+    }
+    let internal = param.internal_type()?;
+    let (name, kind) = match internal {
+        Internal::Enum { contract, ty } if param.ty == "uint8" => {
+            let variants = enums
+                .iter()
+                .find(|e| e.names(contract.as_deref(), ty))?
+                .variants
+                .clone();
+            (ty.clone(), Kind::Enum(variants))
+        }
+        Internal::Contract(name) if param.ty == "address" => {
+            let kind = match PACKS.iter().find(|(pack, _)| pack == name) {
+                Some((_, module)) if packs => Kind::Pack(module),
+                _ => Kind::Contract,
+            };
+            (name.clone(), kind)
+        }
+        Internal::Other { ty, .. }
+            if *ty != param.ty && param.ty != "bool" && is_identifier(ty) =>
+        {
+            (ty.clone(), Kind::Value(param.ty.clone()))
+        }
+        _ => return None,
+    };
+    Some(InternalType {
+        name,
+        solidity: internal.to_string(),
+        kind,
+    })
+}
+
+impl InternalType {
+    pub(crate) fn rust_type(&self) -> String {
+        match self.kind {
+            Kind::Pack(module) => format!("stylus_interfaces::{}::Contract", module),
+            _ => self.name.clone(),
+        }
+    }
+
+    /// True for enums, whose conversion from the decoded value can fail.
+    pub(crate) fn is_checked(&self) -> bool {
+        matches!(self.kind, Kind::Enum(_))
+    }
+
+    /// Rust type the definition wraps, for the imports.
+    pub(crate) fn underlying(&self) -> Option<String> {
+        match &self.kind {
+            Kind::Enum(_) | Kind::Pack(_) => None,
+            Kind::Contract => Some("Address".to_string()),
+            Kind::Value(sol_type) => Some(to_rust_type(sol_type)),
+        }
+    }
+}
+
+/// Parameters of `abi` without a `--type` override whose `internalType` has a Rust type.
+fn used<'a>(
+    abi: &'a JsonAbi,
+    enums: &'a [EnumVariants],
+    overrides: &'a [overrides::Override],
+    packs: bool,
+) -> impl Iterator<Item = (String, InternalType)> + 'a {
+    abi.functions().flat_map(move |function| {
+        function
+            .inputs
+            .iter()
+            .chain(&function.outputs)
+            .filter(move |param| overrides::lookup(overrides, function, param).is_none())
+            .filter_map(move |param| {
+                lookup(param, enums, packs).map(|internal| (function.signature(), internal))
+            })
+    })
+}
+
+/// The types `abi` needs defined, by name.
+pub(crate) fn definitions(
+    abi: &JsonAbi,
+    enums: &[EnumVariants],
+    overrides: &[overrides::Override],
+    packs: bool,
+) -> Vec<InternalType> {
+    let mut definitions = BTreeMap::new();
+    for (_, internal) in used(abi, enums, overrides, packs) {
+        if !matches!(internal.kind, Kind::Pack(_)) {
+            definitions.entry(internal.name.clone()).or_insert(internal);
+        }
+    }
+    definitions.into_values().collect()
+}
+
+/// Fails unless every `--enum` names an enum of `abis` and the types of each ABI have distinct
+/// names.
+pub(crate) fn check(
+    enums: &[EnumVariants],
+    overrides: &[overrides::Override],
+    packs: bool,
+    abis: &[&JsonAbi],
+) -> anyhow::Result<()> {
+    for e in enums {
+        let named = abis.iter().flat_map(|abi| abi.functions()).any(|function| {
+            function
+                .inputs
+                .iter()
+                .chain(&function.outputs)
+                .filter_map(Param::internal_type)
+                .any(|internal| {
+                    matches!(internal, Internal::Enum { contract, ty }
+                        if e.names(contract.as_deref(), ty))
+                })
+        });
+        if !named {
+            anyhow::bail!("--enum {}: no parameter has this enum type", e.name);
+        }
+    }
+    for abi in abis {
+        let mut names: BTreeMap<String, String> = BTreeMap::new();
+        for (_, internal) in used(abi, enums, overrides, packs) {
+            if matches!(internal.kind, Kind::Pack(_)) {
+                continue;
+            }
+            let solidity = names
+                .entry(internal.name.clone())
+                .or_insert_with(|| internal.solidity.clone());
+            if *solidity != internal.solidity {
+                anyhow::bail!(
+                    "`{}` and `{}` would both be named `{}`: map one with --type",
+                    solidity,
+                    internal.solidity,
+                    internal.name
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Top-level type and value names `code` defines or imports, with how often.
+fn item_names(code: &str) -> BTreeMap<&str, usize> {
+    const ITEMS: [&str; 6] = [
+        "pub struct ",
+        "pub enum ",
+        "pub trait ",
+        "pub type ",
+        "pub mod ",
+        "pub const ",
+    ];
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut names = BTreeMap::new();
+    for line in code.lines() {
+        if let Some(imports) = line.strip_prefix("use ") {
+            // The last segment of each path: identifiers not followed by `::`.
+            let mut rest = imports;
+            while let Some(start) = rest.find(|c: char| is_ident(c)) {
+                rest = &rest[start..];
+                let end = rest.find(|c: char| !is_ident(c)).unwrap_or(rest.len());
+                if !rest[end..].starts_with("::") {
+                    *names.entry(&rest[..end]).or_insert(0) += 1;
+                }
+                rest = &rest[end..];
+            }
+        } else if let Some(item) = ITEMS.iter().find_map(|item| line.strip_prefix(item)) {
+            let end = item.find(|c: char| !is_ident(c)).unwrap_or(item.len());
+            *names.entry(&item[..end]).or_insert(0) += 1;
+        }
+    }
+    names
+}
+
+/// Fails if a type generated for `abi` shares its name with anything else `code`, the bindings
+/// generated for it, defines or imports.
+pub(crate) fn check_shadowing(
+    code: &str,
+    abi: &JsonAbi,
+    enums: &[EnumVariants],
+    overrides: &[overrides::Override],
+    packs: bool,
+) -> anyhow::Result<()> {
+    let names = item_names(code);
+    for (signature, internal) in used(abi, enums, overrides, packs) {
+        if !matches!(internal.kind, Kind::Pack(_))
+            && names
+                .get(internal.name.as_str())
+                .is_some_and(|count| *count > 1)
+        {
+            anyhow::bail!(
+                "`{}` of {} would shadow the generated `{}`: map it with --type",
+                internal.solidity,
+                signature,
+                internal.name
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Type `Borrow`ed to encode a value of the elementary `sol_type`, as `alloy_sol_types` does.
+fn borrowed_type(sol_type: &str) -> String {
+    match sol_type {
+        "address" => "[u8; 20]".to_string(),
+        _ => match sol_type.strip_prefix("bytes") {
+            Some(len) => format!("[u8; {}]", len),
+            None => to_rust_type(sol_type),
+        },
+    }
+}
+
+/// Definitions of `types`, after the imports of a bindings file.
+pub(crate) fn generate(code: &mut String, types: &[InternalType]) {
+    for internal in types {
+        match &internal.kind {
+            Kind::Enum(variants) => generate_enum(code, internal, variants),
+            Kind::Contract => generate_newtype(
+                code,
+                &internal.name,
+                &format!("Address of a Solidity `{}`.", internal.solidity),
+                "address",
+            ),
+            Kind::Value(sol_type) => generate_newtype(
+                code,
+                &internal.name,
+                &format!(
+                    "Solidity user-defined value type `{}`, over `{}`.",
+                    internal.solidity, sol_type
+                ),
+                sol_type,
+            ),
+            Kind::Pack(_) => {}
+        }
+    }
+}
+
+fn generate_enum(code: &mut String, internal: &InternalType, variants: &[String]) {
+    let name = &internal.name;
+    code.push_str(&format!("\n/// Solidity `{}`.\n", internal.solidity));
+    code.push_str("#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]\n");
+    code.push_str("#[repr(u8)]\n");
+    code.push_str(&format!("pub enum {} {{\n", name));
+    for (index, variant) in variants.iter().enumerate() {
+        code.push_str(&format!("    {} = {},\n", variant, index));
+    }
+    code.push_str("}\n\n");
+
+    code.push_str(&format!("impl core::borrow::Borrow<u8> for {} {{\n", name));
+    code.push_str("    fn borrow(&self) -> &u8 {\n");
+    code.push_str("        match self {\n");
+    for (index, variant) in variants.iter().enumerate() {
+        code.push_str(&format!("            Self::{} => &{},\n", variant, index));
+    }
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+
+    code.push_str(&format!("impl TryFrom<u8> for {} {{\n", name));
+    code.push_str("    type Error = stylus_sdk::alloy_sol_types::Error;\n\n");
+    code.push_str("    fn try_from(value: u8) -> Result<Self, Self::Error> {\n");
+    code.push_str("        match value {\n");
+    for (index, variant) in variants.iter().enumerate() {
+        code.push_str(&format!(
+            "            {} => Ok(Self::{}),\n",
+            index, variant
+        ));
+    }
+    code.push_str(&format!(
+        "            _ => Err(stylus_sdk::alloy_sol_types::Error::custom(\"value out of range for {}\")),\n",
+        internal.solidity
+    ));
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n");
+}
+
+fn generate_newtype(code: &mut String, name: &str, doc: &str, sol_type: &str) {
+    let rust_type = to_rust_type(sol_type);
+    let borrowed = borrowed_type(sol_type);
+    code.push_str(&format!("\n/// {}\n", doc));
+    code.push_str("#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]\n");
+    code.push_str(&format!("pub struct {}(pub {});\n\n", name, rust_type));
+
+    code.push_str(&format!(
+        "impl core::borrow::Borrow<{}> for {} {{\n",
+        borrowed, name
+    ));
+    code.push_str(&format!("    fn borrow(&self) -> &{} {{\n", borrowed));
+    code.push_str("        core::borrow::Borrow::borrow(&self.0)\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+
+    code.push_str(&format!("impl From<{}> for {} {{\n", rust_type, name));
+    code.push_str(&format!("    fn from(value: {}) -> Self {{\n", rust_type));
+    code.push_str("        Self(value)\n");
+    code.push_str("    }\n");
+    code.push_str("}\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, check_shadowing, definitions, item_names, lookup, EnumVariants};
+    use alloy_json_abi::JsonAbi;
+
+    fn abi() -> JsonAbi {
+        serde_json::from_str(
+            r#"[
+                {"type": "function", "name": "place", "stateMutability": "nonpayable",
+                 "inputs": [
+                    {"name": "side", "type": "uint8", "internalType": "enum Exchange.Side"},
+                    {"name": "token", "type": "address", "internalType": "contract IERC20"},
+                    {"name": "oracle", "type": "address", "internalType": "contract IOracle"},
+                    {"name": "price", "type": "uint128", "internalType": "Price"},
+                    {"name": "sides", "type": "uint8[]", "internalType": "enum Exchange.Side[]"},
+                    {"name": "owner", "type": "address", "internalType": "address"}],
+                 "outputs": []}
+            ]"#,
+        )
+        .unwrap()
+    }
+
+    fn enums(specs: &[&str]) -> Vec<EnumVariants> {
+        specs
+            .iter()
+            .map(|spec| EnumVariants::parse(spec).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn internal_types_map_to_rust_types() {
+        let (abi, enums) = (abi(), enums(&["Exchange.Side=Buy,Sell"]));
+        let function = abi.functions().next().unwrap();
+        let types: Vec<Option<String>> = function
+            .inputs
+            .iter()
+            .map(|param| lookup(param, &enums, false).map(|internal| internal.rust_type()))
+            .collect();
+        assert_eq!(
+            types,
+            [
+                Some("Side".to_string()),
+                Some("IERC20".to_string()),
+                Some("IOracle".to_string()),
+                Some("Price".to_string()),
+                None,
+                None,
+            ]
+        );
+        let defined: Vec<String> = definitions(&abi, &enums, &[], false)
+            .iter()
+            .map(|internal| internal.rust_type())
+            .collect();
+        assert_eq!(defined, ["IERC20", "IOracle", "Price", "Side"]);
+    }
+
+    #[test]
+    fn pack_handles_are_opt_in() {
+        let abi = abi();
+        let token = &abi.functions().next().unwrap().inputs[1];
+        assert_eq!(
+            lookup(token, &[], true).unwrap().rust_type(),
+            "stylus_interfaces::erc20::Contract"
+        );
+        let defined: Vec<String> = definitions(&abi, &[], &[], true)
+            .iter()
+            .map(|internal| internal.rust_type())
+            .collect();
+        assert_eq!(defined, ["IOracle", "Price"]);
+    }
+
+    #[test]
+    fn enums_need_variants() {
+        assert!(EnumVariants::parse("Side").is_err());
+        assert!(EnumVariants::parse("Side=Buy,Buy").is_err());
+        assert!(EnumVariants::parse("Side=Buy,not a name").is_err());
+
+        let abi = abi();
+        assert_eq!(
+            check(&enums(&["Side=Buy", "Other=A"]), &[], false, &[&abi])
+                .unwrap_err()
+                .to_string(),
+            "--enum Other: no parameter has this enum type"
+        );
+        assert!(check(&enums(&["Side=Buy,Sell"]), &[], false, &[&abi]).is_ok());
+        // Without variants, the enum stays a `u8`.
+        assert!(check(&[], &[], false, &[&abi]).is_ok());
+        let side = &abi.functions().next().unwrap().inputs[0];
+        assert!(lookup(side, &[], false).is_none());
+    }
+
+    #[test]
+    fn generated_names_are_read_from_the_code() {
+        let code =
+            "use stylus_sdk::{alloy_primitives::{Address, U256}, alloy_sol_types::SolType};\n\
+                    use stylus_interfaces::{CallError, transport::Transport};\n\
+                    pub struct Price(pub u128);\n\
+                    pub struct CallOpts {\n    pub struct Nested;\n}\n\
+                    pub mod selectors {\n    pub const transfer: [u8; 4] = [0; 4];\n}\n";
+        let names: Vec<(&str, usize)> = item_names(code).into_iter().collect();
+        assert_eq!(
+            names,
+            [
+                ("Address", 1),
+                ("CallError", 1),
+                ("CallOpts", 1),
+                ("Price", 1),
+                ("SolType", 1),
+                ("Transport", 1),
+                ("U256", 1),
+                ("selectors", 1),
+            ]
+        );
+        let abi = abi();
+        let shadowing = format!("{}pub struct Price(pub U256);\n", code);
+        assert_eq!(
+            check_shadowing(&shadowing, &abi, &[], &[], false)
+                .unwrap_err()
+                .to_string(),
+            "`Price` of place(uint8,address,address,uint128,uint8[],address) would shadow the \
+             generated `Price`: map it with --type"
+        );
+        assert!(check_shadowing(code, &abi, &[], &[], false).is_ok());
+    }
+}
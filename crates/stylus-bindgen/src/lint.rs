@@ -0,0 +1,563 @@
+//! `stylus-bindgen lint`: flags risky or ambiguous ABIs before bindings are generated for them.
+//!
+//! Every rule has a stable id and a default level, overridable in the `[lint]` table of
+//! `sipb.toml`:
+//!
+//! ```toml
+//! [lint]
+//! unnamed-param = "allow"
+//! suspicious-selector = "deny"
+//! ```
+
+use crate::config;
+use crate::conforms::Standard;
+use crate::Format;
+use alloy_json_abi::{Function, JsonAbi, Param, StateMutability};
+use alloy_primitives::hex;
+use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(clap::Args)]
+pub(crate) struct Args {
+    /// ABI JSON files to lint
+    #[arg(required = true)]
+    abis: Vec<PathBuf>,
+
+    /// Configuration with a `[lint]` table (default: `sipb.toml` if it exists)
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Report format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Level {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl Level {
+    fn parse(level: &str) -> Option<Self> {
+        match level {
+            "allow" => Some(Self::Allow),
+            "warn" => Some(Self::Warn),
+            "deny" => Some(Self::Deny),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Warn => "warn",
+            Self::Deny => "deny",
+        }
+    }
+}
+
+pub(crate) struct Rule {
+    pub(crate) id: &'static str,
+    pub(crate) default: Level,
+}
+
+pub(crate) const SELECTOR_COLLISION: &str = "selector-collision";
+pub(crate) const SUSPICIOUS_SELECTOR: &str = "suspicious-selector";
+pub(crate) const UNNAMED_PARAM: &str = "unnamed-param";
+pub(crate) const DUPLICATE_PARAM: &str = "duplicate-param";
+pub(crate) const EVENT_SHADOWS_FUNCTION: &str = "event-shadows-function";
+pub(crate) const FORBIDDEN_PAYABLE: &str = "forbidden-payable";
+pub(crate) const NON_CANONICAL_TYPE: &str = "non-canonical-type";
+
+pub(crate) const RULES: &[Rule] = &[
+    Rule {
+        id: SELECTOR_COLLISION,
+        default: Level::Deny,
+    },
+    Rule {
+        id: SUSPICIOUS_SELECTOR,
+        default: Level::Warn,
+    },
+    Rule {
+        id: UNNAMED_PARAM,
+        default: Level::Warn,
+    },
+    Rule {
+        id: DUPLICATE_PARAM,
+        default: Level::Deny,
+    },
+    Rule {
+        id: EVENT_SHADOWS_FUNCTION,
+        default: Level::Warn,
+    },
+    Rule {
+        id: FORBIDDEN_PAYABLE,
+        default: Level::Deny,
+    },
+    Rule {
+        id: NON_CANONICAL_TYPE,
+        default: Level::Deny,
+    },
+];
+
+/// Level of every rule: the defaults, overridden by the `[lint]` table.
+pub(crate) struct Config {
+    levels: BTreeMap<&'static str, Level>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            levels: RULES.iter().map(|rule| (rule.id, rule.default)).collect(),
+        }
+    }
+}
+
+impl Config {
+    /// Applies the `[lint]` table of `sipb.toml`, if there is one.
+    pub(crate) fn from_table(lint: Option<&toml_edit::Item>) -> anyhow::Result<Self> {
+        let mut config = Self::default();
+        let Some(lint) = lint else {
+            return Ok(config);
+        };
+        let lint = lint
+            .as_table_like()
+            .ok_or_else(|| anyhow::anyhow!("[lint] must be a table"))?;
+        for (id, level) in lint.iter() {
+            let Some(rule) = RULES.iter().find(|rule| rule.id == id) else {
+                let known: Vec<&str> = RULES.iter().map(|rule| rule.id).collect();
+                anyhow::bail!("unknown lint rule `{}` (known: {})", id, known.join(", "));
+            };
+            let level = level.as_str().and_then(Level::parse).ok_or_else(|| {
+                anyhow::anyhow!("lint rule `{}` must be \"allow\", \"warn\" or \"deny\"", id)
+            })?;
+            config.levels.insert(rule.id, level);
+        }
+        Ok(config)
+    }
+
+    pub(crate) fn level(&self, rule: &str) -> Level {
+        self.levels[rule]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Finding {
+    pub(crate) rule: &'static str,
+    pub(crate) message: String,
+}
+
+fn finding(rule: &'static str, message: String) -> Finding {
+    Finding { rule, message }
+}
+
+fn selector_hex(selector: [u8; 4]) -> String {
+    format!("0x{}", hex::encode(selector))
+}
+
+/// Different signatures hashing to the same selector; only one of them can be dispatched.
+fn selector_collisions(abi: &JsonAbi) -> Vec<Finding> {
+    let mut signatures: BTreeMap<[u8; 4], BTreeSet<String>> = BTreeMap::new();
+    for function in abi.functions() {
+        signatures
+            .entry(function.selector().0)
+            .or_default()
+            .insert(function.signature());
+    }
+    signatures
+        .into_iter()
+        .filter(|(_, signatures)| signatures.len() > 1)
+        .map(|(selector, signatures)| {
+            let signatures: Vec<String> = signatures.into_iter().collect();
+            finding(
+                SELECTOR_COLLISION,
+                format!(
+                    "{} share selector {}",
+                    signatures.join(" and "),
+                    selector_hex(selector)
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Why `selector` looks mined or reserved, if it does.
+fn suspicious(selector: [u8; 4]) -> Option<&'static str> {
+    match selector {
+        [0, 0, 0, 0] => Some("is zero, which is what calls with short or zeroed calldata match"),
+        [0xff, 0xff, 0xff, 0xff] => Some("is 0xffffffff, the ERC-165 invalid interface id"),
+        [0, 0, _, _] => Some("starts with two zero bytes, which is 1 in 65536 unless mined"),
+        _ => None,
+    }
+}
+
+fn suspicious_selectors(abi: &JsonAbi) -> Vec<Finding> {
+    abi.functions()
+        .filter_map(|function| {
+            let reason = suspicious(function.selector().0)?;
+            Some(finding(
+                SUSPICIOUS_SELECTOR,
+                format!(
+                    "{}: selector {} {}",
+                    function.signature(),
+                    selector_hex(function.selector().0),
+                    reason
+                ),
+            ))
+        })
+        .collect()
+}
+
+/// Unnamed and duplicate input names of functions, events and errors.
+fn parameter_names(abi: &JsonAbi) -> Vec<Finding> {
+    let items = abi
+        .functions()
+        .map(|f| {
+            (
+                f.signature(),
+                f.inputs.iter().map(|p| p.name.as_str()).collect(),
+            )
+        })
+        .chain(abi.events().map(|e| {
+            let names: Vec<&str> = e.inputs.iter().map(|p| p.name.as_str()).collect();
+            (e.signature(), names)
+        }))
+        .chain(abi.errors().map(|e| {
+            let names: Vec<&str> = e.inputs.iter().map(|p| p.name.as_str()).collect();
+            (e.signature(), names)
+        }));
+    let mut findings = Vec::new();
+    for (signature, names) in items {
+        let mut seen = BTreeSet::new();
+        for (index, name) in names.into_iter().enumerate() {
+            if name.is_empty() {
+                findings.push(finding(
+                    UNNAMED_PARAM,
+                    format!("{}: parameter {} is unnamed", signature, index),
+                ));
+            } else if !seen.insert(name) {
+                findings.push(finding(
+                    DUPLICATE_PARAM,
+                    format!("{}: parameter `{}` is declared twice", signature, name),
+                ));
+            }
+        }
+    }
+    findings
+}
+
+fn events_shadowing_functions(abi: &JsonAbi) -> Vec<Finding> {
+    abi.events()
+        .filter(|event| abi.function(&event.name).is_some())
+        .map(|event| {
+            finding(
+                EVENT_SHADOWS_FUNCTION,
+                format!("event {} has the name of a function", event.signature()),
+            )
+        })
+        .collect()
+}
+
+/// ERC721 functions its EIP declares `payable`; abis/erc721.json follows OpenZeppelin instead.
+const ERC721_PAYABLE: &[&str] = &[
+    "approve(address,uint256)",
+    "safeTransferFrom(address,address,uint256)",
+    "safeTransferFrom(address,address,uint256,bytes)",
+    "transferFrom(address,address,uint256)",
+];
+
+/// `payable` functions of a standard the ABI implements, where the standard doesn't allow it.
+/// An ABI implements a standard when it has every one of the standard's functions.
+fn forbidden_payable(abi: &JsonAbi) -> Vec<Finding> {
+    let signatures: BTreeSet<String> = abi.functions().map(Function::signature).collect();
+    let mut findings = Vec::new();
+    for standard in Standard::ALL {
+        let standard_abi = standard.abi();
+        if !standard_abi
+            .functions()
+            .all(|function| signatures.contains(&function.signature()))
+        {
+            continue;
+        }
+        for function in abi.functions() {
+            let signature = function.signature();
+            let allowed = *standard == Standard::Erc721 && ERC721_PAYABLE.contains(&&*signature);
+            let in_standard = standard_abi.functions().any(|f| f.signature() == signature);
+            if function.state_mutability == StateMutability::Payable && in_standard && !allowed {
+                findings.push(finding(
+                    FORBIDDEN_PAYABLE,
+                    format!(
+                        "{}: {} does not allow it to be payable",
+                        signature,
+                        standard.name()
+                    ),
+                ));
+            }
+        }
+    }
+    findings
+}
+
+/// Canonical spelling of an elementary type alias, e.g. `uint256` for `uint`.
+fn canonical(ty: &str) -> Option<&'static str> {
+    match ty {
+        "uint" => Some("uint256"),
+        "int" => Some("int256"),
+        "byte" => Some("bytes1"),
+        "fixed" => Some("fixed128x18"),
+        "ufixed" => Some("ufixed128x18"),
+        _ => None,
+    }
+}
+
+/// Aliases in `ty` and its tuple `components`, spelled canonically, e.g. `int256[2]` for `int[2]`.
+fn aliases(ty: &str, components: &[Param], found: &mut Vec<(String, String)>) {
+    let (base, suffix) = ty.split_at(ty.find('[').unwrap_or(ty.len()));
+    if let Some(canonical) = canonical(base) {
+        found.push((ty.to_string(), format!("{}{}", canonical, suffix)));
+    }
+    for component in components {
+        aliases(&component.ty, &component.components, found);
+    }
+}
+
+/// Aliases such as `uint`: selectors and topics hash the canonical names, so the ABI's own
+/// selectors come out wrong.
+fn non_canonical_types(abi: &JsonAbi) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut report = |name: &str, found: Vec<(String, String)>| {
+        for (alias, canonical) in found {
+            findings.push(finding(
+                NON_CANONICAL_TYPE,
+                format!("{}: `{}` should be spelled `{}`", name, alias, canonical),
+            ));
+        }
+    };
+    for function in abi.functions() {
+        let mut found = Vec::new();
+        for param in function.inputs.iter().chain(&function.outputs) {
+            aliases(&param.ty, &param.components, &mut found);
+        }
+        report(&function.name, found);
+    }
+    for event in abi.events() {
+        let mut found = Vec::new();
+        for param in &event.inputs {
+            aliases(&param.ty, &param.components, &mut found);
+        }
+        report(&event.name, found);
+    }
+    findings
+}
+
+/// Findings of every rule for `abi`, in rule order.
+pub(crate) fn lint(abi: &JsonAbi) -> Vec<Finding> {
+    let mut findings = selector_collisions(abi);
+    findings.extend(suspicious_selectors(abi));
+    findings.extend(parameter_names(abi));
+    findings.extend(events_shadowing_functions(abi));
+    findings.extend(forbidden_payable(abi));
+    findings.extend(non_canonical_types(abi));
+    findings
+}
+
+/// Prints every finding that isn't allowed; returns whether none is denied.
+pub(crate) fn run(args: &Args) -> anyhow::Result<bool> {
+    let config = match config::load(args.config.as_deref())? {
+        Some(config) => config.lint,
+        None => Config::default(),
+    };
+    let mut reported = Vec::new();
+    for path in &args.abis {
+        let abi: JsonAbi = serde_json::from_str(&fs::read_to_string(path)?)
+            .map_err(|e| anyhow::anyhow!("{}: {}", path.display(), e))?;
+        for finding in lint(&abi) {
+            let level = config.level(finding.rule);
+            if level != Level::Allow {
+                reported.push((path, level, finding));
+            }
+        }
+    }
+    let errors = reported
+        .iter()
+        .filter(|(_, level, _)| *level == Level::Deny)
+        .count();
+    let warnings = reported.len() - errors;
+
+    match args.format {
+        Format::Text => {
+            for (path, level, finding) in &reported {
+                let label = if *level == Level::Deny {
+                    "error"
+                } else {
+                    "warning"
+                };
+                println!(
+                    "{}[{}]: {}: {}",
+                    label,
+                    finding.rule,
+                    path.display(),
+                    finding.message
+                );
+            }
+            println!("{} error(s), {} warning(s)", errors, warnings);
+        }
+        Format::Json => {
+            let findings: Vec<serde_json::Value> = reported
+                .iter()
+                .map(|(path, level, finding)| {
+                    json!({
+                        "file": path.display().to_string(),
+                        "rule": finding.rule,
+                        "level": level.name(),
+                        "message": finding.message,
+                    })
+                })
+                .collect();
+            let report = json!({ "findings": findings, "errors": errors, "warnings": warnings });
+            println!("{:#}", report);
+        }
+    }
+    Ok(errors == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn abi(json: &str) -> JsonAbi {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn rules(findings: &[Finding]) -> Vec<&'static str> {
+        findings.iter().map(|finding| finding.rule).collect()
+    }
+
+    #[test]
+    fn standards_are_clean() {
+        for standard in Standard::ALL {
+            assert_eq!(lint(&standard.abi()), [], "{}", standard.name());
+        }
+    }
+
+    #[test]
+    fn colliding_selectors() {
+        // The classic proxy clash: both hash to 0x42966c68.
+        let abi = JsonAbi::parse([
+            "function burn(uint256 amount)",
+            "function collate_propagate_storage(bytes16 slot)",
+        ])
+        .unwrap();
+        assert_eq!(
+            lint(&abi),
+            [finding(
+                SELECTOR_COLLISION,
+                "burn(uint256) and collate_propagate_storage(bytes16) share selector 0x42966c68"
+                    .into()
+            )]
+        );
+    }
+
+    #[test]
+    fn suspicious_selectors_are_flagged() {
+        assert!(suspicious([0, 0, 0, 0]).is_some());
+        assert!(suspicious([0xff; 4]).is_some());
+        assert!(suspicious([0, 0, 0x12, 0x34]).is_some());
+        assert!(suspicious([0, 0x01, 0x12, 0x34]).is_none());
+        // wycpnbqcyf() hashes to 0x00000000.
+        let abi = JsonAbi::parse(["function wycpnbqcyf()"]).unwrap();
+        assert_eq!(rules(&lint(&abi)), [SUSPICIOUS_SELECTOR]);
+    }
+
+    #[test]
+    fn unnamed_and_duplicate_params() {
+        let abi = abi(r#"[
+            {"type":"function","name":"f","inputs":[{"name":"","type":"address"},{"name":"a","type":"uint256"},{"name":"a","type":"bool"}],"outputs":[{"name":"","type":"bool"}],"stateMutability":"nonpayable"},
+            {"type":"event","name":"E","inputs":[{"name":"","type":"address","indexed":true}],"anonymous":false}
+        ]"#);
+        let findings = lint(&abi);
+        assert_eq!(
+            rules(&findings),
+            [UNNAMED_PARAM, DUPLICATE_PARAM, UNNAMED_PARAM]
+        );
+        assert_eq!(
+            findings[1].message,
+            "f(address,uint256,bool): parameter `a` is declared twice"
+        );
+    }
+
+    #[test]
+    fn events_named_like_functions() {
+        let abi = JsonAbi::parse([
+            "function transfer(address to, uint256 value) returns (bool)",
+            "event transfer(address indexed to, uint256 value)",
+        ])
+        .unwrap();
+        assert_eq!(rules(&lint(&abi)), [EVENT_SHADOWS_FUNCTION]);
+    }
+
+    #[test]
+    fn payable_only_where_standards_allow() {
+        let mut erc20 = Standard::Erc20.abi();
+        erc20.functions.get_mut("transfer").unwrap()[0].state_mutability = StateMutability::Payable;
+        assert_eq!(rules(&lint(&erc20)), [FORBIDDEN_PAYABLE]);
+
+        let mut erc721 = Standard::Erc721.abi();
+        for function in erc721.functions.get_mut("safeTransferFrom").unwrap() {
+            function.state_mutability = StateMutability::Payable;
+        }
+        assert_eq!(lint(&erc721), []);
+        erc721.functions.get_mut("setApprovalForAll").unwrap()[0].state_mutability =
+            StateMutability::Payable;
+        assert_eq!(rules(&lint(&erc721)), [FORBIDDEN_PAYABLE]);
+
+        // Not an ERC20: payable `transfer` is fine.
+        let abi = crate::tests::parse_abi(&[
+            "function transfer(address to, uint256 value) payable returns (bool)",
+        ]);
+        assert_eq!(lint(&abi), []);
+    }
+
+    #[test]
+    fn type_aliases() {
+        let abi = abi(r#"[
+            {"type":"function","name":"f","inputs":[{"name":"a","type":"uint"},{"name":"b","type":"int[2]"}],"outputs":[{"name":"","type":"byte"}],"stateMutability":"view"}
+        ]"#);
+        let messages: Vec<String> = lint(&abi).into_iter().map(|f| f.message).collect();
+        assert_eq!(
+            messages,
+            [
+                "f: `uint` should be spelled `uint256`",
+                "f: `int[2]` should be spelled `int256[2]`",
+                "f: `byte` should be spelled `bytes1`",
+            ]
+        );
+    }
+
+    fn parse(toml: &str) -> anyhow::Result<Config> {
+        config::Config::parse(toml, std::path::Path::new("")).map(|config| config.lint)
+    }
+
+    #[test]
+    fn config_overrides_levels() {
+        let config =
+            parse("[lint]\nunnamed-param = \"allow\"\nsuspicious-selector = \"deny\"\n").unwrap();
+        assert_eq!(config.level(UNNAMED_PARAM), Level::Allow);
+        assert_eq!(config.level(SUSPICIOUS_SELECTOR), Level::Deny);
+        assert_eq!(config.level(SELECTOR_COLLISION), Level::Deny);
+        assert_eq!(parse("").unwrap().level(UNNAMED_PARAM), Level::Warn);
+    }
+
+    #[test]
+    fn config_rejects_unknown_rules_and_levels() {
+        let unknown = parse("[lint]\nno-such-rule = \"warn\"\n").err().unwrap();
+        assert!(unknown
+            .to_string()
+            .starts_with("unknown lint rule `no-such-rule`"));
+        assert!(parse("[lint]\nunnamed-param = \"error\"\n").is_err());
+    }
+}
@@ -1,95 +1,2444 @@
-use alloy_json_abi::JsonAbi;
-use alloy_primitives::{hex, U128};
-use clap::Parser;
-use heck::ToSnakeCase;
+use alloy_json_abi::{Function, JsonAbi, Param, StateMutability};
+use alloy_primitives::hex;
+use clap::{Parser, Subcommand, ValueEnum};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+mod config;
+mod conforms;
+mod diff;
+mod filter;
+mod internal_types;
+mod lint;
+mod overrides;
+mod source;
+mod verify_bytecode;
 
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
-    #[arg(short, long)]
-    input: PathBuf,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Project configuration to regenerate when no input is given (default: `sipb.toml`)
+    #[arg(long, conflicts_with = "source")]
+    config: Option<PathBuf>,
+
+    #[command(flatten)]
+    target: Target,
+}
+
+/// What to generate and where: the generation flags, or one `[[target]]` of `sipb.toml`.
+#[derive(clap::Args, Default)]
+struct Target {
+    /// ABI JSON, compiler artifact with an `abi` field, or Solidity source (needs `solc`)
+    #[arg(short, long, group = "source", requires = "output")]
+    input: Option<PathBuf>,
+
+    #[arg(short, long, requires = "input")]
+    output: Option<PathBuf>,
+
+    /// Generate one module per `*.json` ABI in this directory instead of a single `--input`
+    #[arg(long, group = "source", requires = "out_dir", conflicts_with = "name")]
+    input_dir: Option<PathBuf>,
+
+    /// Directory for `--input-dir` modules and their `mod.rs`
+    #[arg(long, requires = "input_dir")]
+    out_dir: Option<PathBuf>,
+
+    /// Write the modules' index as `lib.rs` instead of `mod.rs`
+    #[arg(long, requires = "input_dir")]
+    lib: bool,
+
+    /// Make `--out-dir` a crate with this package name: `Cargo.toml`, `src/lib.rs` and modules
+    #[arg(
+        long = "crate",
+        value_name = "NAME",
+        requires = "input_dir",
+        conflicts_with = "lib"
+    )]
+    crate_name: Option<String>,
+
+    /// `stylus-interfaces` dependency of the `--crate`: a version, `path=DIR` (relative to the
+    /// crate) or `git=URL` (default: this release's version)
+    #[arg(
+        long,
+        value_name = "VERSION|path=DIR|git=URL",
+        value_parser = Dependency::parse,
+        requires = "crate_name"
+    )]
+    interfaces_dep: Option<Dependency>,
+
+    /// Also generate a `DelegateContract` whose methods call through DELEGATECALL
+    #[arg(long, requires = "source")]
+    delegate: bool,
+
+    /// Methods take a stylus-sdk call context instead of going through a `Transport`
+    #[arg(long, requires = "source")]
+    call_context: bool,
+
+    /// Decode this function's outputs lazily (name, signature or 0x selector; repeatable)
+    #[arg(long, value_name = "FUNCTION", requires = "source")]
+    lazy: Vec<String>,
+
+    /// Interface name for the `<NAME>Api` trait and `Mock<NAME>` (default: input file stem)
+    #[arg(long, requires = "input")]
+    name: Option<String>,
+
+    /// Only generate functions matching a name glob, signature or 0x selector (repeatable)
+    #[arg(long, value_name = "FUNCTION", requires = "source")]
+    include: Vec<String>,
+
+    /// Skip functions matching a name glob, signature or 0x selector (repeatable)
+    #[arg(long, value_name = "FUNCTION", requires = "source")]
+    exclude: Vec<String>,
+
+    /// Only generate `view` and `pure` functions
+    #[arg(long, requires = "source")]
+    only_view: bool,
+
+    /// Use a Rust type for the parameters a Solidity type, internalType or `function.param`
+    /// glob selects, e.g. `*.tokenId=crate::TokenId` (repeatable)
+    #[arg(
+        long = "type",
+        value_name = "KEY=TYPE",
+        value_parser = overrides::Override::parse,
+        requires = "source"
+    )]
+    types: Vec<overrides::Override>,
+
+    /// Name the variants of a Solidity enum, e.g. `Side=Buy,Sell`, to generate it as a Rust
+    /// enum (repeatable)
+    #[arg(
+        long = "enum",
+        value_name = "NAME=VARIANTS",
+        value_parser = internal_types::EnumVariants::parse,
+        requires = "source"
+    )]
+    enums: Vec<internal_types::EnumVariants>,
+
+    /// Type `contract IERC20`, `IERC721`, `IERC1155` and `IERC165` parameters as that
+    /// `stylus_interfaces` pack's `Contract` instead of an address newtype
+    #[arg(long, requires = "source")]
+    pack_handles: bool,
+
+    /// Also emit `INTERFACE_ID`, the ERC-165 interface ID of the generated functions
+    #[arg(long, requires = "source")]
+    subset: bool,
+
+    /// What to generate
+    #[arg(long, value_enum, default_value_t = Mode::Bindings, requires = "source")]
+    mode: Mode,
+
+    /// When generated names carry the function's selector
+    #[arg(long, value_enum, default_value_t = Naming::Selector, requires = "source")]
+    naming: Naming,
+
+    /// Name the module of an `--input-dir` ABI, by file stem, e.g. `IERC20=erc20` (repeatable)
+    #[arg(
+        long = "module",
+        value_name = "STEM=MODULE",
+        value_parser = ModuleName::parse,
+        requires = "input_dir"
+    )]
+    modules: Vec<ModuleName>,
+
+    /// Format generated files with `rustfmt`
+    #[arg(long, requires = "source")]
+    rustfmt: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Check an ABI against a standard interface: missing functions, return types, mutability
+    /// and events
+    Conforms(conforms::Args),
+    /// Check that runtime bytecode dispatches an ABI's selectors
+    VerifyBytecode(verify_bytecode::Args),
+    /// Flag risky or ambiguous ABIs; rule levels are configured in `sipb.toml`
+    Lint(lint::Args),
+    /// Compare two versions of an ABI by selector and classify breaking changes
+    Diff(diff::Args),
+}
+
+/// Report format of the checking subcommands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+enum Mode {
+    /// Bindings for calling a deployed contract
+    #[default]
+    Bindings,
+    /// A trait and `#[public]` router for implementing the interface in Stylus
+    Implement,
+}
+
+/// How generated Rust names tell the ABI's functions apart.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+enum Naming {
+    /// Every name carries its selector, e.g. `transfer__0xa9059cbb`
+    #[default]
+    Selector,
+    /// Only names an overload, a keyword or a generated method would clash with carry one
+    Overloads,
+}
+
+/// Code generation switches.
+#[derive(Default)]
+struct Options {
+    mode: Mode,
+    delegate: bool,
+    call_context: bool,
+    /// Functions, by name, signature or `0x` selector, whose outputs are decoded lazily.
+    lazy: Vec<String>,
+    /// UpperCamelCase interface name, e.g. `Erc20` for `Erc20Api` and `MockErc20`.
+    name: String,
+    /// Emit the generated functions' ERC-165 interface ID.
+    interface_id: bool,
+    /// `--type` overrides of parameter types.
+    types: Vec<overrides::Override>,
+    /// `--enum` variants of enum `internalType`s.
+    enums: Vec<internal_types::EnumVariants>,
+    /// `--pack-handles`: standard interface `contract` types are the packs' `Contract`s.
+    pack_handles: bool,
+    /// Signatures of the functions whose names go without a selector suffix.
+    plain: BTreeSet<String>,
+}
+
+/// True if `pattern` names `function` by Solidity name glob, full signature or `0x` selector.
+fn names_function(pattern: &str, function: &Function) -> bool {
+    filter::glob_matches(pattern, &function.name)
+        || pattern == function.signature()
+        || pattern.strip_prefix("0x") == Some(hex::encode(function.selector()).as_str())
+}
+
+fn is_lazy(function: &Function, options: &Options) -> bool {
+    options
+        .lazy
+        .iter()
+        .any(|pattern| names_function(pattern, function))
+}
+
+/// Element type and length of an array type: `("uint256", None)` for `uint256[]`, `("bool",
+/// Some(2))` for `bool[2]`.
+fn array_type(sol_type: &str) -> Option<(&str, Option<usize>)> {
+    let (element, len) = sol_type.strip_suffix(']')?.rsplit_once('[')?;
+    match len {
+        "" => Some((element, None)),
+        _ => Some((element, Some(len.parse().ok()?))),
+    }
+}
+
+/// Bit width of `prefix<N>`, e.g. 96 for `uint96`; a multiple of 8 up to 256.
+fn int_bits(sol_type: &str, prefix: &str) -> Option<usize> {
+    let bits: usize = sol_type.strip_prefix(prefix)?.parse().ok()?;
+    (bits.is_multiple_of(8) && (8..=256).contains(&bits)).then_some(bits)
+}
+
+/// Signedness, bit width and decimals of `fixed<M>x<N>` or `ufixed<M>x<N>`, e.g. `(true, 128,
+/// 18)` for `fixed128x18`; 1 to 80 decimals. Bare `fixed` and `ufixed` are `fixed128x18` and
+/// `ufixed128x18`.
+fn fixed_point(sol_type: &str) -> Option<(bool, usize, usize)> {
+    let (signed, size) = match sol_type.strip_prefix("ufixed") {
+        Some(size) => (false, size),
+        None => (true, sol_type.strip_prefix("fixed")?),
+    };
+    if size.is_empty() {
+        return Some((signed, 128, 18));
+    }
+    let (bits, decimals) = size.split_once('x')?;
+    let decimals: usize = decimals.parse().ok()?;
+    Some((signed, int_bits(bits, "")?, decimals)).filter(|_| (1..=80).contains(&decimals))
+}
+
+/// Canonical spelling of a fixed-point type, e.g. `ufixed128x18`.
+fn fixed_point_name(signed: bool, bits: usize, decimals: usize) -> String {
+    format!(
+        "{}fixed{}x{}",
+        if signed { "" } else { "u" },
+        bits,
+        decimals
+    )
+}
+
+/// Length of `bytes<N>`, from 1 to 32.
+fn fixed_bytes_len(sol_type: &str) -> Option<usize> {
+    let len: usize = sol_type.strip_prefix("bytes")?.parse().ok()?;
+    (1..=32).contains(&len).then_some(len)
+}
+
+/// Rust integer decoded from a `bits`-wide ABI integer: the smallest primitive holding it, or
+/// `U256`/`I256` above 128 bits, as `alloy_sol_types` does.
+fn int_rust_type(bits: usize, signed: bool) -> String {
+    let width = match bits {
+        8 | 16 => bits,
+        24 | 32 => 32,
+        40..=64 => 64,
+        72..=128 => 128,
+        _ => return if signed { "I256" } else { "U256" }.to_string(),
+    };
+    format!("{}{}", if signed { 'i' } else { 'u' }, width)
+}
+
+fn to_rust_type(sol_type: &str) -> String {
+    if let Some((element, len)) = array_type(sol_type) {
+        // Elements are the decoded types, so `bytes[]` is `Vec<Bytes>` both ways.
+        let element = to_rust_return_type(element);
+        return match len {
+            None => format!("Vec<{}>", element),
+            Some(len) => format!("[{}; {}]", element, len),
+        };
+    }
+    if let Some(bits) = int_bits(sol_type, "uint") {
+        return int_rust_type(bits, false);
+    }
+    if let Some(bits) = int_bits(sol_type, "int") {
+        return int_rust_type(bits, true);
+    }
+    if let Some(len) = fixed_bytes_len(sol_type) {
+        return format!("FixedBytes<{}>", len);
+    }
+    if let Some((signed, bits, _)) = fixed_point(sol_type) {
+        return int_rust_type(bits, signed);
+    }
+    match sol_type {
+        "address" => "Address".to_string(),
+        "bool" => "bool".to_string(),
+        "bytes" => "Vec<u8>".to_string(),
+        "string" => "String".to_string(),
+        "function" => "Function".to_string(),
+        _ => unreachable!("unsupported Solidity type in ABI: {}", sol_type),
+    }
+}
+
+/// Rust type produced when decoding `sol_type` from return data.
+fn to_rust_return_type(sol_type: &str) -> String {
+    match sol_type {
+        "bytes" => "Bytes".to_string(),
+        _ => to_rust_type(sol_type),
+    }
+}
+
+/// `alloy_sol_types::sol_data` type used to encode and decode `sol_type`.
+fn to_sol_data_type(sol_type: &str) -> String {
+    if let Some((element, len)) = array_type(sol_type) {
+        return match len {
+            None => format!("sol_data::Array<{}>", to_sol_data_type(element)),
+            Some(len) => format!(
+                "sol_data::FixedArray<{}, {}>",
+                to_sol_data_type(element),
+                len
+            ),
+        };
+    }
+    if let Some(bits) = int_bits(sol_type, "uint") {
+        return format!("sol_data::Uint<{}>", bits);
+    }
+    if let Some(bits) = int_bits(sol_type, "int") {
+        return format!("sol_data::Int<{}>", bits);
+    }
+    if let Some(len) = fixed_bytes_len(sol_type) {
+        return format!("sol_data::FixedBytes<{}>", len);
+    }
+    if let Some((signed, bits, _)) = fixed_point(sol_type) {
+        // Fixed-point values encode as their raw integer.
+        return format!(
+            "sol_data::{}<{}>",
+            if signed { "Int" } else { "Uint" },
+            bits
+        );
+    }
+    match sol_type {
+        "address" => "sol_data::Address".to_string(),
+        "bool" => "sol_data::Bool".to_string(),
+        "bytes" => "sol_data::Bytes".to_string(),
+        "string" => "sol_data::String".to_string(),
+        "function" => "sol_data::Function".to_string(),
+        _ => unreachable!("unsupported Solidity type in ABI: {}", sol_type),
+    }
+}
+
+/// Renders a list of types or values as a tuple, e.g. `(A, B)` or `(A,)`.
+fn tuple_of(items: &[String]) -> String {
+    match items.len() {
+        1 => format!("({},)", items[0]),
+        _ => format!("({})", items.join(", ")),
+    }
+}
+
+/// `alloy_primitives` names referenced by the generated code, in import order.
+fn primitive_imports(abi: &JsonAbi, options: &Options) -> Vec<&'static str> {
+    const PRIMITIVES: [&str; 6] = ["Address", "Bytes", "FixedBytes", "Function", "I256", "U256"];
+    let mut used: BTreeSet<&'static str> = BTreeSet::from(["Address"]);
+    if !options.call_context {
+        used.insert("U256"); // CallOpts::value
+    }
+    for function in abi.functions() {
+        let inputs = function
+            .inputs
+            .iter()
+            .map(|p| param_type(function, p, options));
+        let outputs = function
+            .outputs
+            .iter()
+            .map(|p| return_param_type(function, p, options));
+        let underlying =
+            internal_types::definitions(abi, &options.enums, &options.types, options.pack_handles)
+                .into_iter()
+                .filter_map(|t| t.underlying());
+        let raw = generated_types(abi, options)
+            .into_iter()
+            .filter_map(|sol_type| fixed_point(&sol_type))
+            .map(|(signed, bits, _)| int_rust_type(bits, signed));
+        for ty in inputs.chain(outputs).chain(underlying).chain(raw) {
+            for token in ty.split(|c: char| !c.is_ascii_alphanumeric()) {
+                if let Some(name) = PRIMITIVES.iter().find(|name| **name == token) {
+                    used.insert(name);
+                }
+            }
+        }
+    }
+    used.into_iter().collect()
+}
+
+/// Methods of the generated types, which a function's plain name would clash with.
+const GENERATED_METHODS: &[&str] = &[
+    "abi_decode",
+    "abi_encode",
+    "borrow",
+    "call",
+    "calls",
+    "fail",
+    "fmt",
+    "from",
+    "from_raw",
+    "new",
+    "raw",
+    "selector",
+    "send",
+    "send_delegate",
+    "static_call",
+    "try_from",
+    "with",
+    "with_transport",
+];
+
+/// Signatures of the functions `naming` names without a selector suffix: none for
+/// `Naming::Selector`; for `Naming::Overloads`, those whose snake_case name no other function
+/// shares and isn't a keyword or a `GENERATED_METHODS` name.
+fn plain_names(abi: &JsonAbi, naming: Naming) -> BTreeSet<String> {
+    if naming == Naming::Selector {
+        return BTreeSet::new();
+    }
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for function in abi.functions() {
+        *counts.entry(function.name.to_snake_case()).or_default() += 1;
+    }
+    abi.functions()
+        .filter(|function| {
+            let name = function.name.to_snake_case();
+            !name.is_empty()
+                && counts[&name] == 1
+                && !KEYWORDS.contains(&name.as_str())
+                && !GENERATED_METHODS.contains(&name.as_str())
+        })
+        .map(Function::signature)
+        .collect()
+}
+
+/// `name` with `function`'s selector, e.g. `transfer__0xa9059cbb`, unless `options` leaves
+/// `function` plain.
+fn with_selector(name: String, function: &Function, options: &Options) -> String {
+    if options.plain.contains(&function.signature()) {
+        name
+    } else {
+        format!("{}__0x{}", name, hex::encode(function.selector()))
+    }
+}
+
+/// Rust name for `function`, e.g. `transfer__0xa9059cbb`.
+fn safe_name(function: &Function, options: &Options) -> String {
+    with_selector(function.name.to_snake_case(), function, options)
+}
+
+/// Type generated for a Solidity type without an alloy Rust type of its own: `ExternalFunction`
+/// for `function`, and a fixed-point newtype such as `Fixed128x18` for `fixed128x18` or `fixed`.
+fn generated_type(sol_type: &str) -> Option<String> {
+    if sol_type == "function" {
+        return Some("ExternalFunction".to_string());
+    }
+    fixed_point(sol_type).map(|(signed, bits, decimals)| {
+        let name = fixed_point_name(signed, bits, decimals);
+        format!("{}{}", name[..1].to_uppercase(), &name[1..])
+    })
+}
+
+/// Type replacing the Rust type of `param`: its `--type` override, else the type generated for
+/// its `internalType`, else its `generated_type`.
+fn custom_type(function: &Function, param: &Param, options: &Options) -> Option<String> {
+    match overrides::lookup(&options.types, function, param) {
+        Some(rust_type) => Some(rust_type.to_string()),
+        None => internal_types::lookup(param, &options.enums, options.pack_handles)
+            .map(|t| t.rust_type())
+            .or_else(|| generated_type(&param.ty)),
+    }
+}
+
+/// Solidity types of `abi` whose parameters decode into a `generated_type`, spelled canonically so
+/// `fixed` and `fixed128x18` share one newtype.
+fn generated_types(abi: &JsonAbi, options: &Options) -> BTreeSet<String> {
+    abi.functions()
+        .flat_map(|function| {
+            function
+                .inputs
+                .iter()
+                .chain(&function.outputs)
+                .filter(move |param| {
+                    generated_type(&param.ty)
+                        .is_some_and(|t| custom_type(function, param, options) == Some(t))
+                })
+                .map(|param| match fixed_point(&param.ty) {
+                    Some((signed, bits, decimals)) => fixed_point_name(signed, bits, decimals),
+                    None => param.ty.clone(),
+                })
+        })
+        .collect()
+}
+
+/// True if `param` converts from its decoded value with `TryFrom`, as generated enums do.
+fn is_checked(function: &Function, param: &Param, options: &Options) -> bool {
+    overrides::lookup(&options.types, function, param).is_none()
+        && internal_types::lookup(param, &options.enums, options.pack_handles)
+            .is_some_and(|t| t.is_checked())
+}
+
+/// Rust type of input `param` of `function`: its `custom_type`, or `to_rust_type`.
+fn param_type(function: &Function, param: &Param, options: &Options) -> String {
+    custom_type(function, param, options).unwrap_or_else(|| to_rust_type(&param.ty))
+}
+
+/// Rust type of output `param` of `function`: its `custom_type`, or `to_rust_return_type`.
+fn return_param_type(function: &Function, param: &Param, options: &Options) -> String {
+    custom_type(function, param, options).unwrap_or_else(|| to_rust_return_type(&param.ty))
+}
+
+/// `name: Type` parameter list shared by contract methods and encoders.
+fn input_params(function: &Function, options: &Options) -> String {
+    function
+        .inputs
+        .iter()
+        .zip(input_names(function))
+        .map(|(input, name)| format!("{}: {}", name, param_type(function, input, options)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Rust name of input `index`: `arg<index>` when unnamed, and a raw identifier such as `r#type`
+/// for a keyword. `self`, `Self`, `super` and `crate` can't be raw, so they get a trailing `_`.
+fn input_name(name: &str, index: usize) -> String {
+    match name {
+        "" | "_" => format!("arg{}", index),
+        "self" | "Self" | "super" | "crate" => format!("{}_", name),
+        _ if KEYWORDS.contains(&name) => format!("r#{}", name),
+        _ => name.to_string(),
+    }
+}
+
+fn input_names(function: &Function) -> Vec<String> {
+    function
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(index, input)| input_name(&input.name, index))
+        .collect()
+}
+
+/// Whether `function` can be called with STATICCALL.
+fn is_view(function: &Function) -> bool {
+    matches!(
+        function.state_mutability,
+        StateMutability::View | StateMutability::Pure
+    )
+}
+
+/// `CallKind` for `function`: STATICCALL for view/pure, CALL with the configured value otherwise.
+fn call_kind(function: &Function) -> &'static str {
+    if is_view(function) {
+        "CallKind::StaticCall"
+    } else {
+        "CallKind::Call"
+    }
+}
+
+/// Call context trait required by `function` in `--call-context` mode, as `sol_interface!` does.
+fn context_trait(function: &Function) -> &'static str {
+    match function.state_mutability {
+        StateMutability::View | StateMutability::Pure => "StaticCallContext",
+        StateMutability::NonPayable => "NonPayableCallContext",
+        StateMutability::Payable => "MutatingCallContext",
+    }
+}
+
+/// Renders the tail of a `use` path: `Name` for one item, `{A, B}` for several.
+fn use_group(names: &[&str]) -> String {
+    match names {
+        [name] => name.to_string(),
+        _ => format!("{{{}}}", names.join(", ")),
+    }
+}
+
+/// `stylus_sdk::call` names referenced by the generated code, in import order.
+fn call_imports(abi: &JsonAbi, options: &Options) -> Vec<&'static str> {
+    if !options.call_context {
+        return Vec::new();
+    }
+    let mut used: BTreeSet<&'static str> = BTreeSet::new();
+    for function in abi.functions() {
+        used.insert(context_trait(function));
+        if is_view(function) {
+            used.insert("static_call");
+        } else {
+            used.insert("call");
+        }
+    }
+    if options.delegate {
+        used.insert("delegate_call");
+        used.insert("MutatingCallContext");
+    }
+    // functions before traits, matching rustfmt's import ordering
+    let (functions, traits): (Vec<_>, Vec<_>) = used
+        .into_iter()
+        .partition(|name| name.starts_with(char::is_lowercase));
+    functions.into_iter().chain(traits).collect()
+}
+
+/// True for types encoded in place as a single 32-byte word (no dynamic data, no arrays).
+fn is_word_type(sol_type: &str) -> bool {
+    !(sol_type == "bytes" || sol_type == "string" || sol_type.ends_with(']'))
+}
+
+/// True for types encoded entirely in the head: everything but `bytes`, `string`, `T[]` and
+/// arrays of those.
+fn is_static_type(sol_type: &str) -> bool {
+    match array_type(sol_type) {
+        Some((element, Some(_))) => is_static_type(element),
+        Some((_, None)) => false,
+        None => sol_type != "bytes" && sol_type != "string",
+    }
+}
+
+/// Head words of a `sol_type` output: `N` times the element's for a static `T[N]`, one for
+/// every other type (a value, or the offset of dynamic data).
+fn head_words(sol_type: &str) -> usize {
+    match array_type(sol_type) {
+        Some((element, Some(len))) if is_static_type(element) => len * head_words(element),
+        _ => 1,
+    }
+}
+
+/// Calldata length of `function` when all of its arguments are words, `None` otherwise.
+fn static_calldata_len(function: &Function) -> Option<usize> {
+    function
+        .inputs
+        .iter()
+        .all(|input| is_word_type(&input.ty))
+        .then(|| 4 + 32 * function.inputs.len())
+}
+
+/// Return type of `encode_<name>`: a stack array for static arguments, `Vec<u8>` otherwise.
+fn encoder_return_type(function: &Function) -> String {
+    match static_calldata_len(function) {
+        Some(len) => format!("[u8; {}]", len),
+        None => "Vec<u8>".to_string(),
+    }
+}
+
+/// `selectors` module with one `[u8; 4]` const per function, named like its method.
+fn generate_selectors(code: &mut String, abi: &JsonAbi, options: &Options) {
+    code.push_str("\n/// Function selectors, named like the methods that call them.\n");
+    code.push_str("#[allow(non_upper_case_globals)]\n");
+    code.push_str("pub mod selectors {\n");
+    for function in abi.functions() {
+        code.push_str(&format!("    // Original: {}\n", function.signature()));
+        code.push_str(&format!(
+            "    pub const {}: [u8; 4] = {};\n",
+            safe_name(function, options),
+            selector_array(function)
+        ));
+    }
+    code.push_str("}\n");
+}
+
+/// `INTERFACE_ID` const: what an ERC-165 contract implementing exactly these functions reports.
+fn generate_interface_id(code: &mut String, abi: &JsonAbi) {
+    let bytes: Vec<String> = filter::interface_id(abi)
+        .iter()
+        .map(|b| format!("0x{:02x}", b))
+        .collect();
+    code.push_str(
+        "\n/// ERC-165 interface ID of the functions in this module: the XOR of their selectors.\n",
+    );
+    code.push_str(&format!(
+        "pub const INTERFACE_ID: [u8; 4] = [{}];\n",
+        bytes.join(", ")
+    ));
+}
+
+/// Free function returning the full calldata (selector + ABI-encoded args) for `function`.
+///
+/// Word-only argument lists are written straight into a `[u8; 4 + 32 * n]` buffer.
+fn generate_encoder(code: &mut String, function: &Function, options: &Options) {
+    let name = safe_name(function, options);
+
+    code.push_str(&format!("\n// Original: {}\n", function.signature()));
+    code.push_str(&format!(
+        "pub fn encode_{}({}) -> {} {{\n",
+        name,
+        input_params(function, options),
+        encoder_return_type(function)
+    ));
+    if function.inputs.is_empty() {
+        code.push_str(&format!("    selectors::{}\n", name));
+    } else if let Some(len) = static_calldata_len(function) {
+        code.push_str(&format!("    let mut input = [0u8; {}];\n", len));
+        code.push_str(&format!(
+            "    input[..4].copy_from_slice(&selectors::{});\n",
+            name
+        ));
+        for (i, (param, arg)) in function
+            .inputs
+            .iter()
+            .zip(input_names(function))
+            .enumerate()
+        {
+            let offset = 4 + 32 * i;
+            code.push_str(&format!(
+                "    input[{}..{}].copy_from_slice(<{} as SolType>::tokenize(&{}).0.as_slice());\n",
+                offset,
+                offset + 32,
+                to_sol_data_type(&param.ty),
+                arg
+            ));
+        }
+        code.push_str("    input\n");
+    } else {
+        let sol_types: Vec<String> = function
+            .inputs
+            .iter()
+            .map(|input| to_sol_data_type(&input.ty))
+            .collect();
+        code.push_str(&format!(
+            "    let mut input = selectors::{}.to_vec();\n",
+            name
+        ));
+        code.push_str(&format!(
+            "    input.extend(<{}>::abi_encode_params(&{}));\n",
+            tuple_of(&sol_types),
+            tuple_of(&input_names(function))
+        ));
+        code.push_str("    input\n");
+    }
+    code.push_str("}\n");
+}
+
+/// Rust type of `function`'s decoded outputs: the bare type for one output, a tuple otherwise.
+///
+/// Lazily decoded functions return their `<Variant>Returns` view instead.
+fn output_type(function: &Function, options: &Options) -> String {
+    if is_lazy(function, options) {
+        return lazy_returns_name(function, options);
+    }
+    let rust_types: Vec<String> = function
+        .outputs
+        .iter()
+        .map(|output| return_param_type(function, output, options))
+        .collect();
+    match rust_types.len() {
+        1 => rust_types[0].clone(),
+        _ => format!("({})", rust_types.join(", ")),
+    }
+}
+
+/// `fn name(&self, ...) -> Result<T, CallError>` shared by `Contract`, the API trait and the mock.
+///
+/// In call-context mode the context parameter comes first, named `context`.
+fn method_signature(function: &Function, options: &Options, context: &str) -> String {
+    let context_param = if options.call_context {
+        format!("{}: impl {}, ", context, context_trait(function))
+    } else {
+        String::new()
+    };
+    format!(
+        "fn {}(&self, {}{}) -> Result<{}, CallError>",
+        safe_name(function, options),
+        context_param,
+        input_params(function, options),
+        output_type(function, options)
+    )
+}
+
+/// Tail of every binding method: classify the raw `result` and decode the return data.
+fn generate_call_result(code: &mut String, function: &Function, options: &Options) {
+    code.push_str("        let output = CallError::check(self.address, result)?;\n");
+    code.push_str(&format!("        {}\n", decode_output(function, options)));
+}
+
+/// Expression decoding the return data in `output` with `function`'s decoder.
+fn decode_output(function: &Function, options: &Options) -> String {
+    format!(
+        "CallError::{}(output, decode_{}_returns)",
+        if is_lazy(function, options) {
+            "decode_lazy"
+        } else {
+            "decode"
+        },
+        safe_name(function, options)
+    )
+}
+
+/// Free function decoding the return data of `function` into its Rust output type(s).
+fn generate_decoder(code: &mut String, function: &Function, options: &Options) {
+    if is_lazy(function, options) {
+        generate_lazy_decoder(code, function, options);
+        return;
+    }
+    let output_sol: Vec<&str> = function.outputs.iter().map(|o| o.ty.as_str()).collect();
+    let sol_types: Vec<String> = function
+        .outputs
+        .iter()
+        .map(|output| to_sol_data_type(&output.ty))
+        .collect();
+
+    code.push_str(&format!(
+        "\n// Original: {} returns ({})\n",
+        function.signature(),
+        output_sol.join(",")
+    ));
+    code.push_str(&format!(
+        "pub fn decode_{}_returns(data: &[u8]) -> Result<{}, stylus_sdk::alloy_sol_types::Error> {{\n",
+        safe_name(function, options),
+        output_type(function, options)
+    ));
+    let decode = format!("<{}>::abi_decode_params(data, true)", tuple_of(&sol_types));
+    // Decoded values, converted into their overrides.
+    let (names, values): (Vec<String>, Vec<String>) = function
+        .outputs
+        .iter()
+        .enumerate()
+        .map(|(index, output)| {
+            let name = if sol_types.len() == 1 {
+                "value".to_string()
+            } else {
+                format!("value{}", index)
+            };
+            let value = match custom_type(function, output, options) {
+                Some(rust_type) if is_checked(function, output, options) => {
+                    format!("<{}>::try_from({})?", rust_type, name)
+                }
+                Some(rust_type) => format!("<{}>::from({})", rust_type, name),
+                None => name.clone(),
+            };
+            (name, value)
+        })
+        .unzip();
+    if values.iter().any(|value| value.ends_with('?')) {
+        code.push_str(&format!(
+            "    {}.and_then(|{}| {})\n",
+            decode,
+            tuple_of(&names),
+            match values.len() {
+                1 => values[0].trim_end_matches('?').to_string(),
+                _ => format!("Ok({})", tuple_of(&values)),
+            }
+        ));
+    } else if names != values {
+        code.push_str(&format!(
+            "    {}.map(|{}| {})\n",
+            decode,
+            tuple_of(&names),
+            match values.len() {
+                1 => values[0].clone(),
+                _ => tuple_of(&values),
+            }
+        ));
+    } else if sol_types.len() == 1 {
+        code.push_str(&format!("    {}.map(|(value,)| value)\n", decode));
+    } else {
+        code.push_str(&format!("    {}\n", decode));
+    }
+    code.push_str("}\n");
+}
+
+/// Name of the lazy view over `function`'s return data, e.g. `BalanceOfBatch__0x4e1273f4Returns`.
+fn lazy_returns_name(function: &Function, options: &Options) -> String {
+    format!("{}Returns", variant_name(function, options))
+}
+
+/// Accessor for output `index`: its snake_case name, or `output_<index>` when unnamed.
+fn output_accessor(name: &str, index: usize) -> String {
+    if name.is_empty() {
+        format!("output_{}", index)
+    } else {
+        name.to_snake_case()
+    }
+}
+
+/// `<Variant>Returns` view over the return buffer plus a decoder that only checks the head size.
+///
+/// Dynamic array outputs are exposed as `ArrayView`s; other outputs are decoded on access.
+fn generate_lazy_decoder(code: &mut String, function: &Function, options: &Options) {
+    let returns = lazy_returns_name(function, options);
+    let output_sol: Vec<&str> = function.outputs.iter().map(|o| o.ty.as_str()).collect();
+
+    code.push_str(&format!(
+        "\n/// Lazily decoded outputs of `{}`.\n",
+        function.signature()
+    ));
+    code.push_str("#[allow(non_camel_case_types)]\n");
+    code.push_str(&format!("pub struct {}(pub ReturnData);\n\n", returns));
+    code.push_str(&format!("impl {} {{\n", returns));
+    let mut slot = 0;
+    for (index, output) in function.outputs.iter().enumerate() {
+        if index > 0 {
+            code.push('\n');
+        }
+        code.push_str(&format!("    // Output {}: {}\n", index, output.ty));
+        let accessor = output_accessor(&output.name, index);
+        match output.ty.strip_suffix("[]") {
+            Some(element) => code.push_str(&format!(
+                "    pub fn {}(&self) -> Result<ArrayView<'_, {}>, stylus_sdk::alloy_sol_types::Error> {{ self.0.array({}) }}\n",
+                accessor,
+                to_sol_data_type(element),
+                slot
+            )),
+            None => code.push_str(&format!(
+                "    pub fn {}(&self) -> Result<{}, stylus_sdk::alloy_sol_types::Error> {{ self.0.value::<{}>({}){} }}\n",
+                accessor,
+                return_param_type(function, output, options),
+                to_sol_data_type(&output.ty),
+                slot,
+                match custom_type(function, output, options) {
+                    Some(rust_type) if is_checked(function, output, options) => {
+                        format!(".and_then(<{}>::try_from)", rust_type)
+                    }
+                    Some(rust_type) => format!(".map(<{}>::from)", rust_type),
+                    None => String::new(),
+                }
+            )),
+        }
+        slot += head_words(&output.ty);
+    }
+    code.push_str("}\n");
+
+    code.push_str(&format!(
+        "\n// Original: {} returns ({})\n",
+        function.signature(),
+        output_sol.join(",")
+    ));
+    code.push_str(&format!(
+        "pub fn decode_{}_returns(data: Vec<u8>) -> Result<{}, Vec<u8>> {{\n",
+        safe_name(function, options),
+        returns
+    ));
+    code.push_str(&format!(
+        "    ReturnData::new(data, {}).map({})\n",
+        slot, returns
+    ));
+    code.push_str("}\n");
+}
+
+/// `stylus_interfaces` imports: `CallError`, plus the lazy views when any function uses them.
+fn interfaces_imports(abi: &JsonAbi, options: &Options) -> Vec<&'static str> {
+    let lazy: Vec<&Function> = abi
+        .functions()
+        .filter(|function| is_lazy(function, options))
+        .collect();
+    let mut imports = vec!["CallError", "mock::MockState"];
+    if !options.call_context {
+        imports.extend([
+            "transport::CallKind",
+            "transport::Request",
+            "transport::StylusTransport",
+            "transport::Transport",
+        ]);
+    }
+    if lazy
+        .iter()
+        .flat_map(|function| &function.outputs)
+        .any(|output| output.ty.ends_with("[]"))
+    {
+        imports.push("lazy::ArrayView");
+    }
+    if !lazy.is_empty() {
+        imports.push("lazy::ReturnData");
+    }
+    imports
+}
+
+/// `Calls` variant name for `function`, e.g. `SafeTransferFrom__0x42842e0e`.
+fn variant_name(function: &Function, options: &Options) -> String {
+    with_selector(function.name.to_upper_camel_case(), function, options)
+}
+
+/// Selector of `function` as a `[u8; 4]` literal, e.g. `[0xa9, 0x05, 0x9c, 0xbb]`.
+fn selector_array(function: &Function) -> String {
+    let bytes: Vec<String> = function
+        .selector()
+        .iter()
+        .map(|b| format!("0x{:02x}", b))
+        .collect();
+    format!("[{}]", bytes.join(", "))
+}
+
+/// `match` pattern binding every argument of `function`'s `Calls` variant.
+fn variant_pattern(function: &Function, options: &Options) -> String {
+    if function.inputs.is_empty() {
+        format!("Self::{}", variant_name(function, options))
+    } else {
+        format!(
+            "Self::{} {{ {} }}",
+            variant_name(function, options),
+            input_names(function).join(", ")
+        )
+    }
+}
+
+/// Format string piece and argument expression used to display a `Calls` field.
+///
+/// Overridden fields only promise `Debug`, which `Calls` derives.
+fn display_arg(sol_type: &str, name: &str, overridden: bool) -> (&'static str, String) {
+    if overridden {
+        ("{:?}", name.to_string())
+    } else if sol_type == "bytes" {
+        (
+            "0x{}",
+            format!("stylus_sdk::alloy_primitives::hex::encode({})", name),
+        )
+    } else if array_type(sol_type).is_some() {
+        (
+            "[{}]",
+            format!(
+                "{}.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(\", \")",
+                name
+            ),
+        )
+    } else {
+        ("{}", name.to_string())
+    }
+}
+
+/// `Calls` enum with one variant per function, decodable from inbound calldata.
+fn generate_calls_enum(code: &mut String, abi: &JsonAbi, options: &Options) {
+    code.push_str("\n#[allow(non_camel_case_types, non_snake_case)]\n");
+    code.push_str("#[derive(Debug, Clone, PartialEq, Eq)]\n");
+    code.push_str("pub enum Calls {\n");
+    for function in abi.functions() {
+        code.push_str(&format!("    // Original: {}\n", function.signature()));
+        if function.inputs.is_empty() {
+            code.push_str(&format!("    {},\n", variant_name(function, options)));
+        } else {
+            code.push_str(&format!(
+                "    {} {{ {} }},\n",
+                variant_name(function, options),
+                input_params(function, options)
+            ));
+        }
+    }
+    code.push_str("}\n\n");
+
+    code.push_str("impl Calls {\n");
+    code.push_str("    pub fn abi_decode(data: &[u8]) -> Result<Self, stylus_sdk::alloy_sol_types::Error> {\n");
+    code.push_str("        let Some((selector, args)) = data.split_first_chunk::<4>() else {\n");
+    code.push_str("            return Err(stylus_sdk::alloy_sol_types::Error::Overrun);\n");
+    code.push_str("        };\n");
+    code.push_str("        match *selector {\n");
+    for function in abi.functions() {
+        code.push_str(&format!(
+            "            selectors::{} => {{\n",
+            safe_name(function, options)
+        ));
+        if function.inputs.is_empty() {
+            code.push_str(&format!(
+                "                Ok(Self::{})\n",
+                variant_name(function, options)
+            ));
+        } else {
+            let sol_types: Vec<String> = function
+                .inputs
+                .iter()
+                .map(|input| to_sol_data_type(&input.ty))
+                .collect();
+            let fields: Vec<String> = function
+                .inputs
+                .iter()
+                .zip(input_names(function))
+                .map(|(input, name)| {
+                    if is_checked(function, input, options) {
+                        format!("{}: {}.try_into()?", name, name)
+                    } else if param_type(function, input, options) == to_rust_return_type(&input.ty)
+                    {
+                        name
+                    } else {
+                        format!("{}: {}.into()", name, name)
+                    }
+                })
+                .collect();
+            code.push_str(&format!(
+                "                let {} = <{}>::abi_decode_params(args, true)?;\n",
+                tuple_of(&input_names(function)),
+                tuple_of(&sol_types)
+            ));
+            code.push_str(&format!(
+                "                Ok(Self::{} {{ {} }})\n",
+                variant_name(function, options),
+                fields.join(", ")
+            ));
+        }
+        code.push_str("            }\n");
+    }
+    code.push_str("            _ => Err(stylus_sdk::alloy_sol_types::Error::unknown_selector(\"Calls\", *selector)),\n");
+    code.push_str("        }\n");
+    code.push_str("    }\n\n");
+
+    code.push_str("    pub fn abi_encode(&self) -> Vec<u8> {\n");
+    code.push_str("        match self.clone() {\n");
+    for function in abi.functions() {
+        code.push_str(&format!(
+            "            {} => encode_{}({}){},\n",
+            variant_pattern(function, options),
+            safe_name(function, options),
+            input_names(function).join(", "),
+            if static_calldata_len(function).is_some() {
+                ".to_vec()"
+            } else {
+                ""
+            }
+        ));
+    }
+    code.push_str("        }\n");
+    code.push_str("    }\n\n");
+
+    code.push_str("    pub fn selector(&self) -> [u8; 4] {\n");
+    code.push_str("        match self {\n");
+    for function in abi.functions() {
+        let pattern = if function.inputs.is_empty() {
+            format!("Self::{}", variant_name(function, options))
+        } else {
+            format!("Self::{} {{ .. }}", variant_name(function, options))
+        };
+        code.push_str(&format!(
+            "            {} => selectors::{},\n",
+            pattern,
+            safe_name(function, options)
+        ));
+    }
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+
+    code.push_str("impl core::fmt::Display for Calls {\n");
+    code.push_str("    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {\n");
+    code.push_str("        match self {\n");
+    for function in abi.functions() {
+        let (pieces, args): (Vec<String>, Vec<String>) = function
+            .inputs
+            .iter()
+            .zip(input_names(function))
+            .map(|(input, name)| {
+                let overridden = custom_type(function, input, options).is_some();
+                let (piece, arg) = display_arg(&input.ty, &name, overridden);
+                // Labels keep the Solidity name; unnamed inputs show their Rust one.
+                let label = if input.name.is_empty() {
+                    &name
+                } else {
+                    &input.name
+                };
+                (format!("{}={}", label, piece), arg)
+            })
+            .unzip();
+        let format = format!("{}({})", function.name, pieces.join(", "));
+        if args.is_empty() {
+            code.push_str(&format!(
+                "            {} => write!(f, \"{}\"),\n",
+                variant_pattern(function, options),
+                format
+            ));
+        } else {
+            code.push_str(&format!(
+                "            {} => write!(f, \"{}\", {}),\n",
+                variant_pattern(function, options),
+                format,
+                args.join(", ")
+            ));
+        }
+    }
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n");
+}
+
+/// Compile-time check that every `--type` override encodes as the Solidity type it replaces and
+/// converts from its decoded value, so a missing impl is reported here rather than in an encoder.
+fn generate_override_checks(code: &mut String, abi: &JsonAbi, options: &Options) {
+    // Override type and `sol_data` type to the Solidity types it covers.
+    let mut checks: BTreeMap<(String, String), BTreeSet<String>> = BTreeMap::new();
+    for function in abi.functions() {
+        for param in function.inputs.iter().chain(&function.outputs) {
+            if let Some(rust_type) = overrides::lookup(&options.types, function, param) {
+                checks
+                    .entry((rust_type.to_string(), to_sol_data_type(&param.ty)))
+                    .or_default()
+                    .insert(param.ty.clone());
+            }
+        }
+    }
+    if checks.is_empty() {
+        return;
+    }
+    code.push_str("\n/// `--type` overrides encode as the Solidity type they replace and convert from its decoded value.\n");
+    code.push_str("const _: () = {\n");
+    for ((rust_type, sol_type), sol_types) in &checks {
+        let sol_types: Vec<&str> = sol_types.iter().map(String::as_str).collect();
+        code.push_str(&format!("    // {}: {}\n", rust_type, sol_types.join(", ")));
+        code.push_str(&format!(
+            "    let _ = |value: &{}| <{} as SolType>::abi_encode(value);\n",
+            rust_type, sol_type
+        ));
+        code.push_str(&format!(
+            "    let _ = |value: <{} as SolType>::RustType| <{}>::from(value);\n",
+            sol_type, rust_type
+        ));
+    }
+    code.push_str("};\n");
+}
+
+/// `ExternalFunction`, the value of a Solidity `function` type, and a helper calling it.
+fn generate_external_function(code: &mut String) {
+    code.push_str("\n/// Solidity external `function` value: a contract and one of its selectors, encoded as 24\n");
+    code.push_str("/// bytes.\n");
+    code.push_str("#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]\n");
+    code.push_str("#[repr(C)]\n");
+    code.push_str("pub struct ExternalFunction {\n");
+    code.push_str("    pub address: Address,\n");
+    code.push_str("    pub selector: [u8; 4],\n");
+    code.push_str("}\n\n");
+    code.push_str("impl ExternalFunction {\n");
+    code.push_str("    /// Calls the function with `args`, its ABI-encoded arguments, and returns the raw return data.\n");
+    code.push_str("    pub fn call<T: stylus_interfaces::transport::Transport>(&self, transport: &T, args: &[u8]) -> Result<Vec<u8>, CallError> {\n");
+    code.push_str(
+        "        self.send(transport, stylus_interfaces::transport::CallKind::Call, args)\n",
+    );
+    code.push_str("    }\n\n");
+    code.push_str("    /// Like [`call`](Self::call), but as a STATICCALL, for `view` and `pure` functions.\n");
+    code.push_str("    pub fn static_call<T: stylus_interfaces::transport::Transport>(&self, transport: &T, args: &[u8]) -> Result<Vec<u8>, CallError> {\n");
+    code.push_str(
+        "        self.send(transport, stylus_interfaces::transport::CallKind::StaticCall, args)\n",
+    );
+    code.push_str("    }\n\n");
+    code.push_str("    fn send<T: stylus_interfaces::transport::Transport>(\n");
+    code.push_str("        &self,\n");
+    code.push_str("        transport: &T,\n");
+    code.push_str("        kind: stylus_interfaces::transport::CallKind,\n");
+    code.push_str("        args: &[u8],\n");
+    code.push_str("    ) -> Result<Vec<u8>, CallError> {\n");
+    code.push_str("        let calldata = [&self.selector[..], args].concat();\n");
+    code.push_str("        transport.call(stylus_interfaces::transport::Request {\n");
+    code.push_str("            address: self.address,\n");
+    code.push_str("            calldata: &calldata,\n");
+    code.push_str("            kind,\n");
+    code.push_str("            value: stylus_sdk::alloy_primitives::U256::ZERO,\n");
+    code.push_str("            gas: None,\n");
+    code.push_str("            max_return: None,\n");
+    code.push_str("        })\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("impl core::borrow::Borrow<[u8; 24]> for ExternalFunction {\n");
+    code.push_str("    fn borrow(&self) -> &[u8; 24] {\n");
+    code.push_str("        // SAFETY: `#[repr(C)]` lays out the 20 address bytes, then the 4 selector bytes, with\n");
+    code.push_str("        // alignment 1 and no padding.\n");
+    code.push_str("        unsafe { &*(self as *const Self).cast::<[u8; 24]>() }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("impl From<stylus_sdk::alloy_primitives::Function> for ExternalFunction {\n");
+    code.push_str("    fn from(value: stylus_sdk::alloy_primitives::Function) -> Self {\n");
+    code.push_str("        let (address, selector) = value.to_address_and_selector();\n");
+    code.push_str("        Self { address, selector: selector.0 }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n");
+}
+
+/// Newtype for the `bits`-wide fixed-point `sol_type` with `decimals` decimal places, over the
+/// raw integer it encodes as.
+fn generate_fixed_point(
+    code: &mut String,
+    sol_type: &str,
+    signed: bool,
+    bits: usize,
+    decimals: usize,
+) {
+    let name = generated_type(sol_type).unwrap_or_default();
+    let raw = int_rust_type(bits, signed);
+    code.push_str(&format!(
+        "\n/// Solidity `{}`: the raw integer divided by 10^{}.\n",
+        sol_type, decimals
+    ));
+    code.push_str("#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]\n");
+    code.push_str(&format!("pub struct {}(pub {});\n\n", name, raw));
+    code.push_str(&format!("impl {} {{\n", name));
+    code.push_str("    /// Decimal places of the value.\n");
+    code.push_str(&format!("    pub const DECIMALS: u32 = {};\n\n", decimals));
+    code.push_str(&format!(
+        "    pub const fn from_raw(raw: {}) -> Self {{\n",
+        raw
+    ));
+    code.push_str("        Self(raw)\n");
+    code.push_str("    }\n\n");
+    code.push_str(&format!("    pub const fn raw(self) -> {} {{\n", raw));
+    code.push_str("        self.0\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str(&format!(
+        "impl core::borrow::Borrow<{}> for {} {{\n",
+        raw, name
+    ));
+    code.push_str(&format!("    fn borrow(&self) -> &{} {{\n", raw));
+    code.push_str("        &self.0\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str(&format!("impl From<{}> for {} {{\n", raw, name));
+    code.push_str(&format!("    fn from(raw: {}) -> Self {{\n", raw));
+    code.push_str("        Self(raw)\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("/// The decimal value, with all `DECIMALS` places.\n");
+    code.push_str(&format!("impl core::fmt::Display for {} {{\n", name));
+    code.push_str("    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {\n");
+    code.push_str(if signed {
+        "        let (sign, digits) = (if self.0.is_negative() { \"-\" } else { \"\" }, self.0.unsigned_abs().to_string());\n"
+    } else {
+        "        let (sign, digits) = (\"\", self.0.to_string());\n"
+    });
+    code.push_str(
+        "        let digits = format!(\"{:0>1$}\", digits, Self::DECIMALS as usize + 1);\n",
+    );
+    code.push_str(
+        "        let (int, frac) = digits.split_at(digits.len() - Self::DECIMALS as usize);\n",
+    );
+    code.push_str("        match frac {\n");
+    code.push_str("            \"\" => write!(f, \"{}{}\", sign, int),\n");
+    code.push_str("            _ => write!(f, \"{}{}.{}\", sign, int, frac),\n");
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n");
+}
+
+fn generate_call_opts(code: &mut String) {
+    code.push_str("\n/// Per-call overrides applied to every call made through a `Contract`.\n");
+    code.push_str("#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]\n");
+    code.push_str("pub struct CallOpts {\n");
+    code.push_str("    /// Gas forwarded to the callee; all remaining gas when `None`.\n");
+    code.push_str("    pub gas: Option<u64>,\n");
+    code.push_str(
+        "    /// Wei sent with non-view calls. View and pure functions always use STATICCALL.\n",
+    );
+    code.push_str("    pub value: U256,\n");
+    code.push_str(
+        "    /// Maximum bytes of return data copied back, guarding against return bombs.\n",
+    );
+    code.push_str("    pub max_return: Option<usize>,\n");
+    code.push_str("}\n");
+}
+
+/// Constructors, `with` and the transport-backed `send` shared by `Contract` and `DelegateContract`.
+fn generate_handle_prelude(code: &mut String, handle: &str, delegate: bool) {
+    code.push_str(&format!("impl {} {{\n", handle));
+    code.push_str("    pub fn new(address: Address) -> Self { Self::with_transport(address, StylusTransport) }\n");
+    code.push_str("}\n\n");
+    code.push_str(&format!("impl<T: Transport> {}<T> {{\n", handle));
+    code.push_str("    pub fn with_transport(address: Address, transport: T) -> Self { Self { address, opts: CallOpts::default(), transport } }\n");
+    code.push_str("\n    pub fn with(&self, opts: CallOpts) -> Self where T: Clone { Self { address: self.address, opts, transport: self.transport.clone() } }\n");
+    if delegate {
+        code.push_str("\n    /// # Safety\n");
+        code.push_str("    ///\n");
+        code.push_str("    /// As for the methods: the target must be trusted with this contract's storage.\n");
+        code.push_str(
+            "    unsafe fn send_delegate(&self, input: &[u8]) -> Result<Vec<u8>, CallError> {\n",
+        );
+        code.push_str("        let request = Request {\n");
+        code.push_str("            address: self.address,\n");
+        code.push_str("            calldata: input,\n");
+        code.push_str("            kind: CallKind::DelegateCall,\n");
+        code.push_str("            value: U256::ZERO,\n");
+    } else {
+        code.push_str(
+            "\n    fn send(&self, kind: CallKind, input: &[u8]) -> Result<Vec<u8>, CallError> {\n",
+        );
+        code.push_str("        self.transport.call(Request {\n");
+        code.push_str("            address: self.address,\n");
+        code.push_str("            calldata: input,\n");
+        code.push_str("            kind,\n");
+        code.push_str(
+            "            value: if kind == CallKind::Call { self.opts.value } else { U256::ZERO },\n",
+        );
+    }
+    code.push_str("            gas: self.opts.gas,\n");
+    code.push_str("            max_return: self.opts.max_return,\n");
+    if delegate {
+        code.push_str("        };\n");
+        code.push_str("        unsafe { self.transport.call_delegate(request) }\n");
+    } else {
+        code.push_str("        })\n");
+    }
+    code.push_str("    }\n");
+}
+
+/// Struct header of a binding handle: generic over its transport unless in call-context mode.
+fn generate_handle_struct(code: &mut String, handle: &str, delegate: bool, options: &Options) {
+    if options.call_context {
+        code.push_str(&format!("pub struct {} {{\n", handle));
+        code.push_str("    pub address: Address,\n");
+    } else {
+        code.push_str(&format!("pub struct {}<T = StylusTransport> {{\n", handle));
+        code.push_str("    pub address: Address,\n");
+        code.push_str("    pub opts: CallOpts,\n");
+        code.push_str("    pub transport: T,\n");
+    }
+    code.push_str("}\n\n");
+    if options.call_context {
+        code.push_str(&format!("impl {} {{\n", handle));
+        code.push_str("    pub fn new(address: Address) -> Self { Self { address } }\n");
+    } else {
+        generate_handle_prelude(code, handle, delegate);
+    }
+}
+
+fn generate_contract(code: &mut String, abi: &JsonAbi, options: &Options) {
+    code.push('\n');
+    generate_handle_struct(code, "Contract", false, options);
+
+    for function in abi.functions() {
+        let safe_name = safe_name(function, options);
+
+        code.push_str(&format!("\n    // Original: {}\n", function.signature()));
+        code.push_str(&format!(
+            "    pub {} {{\n",
+            method_signature(function, options, "context")
+        ));
+        code.push_str(&format!(
+            "        let input = encode_{}({});\n",
+            safe_name,
+            input_names(function).join(", ")
+        ));
+        if options.call_context {
+            code.push_str(&format!(
+                "        let result = {}(context, self.address, &input).map_err(Vec::from);\n",
+                if is_view(function) {
+                    "static_call"
+                } else {
+                    "call"
+                }
+            ));
+            generate_call_result(code, function, options);
+        } else {
+            code.push_str(&format!(
+                "        let output = self.send({}, &input)?;\n",
+                call_kind(function)
+            ));
+            code.push_str(&format!("        {}\n", decode_output(function, options)));
+        }
+        code.push_str("    }\n");
+    }
+
+    code.push_str("}\n");
+}
+
+/// `DelegateContract`: same names and encoding as `Contract`, but every method is an `unsafe fn`
+/// that runs the target's code against the caller's storage via DELEGATECALL.
+fn generate_delegate_contract(code: &mut String, abi: &JsonAbi, options: &Options) {
+    code.push_str("\n/// Library-style binding that executes the target's code in *this* contract's context\n");
+    if options.call_context {
+        code.push_str("/// (storage, balance, `msg.sender`, `msg.value`) via DELEGATECALL.\n");
+    } else {
+        code.push_str("/// (storage, balance, `msg.sender`, `msg.value`) via DELEGATECALL. `CallOpts::value` is ignored.\n");
+    }
+    generate_handle_struct(code, "DelegateContract", true, options);
+
+    for function in abi.functions() {
+        let safe_name = safe_name(function, options);
+
+        code.push_str(&format!("\n    // Original: {}\n", function.signature()));
+        code.push_str("    /// # Safety\n");
+        code.push_str("    ///\n");
+        code.push_str("    /// The target code can read and overwrite any storage slot of the calling contract.\n");
+        code.push_str(
+            "    /// It must be trusted and built against the caller's storage layout.\n",
+        );
+        if options.call_context {
+            code.push_str(&format!(
+                "    pub unsafe fn {}(&self, context: impl MutatingCallContext, {}) -> Result<{}, CallError> {{\n",
+                safe_name,
+                input_params(function, options),
+                output_type(function, options)
+            ));
+        } else {
+            code.push_str(&format!(
+                "    pub unsafe fn {}(&self, {}) -> Result<{}, CallError> {{\n",
+                safe_name,
+                input_params(function, options),
+                output_type(function, options)
+            ));
+        }
+        code.push_str(&format!(
+            "        let input = encode_{}({});\n",
+            safe_name,
+            input_names(function).join(", ")
+        ));
+        if options.call_context {
+            // The target reads and writes storage directly, so pending writes go out first and
+            // cached slots it may have overwritten are dropped after.
+            code.push_str("        StorageCache::flush();\n");
+            code.push_str(
+                "        let result = unsafe { delegate_call(context, self.address, &input) }.map_err(Vec::from);\n",
+            );
+            code.push_str("        StorageCache::clear();\n");
+            generate_call_result(code, function, options);
+        } else {
+            code.push_str("        let output = unsafe { self.send_delegate(&input) }?;\n");
+            code.push_str(&format!("        {}\n", decode_output(function, options)));
+        }
+        code.push_str("    }\n");
+    }
 
-    #[arg(short, long)]
-    output: PathBuf,
+    code.push_str("}\n");
 }
 
-fn to_rust_type(sol_type: &str) -> String {
-    match sol_type {
-        "address" => "Address".to_string(),
-        "uint256" => "U256".to_string(),
-        "uint128" => "U128".to_string(),
-        "bool" => "bool".to_string(),
-        "bytes" => "Vec<u8>".to_string(),
-        "bytes4" => "Vec<u8>".to_string(),
-        "address[]" => "Vec<Address>".to_string(),
-        "uint256[]" => "Vec<U256>".to_string(),
-        "bool[]" => "Vec<bool>".to_string(),
-        _ => unreachable!("unsupported Solidity type in ABI: {}", sol_type),
+/// `<Name>Api` trait with every binding method, implemented by `Contract` by delegation.
+fn generate_api_trait(code: &mut String, abi: &JsonAbi, options: &Options) {
+    let api = format!("{}Api", options.name);
+
+    code.push_str(&format!(
+        "\n/// Calls of the `{}` interface, so logic can be generic over `Contract` and `Mock{}`.\n",
+        options.name, options.name
+    ));
+    code.push_str(&format!("pub trait {} {{\n", api));
+    for (index, function) in abi.functions().enumerate() {
+        if index > 0 {
+            code.push('\n');
+        }
+        code.push_str(&format!("    // Original: {}\n", function.signature()));
+        code.push_str(&format!(
+            "    {};\n",
+            method_signature(function, options, "context")
+        ));
     }
+    code.push_str("}\n\n");
+
+    if options.call_context {
+        code.push_str(&format!("impl {} for Contract {{\n", api));
+    } else {
+        code.push_str(&format!("impl<T: Transport> {} for Contract<T> {{\n", api));
+    }
+    for (index, function) in abi.functions().enumerate() {
+        if index > 0 {
+            code.push('\n');
+        }
+        let mut args = input_names(function);
+        if options.call_context {
+            args.insert(0, "context".to_string());
+        }
+        code.push_str(&format!(
+            "    {} {{ Contract::{}(self, {}) }}\n",
+            method_signature(function, options, "context"),
+            safe_name(function, options),
+            args.join(", ")
+        ));
+    }
+    code.push_str("}\n");
 }
 
-fn main() -> anyhow::Result<()> {
-    let args = Cli::parse();
-    let content = fs::read_to_string(&args.input)?;
-    let abi: JsonAbi = serde_json::from_str(&content)?;
+/// Eagerly decoded output type and `sol_data` tuple of `function`, for mock responses.
+fn mock_response_types(function: &Function, options: &Options) -> (String, String) {
+    let rust_types: Vec<String> = function
+        .outputs
+        .iter()
+        .map(|output| return_param_type(function, output, options))
+        .collect();
+    let sol_types: Vec<String> = function
+        .outputs
+        .iter()
+        .map(|output| to_sol_data_type(&output.ty))
+        .collect();
+    let rust_type = match rust_types.len() {
+        1 => rust_types[0].clone(),
+        _ => format!("({})", rust_types.join(", ")),
+    };
+    (rust_type, tuple_of(&sol_types))
+}
+
+/// `Mock<Name>`: in-memory `<Name>Api` with configurable responses and a `Calls` log.
+fn generate_mock(code: &mut String, abi: &JsonAbi, options: &Options) {
+    let mock = format!("Mock{}", options.name);
+
+    code.push_str(&format!(
+        "\n/// In-memory `{}Api` for native unit tests: configurable responses and a log of calls.\n",
+        options.name
+    ));
+    code.push_str("///\n");
+    code.push_str(
+        "/// Responses are raw return data, decoded exactly like a real `Contract` call.\n",
+    );
+    code.push_str("#[derive(Default)]\n");
+    code.push_str(&format!("pub struct {} {{\n", mock));
+    code.push_str("    pub state: MockState<Calls>,\n");
+    code.push_str("}\n\n");
+
+    code.push_str(&format!("impl {} {{\n", mock));
+    code.push_str("    pub fn new() -> Self { Self::default() }\n");
+    code.push_str("\n    /// Every call made so far, oldest first.\n");
+    code.push_str("    pub fn calls(&self) -> Vec<Calls> { self.state.calls() }\n");
+    code.push_str("\n    /// Makes calls to `selector` fail with `error` until replaced.\n");
+    code.push_str("    pub fn fail(&self, selector: [u8; 4], error: CallError) { self.state.respond(selector, Err(error)); }\n");
+    for function in abi
+        .functions()
+        .filter(|function| !function.outputs.is_empty())
+    {
+        let (rust_type, sol_types) = mock_response_types(function, options);
+        let value = if function.outputs.len() == 1 {
+            "&(value,)"
+        } else {
+            "&value"
+        };
+        code.push_str(&format!(
+            "\n    /// Makes `{}` return `value` until replaced.\n",
+            function.signature()
+        ));
+        code.push_str(&format!(
+            "    pub fn returns_{}(&self, value: {}) {{ self.state.respond(selectors::{}, Ok(<{}>::abi_encode_params({}))); }}\n",
+            safe_name(function, options),
+            rust_type,
+            safe_name(function, options),
+            sol_types,
+            value
+        ));
+    }
+    code.push_str("}\n\n");
+
+    code.push_str(&format!("impl {}Api for {} {{\n", options.name, mock));
+    for (index, function) in abi.functions().enumerate() {
+        if index > 0 {
+            code.push('\n');
+        }
+        let call = if function.inputs.is_empty() {
+            format!("Calls::{}", variant_name(function, options))
+        } else {
+            format!(
+                "Calls::{} {{ {} }}",
+                variant_name(function, options),
+                input_names(function).join(", ")
+            )
+        };
+        code.push_str(&format!(
+            "    {} {{\n",
+            method_signature(function, options, "_context")
+        ));
+        code.push_str(&format!(
+            "        let output = self.state.record(selectors::{}, {})?;\n",
+            safe_name(function, options),
+            call
+        ));
+        code.push_str(&format!("        {}\n", decode_output(function, options)));
+        code.push_str("    }\n");
+    }
+    code.push_str("}\n");
+}
+
+/// Receiver of a `#[public]` method; stylus-sdk exports its mutability from it.
+fn public_receiver(function: &Function) -> Option<&'static str> {
+    match function.state_mutability {
+        StateMutability::Pure => None,
+        StateMutability::View => Some("&self"),
+        StateMutability::NonPayable | StateMutability::Payable => Some("&mut self"),
+    }
+}
 
+/// Rust type a `#[public]` method uses for `sol_type`, which stylus-sdk exports back as it.
+///
+/// Primitive integers export their own width, so other widths use `Uint<N, L>`/`Signed<N, L>`.
+fn to_public_type(sol_type: &str) -> String {
+    if let Some((element, len)) = array_type(sol_type) {
+        let element = to_public_type(element);
+        return match len {
+            None => format!("Vec<{}>", element),
+            Some(len) => format!("[{}; {}]", element, len),
+        };
+    }
+    let (bits, signed) = match (int_bits(sol_type, "uint"), int_bits(sol_type, "int")) {
+        (Some(bits), _) => (bits, false),
+        (_, Some(bits)) => (bits, true),
+        _ => return to_rust_return_type(sol_type),
+    };
+    match bits {
+        8 | 16 | 32 | 64 | 128 | 256 => int_rust_type(bits, signed),
+        _ => format!(
+            "{}<{}, {}>",
+            if signed { "Signed" } else { "Uint" },
+            bits,
+            bits.div_ceil(64)
+        ),
+    }
+}
+
+/// Rust keywords, which arguments can only use as raw identifiers.
+const KEYWORDS: [&str; 51] = [
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while",
+];
+
+/// `fn name(receiver, ...) -> Result<T, Vec<u8>>` of the implementation trait and router.
+///
+/// `bytes` is `stylus_sdk::abi::Bytes` both ways, as `Vec<u8>` would export as `uint8[]`.
+fn public_signature(function: &Function, options: &Options) -> String {
+    let params = public_receiver(function).into_iter().map(str::to_string);
+    let inputs = function
+        .inputs
+        .iter()
+        .zip(input_names(function))
+        .map(|(input, name)| format!("{}: {}", name, to_public_type(&input.ty)));
+    let outputs: Vec<String> = function
+        .outputs
+        .iter()
+        .map(|output| to_public_type(&output.ty))
+        .collect();
+    format!(
+        "fn {}({}) -> Result<{}, Vec<u8>>",
+        safe_name(function, options),
+        params.chain(inputs).collect::<Vec<_>>().join(", "),
+        match outputs.len() {
+            1 => outputs[0].clone(),
+            _ => format!("({})", outputs.join(", ")),
+        }
+    )
+}
+
+/// `alloy_primitives` names referenced by the implementation skeleton, in import order.
+fn public_primitive_imports(abi: &JsonAbi) -> Vec<&'static str> {
+    const PRIMITIVES: [&str; 6] = ["Address", "FixedBytes", "I256", "Signed", "U256", "Uint"];
+    let mut used: BTreeSet<&'static str> = BTreeSet::new();
+    for function in abi.functions() {
+        for param in function.inputs.iter().chain(&function.outputs) {
+            let ty = to_public_type(&param.ty);
+            for token in ty.split(|c: char| !c.is_ascii_alphanumeric()) {
+                if let Some(name) = PRIMITIVES.iter().find(|name| **name == token) {
+                    used.insert(name);
+                }
+            }
+        }
+    }
+    used.into_iter().collect()
+}
+
+/// `--mode implement`: a `<Name>Interface` trait with one method per ABI function, and a
+/// `#[public]` block on a `<Name>` storage struct routing each selector to it.
+///
+/// Router methods keep the selector-suffixed names and export the Solidity name through
+/// `#[selector(name = ...)]`, so overloads get distinct Rust names but the ABI's selectors.
+fn generate_implementation(abi: &JsonAbi, options: &Options) -> String {
+    let interface = format!("{}Interface", options.name);
     let mut code = String::new();
 
     code.push_str("// Generated by stylus-bindgen\n");
     code.push_str("#![allow(clippy::all)]\n");
-    code.push_str("use stylus_sdk::{alloy_primitives::{Address, U256}, alloy_sol_types::SolType, call::RawCall, prelude::*};\n");
-    code.push_str("\npub struct Contract {\n");
-    code.push_str("    pub address: Address,\n");
-    code.push_str("}\n\n");
-    code.push_str("impl Contract {\n");
-    code.push_str("    pub fn new(address: Address) -> Self { Self { address } }\n");
+    let bytes = abi
+        .functions()
+        .flat_map(|function| function.inputs.iter().chain(&function.outputs))
+        .any(|param| {
+            to_rust_return_type(&param.ty)
+                .split(|c: char| !c.is_ascii_alphanumeric())
+                .any(|token| token == "Bytes")
+        });
+    let primitives = public_primitive_imports(abi);
+    let mut imports = Vec::new();
+    if bytes {
+        imports.push("abi::Bytes".to_string());
+    }
+    if !primitives.is_empty() {
+        imports.push(format!("alloy_primitives::{}", use_group(&primitives)));
+    }
+    imports.push("prelude::*".to_string());
+    code.push_str(&format!("use stylus_sdk::{{{}}};\n", imports.join(", ")));
 
-    for function in abi.functions() {
-        let name = &function.name;
-        let selector = hex::encode(function.selector());
-        let safe_name = format!("{}__0x{}", name.to_snake_case(), selector);
+    code.push_str(&format!(
+        "\n/// Functions of the `{}` interface, as a Stylus contract implements them.\n",
+        options.name
+    ));
+    code.push_str("///\n");
+    code.push_str(&format!(
+        "/// Implement this for `{}`; its `#[public]` block routes every selector of the ABI here.\n",
+        options.name
+    ));
+    code.push_str(&format!("pub trait {} {{\n", interface));
+    for (index, function) in abi.functions().enumerate() {
+        if index > 0 {
+            code.push('\n');
+        }
+        code.push_str(&format!("    // Original: {}\n", function.signature()));
+        code.push_str(&format!("    {};\n", public_signature(function, options)));
+    }
+    code.push_str("}\n");
 
-        let inputs: Vec<String> = function
-            .inputs
-            .iter()
-            .map(|input| format!("{}: {}", input.name, to_rust_type(&input.ty)))
-            .collect();
-        let input_args = inputs.join(", ");
+    code.push_str("\n/// Contract storage; add fields as needed.\n");
+    code.push_str("#[storage]\n");
+    code.push_str("#[entrypoint]\n");
+    code.push_str(&format!("pub struct {} {{}}\n", options.name));
 
-        code.push_str(&format!("\n    // Original: {}\n", function.signature()));
+    code.push_str("\n#[public]\n");
+    code.push_str(&format!("impl {} {{\n", options.name));
+    for (index, function) in abi.functions().enumerate() {
+        if index > 0 {
+            code.push('\n');
+        }
+        let mut args = input_names(function);
+        if public_receiver(function).is_some() {
+            args.insert(0, "self".to_string());
+        }
+        code.push_str(&format!("    // Original: {}\n", function.signature()));
+        if function.state_mutability == StateMutability::Payable {
+            code.push_str("    #[payable]\n");
+        }
+        code.push_str(&format!("    #[selector(name = \"{}\")]\n", function.name));
         code.push_str(&format!(
-            "    pub fn {}(&self, {}) -> Result<Vec<u8>, Vec<u8>> {{\n",
-            safe_name, input_args
+            "    pub {} {{\n",
+            public_signature(function, options)
         ));
-
         code.push_str(&format!(
-            "        let input = hex::decode(\"{}\").unwrap();\n",
-            selector
+            "        <Self as {}>::{}({})\n",
+            interface,
+            safe_name(function, options),
+            args.join(", ")
         ));
+        code.push_str("    }\n");
+    }
+    code.push_str("}\n");
+    if options.interface_id {
+        generate_interface_id(&mut code, abi);
+    }
 
-        code.push_str(
-            "        // note: real encoding requires SolType definitions (Milestone 2)\n",
+    code
+}
+
+fn generate(abi: &JsonAbi, options: &Options) -> String {
+    if options.mode == Mode::Implement {
+        return generate_implementation(abi, options);
+    }
+    let mut code = String::new();
+
+    code.push_str("// Generated by stylus-bindgen\n");
+    code.push_str("#![allow(clippy::all)]\n");
+    let call = call_imports(abi, options);
+    code.push_str(&format!(
+        "use stylus_sdk::{{alloy_primitives::{}, alloy_sol_types::{{sol_data, SolType}}{}{}}};\n",
+        use_group(&primitive_imports(abi, options)),
+        if call.is_empty() {
+            String::new()
+        } else {
+            format!(", call::{}", use_group(&call))
+        },
+        if options.call_context && options.delegate {
+            ", storage::StorageCache"
+        } else {
+            ""
+        }
+    ));
+    code.push_str(&format!(
+        "use stylus_interfaces::{};\n",
+        use_group(&interfaces_imports(abi, options))
+    ));
+
+    generate_override_checks(&mut code, abi, options);
+    internal_types::generate(
+        &mut code,
+        &internal_types::definitions(abi, &options.enums, &options.types, options.pack_handles),
+    );
+    for sol_type in generated_types(abi, options) {
+        match fixed_point(&sol_type) {
+            Some((signed, bits, decimals)) => {
+                generate_fixed_point(&mut code, &sol_type, signed, bits, decimals)
+            }
+            None => generate_external_function(&mut code),
+        }
+    }
+    if !options.call_context {
+        generate_call_opts(&mut code);
+    }
+
+    generate_contract(&mut code, abi, options);
+    generate_api_trait(&mut code, abi, options);
+    if options.delegate {
+        generate_delegate_contract(&mut code, abi, options);
+    }
+
+    generate_selectors(&mut code, abi, options);
+    if options.interface_id {
+        generate_interface_id(&mut code, abi);
+    }
+    for function in abi.functions() {
+        generate_encoder(&mut code, function, options);
+        generate_decoder(&mut code, function, options);
+    }
+
+    generate_calls_enum(&mut code, abi, options);
+    generate_mock(&mut code, abi, options);
+
+    code
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Cli::parse();
+    match &args.command {
+        Some(Command::Conforms(conforms)) => {
+            if !conforms::run(conforms)? {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        Some(Command::VerifyBytecode(verify)) => {
+            if !verify_bytecode::run(verify)? {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        Some(Command::Lint(lint)) => {
+            if !lint::run(lint)? {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        Some(Command::Diff(diff)) => {
+            if !diff::run(diff)? {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        None if args.target.input.is_some() || args.target.input_dir.is_some() => {
+            generate_target(&args.target)
+        }
+        None => generate_from_config(args.config.as_deref()),
+    }
+}
+
+/// Fails unless every pattern of `flag` names a function of one of `abis`.
+fn check_patterns(
+    flag: &str,
+    patterns: &[String],
+    abis: &[&JsonAbi],
+    input: &Path,
+) -> anyhow::Result<()> {
+    if let Some(unknown) = patterns.iter().find(|pattern| {
+        !abis.iter().any(|abi| {
+            abi.functions()
+                .any(|function| names_function(pattern, function))
+        })
+    }) {
+        anyhow::bail!("--{} {}: no such function in {:?}", flag, unknown, input);
+    }
+    Ok(())
+}
+
+/// Fails on fixed-point types outside the ABI spec's `0 < M <= 256`, `M % 8 == 0`, `0 < N <= 80`,
+/// such as `fixed256x0`.
+fn check_fixed_point(abis: &[&JsonAbi]) -> anyhow::Result<()> {
+    for function in abis.iter().flat_map(|abi| abi.functions()) {
+        for param in function.inputs.iter().chain(&function.outputs) {
+            let base = param.ty.split('[').next().unwrap_or_default();
+            if (base.starts_with("fixed") || base.starts_with("ufixed"))
+                && fixed_point(base).is_none()
+            {
+                anyhow::bail!(
+                    "{}: `{}` is not a valid fixed-point type; it must be fixed<M>x<N> or \
+                     ufixed<M>x<N> with M a multiple of 8 up to 256 and N from 1 to 80",
+                    function.signature(),
+                    param.ty
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Fails on `--lazy` functions with tuple or struct outputs, which have no lazy view.
+fn check_lazy_outputs(patterns: &[String], abis: &[&JsonAbi]) -> anyhow::Result<()> {
+    for function in abis.iter().flat_map(|abi| abi.functions()) {
+        if !patterns
+            .iter()
+            .any(|pattern| names_function(pattern, function))
+        {
+            continue;
+        }
+        if let Some(output) = function.outputs.iter().find(|o| o.ty.starts_with("tuple")) {
+            anyhow::bail!(
+                "--lazy {}: output `{}` is a tuple, which can't be decoded lazily; decode this \
+                 function eagerly",
+                function.signature(),
+                output.selector_type()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Checks `--include`/`--exclude` against `abis` and removes the functions they filter out.
+fn filter_abis(args: &Target, abis: &mut [&mut JsonAbi], input: &Path) -> anyhow::Result<()> {
+    let all: Vec<&JsonAbi> = abis.iter().map(|abi| &**abi).collect();
+    check_patterns("include", &args.include, &all, input)?;
+    check_patterns("exclude", &args.exclude, &all, input)?;
+    let filter = filter::Filter {
+        include: &args.include,
+        exclude: &args.exclude,
+        only_view: args.only_view,
+    };
+    for abi in abis.iter_mut() {
+        filter.apply(abi);
+    }
+    Ok(())
+}
+
+/// Formats `files` in place, like the packs in `stylus-interfaces`.
+fn rustfmt(files: &[PathBuf]) -> anyhow::Result<()> {
+    let status = std::process::Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .args(files)
+        .status()
+        .map_err(|e| anyhow::anyhow!("running rustfmt: {}", e))?;
+    if !status.success() {
+        anyhow::bail!("rustfmt failed on {:?}", files);
+    }
+    Ok(())
+}
+
+fn generate_target(target: &Target) -> anyhow::Result<()> {
+    if target.input_dir.is_some() {
+        generate_directory(target)
+    } else {
+        generate_bindings(target)
+    }
+}
+
+/// Regenerates every `[[target]]` of `--config`, or of `sipb.toml` in the current directory.
+fn generate_from_config(path: Option<&Path>) -> anyhow::Result<()> {
+    let Some(config) = config::load(path)? else {
+        anyhow::bail!(
+            "nothing to generate: pass --input or --input-dir, or list [[target]]s in {}",
+            config::FILE
         );
+    };
+    if config.targets.is_empty() {
+        anyhow::bail!("no [[target]] to generate in {}", config.path.display());
+    }
+    for target in &config.targets {
+        generate_target(target)?;
+    }
+    Ok(())
+}
 
-        code.push_str(
-            "        let result = unsafe { RawCall::new_static().call(self.address, &input)? };\n",
+fn generate_bindings(args: &Target) -> anyhow::Result<()> {
+    let (Some(input), Some(output)) = (&args.input, &args.output) else {
+        unreachable!("clap and sipb.toml require --output with --input");
+    };
+    let mut abi = source::read_abi(input)?;
+
+    filter_abis(args, &mut [&mut abi], input)?;
+    if abi.functions.is_empty() {
+        anyhow::bail!("the filters leave no functions in {:?}", input);
+    }
+    check_patterns("lazy", &args.lazy, &[&abi], input)?;
+    check_lazy_outputs(&args.lazy, &[&abi])?;
+    check_fixed_point(&[&abi])?;
+    overrides::check(&args.types, &[&abi])?;
+    internal_types::check(&args.enums, &args.types, args.pack_handles, &[&abi])?;
+    if args.mode == Mode::Implement
+        && (args.delegate
+            || args.call_context
+            || !args.lazy.is_empty()
+            || !args.types.is_empty()
+            || !args.enums.is_empty()
+            || args.pack_handles)
+    {
+        anyhow::bail!(
+            "--delegate, --call-context, --lazy, --type, --enum and --pack-handles only apply to \
+             --mode bindings"
         );
-        code.push_str("        Ok(result)\n");
-        code.push_str("    }\n");
+    }
+    if args.mode == Mode::Implement {
+        if let Some(function) = abi.functions().find(|function| {
+            function
+                .inputs
+                .iter()
+                .chain(&function.outputs)
+                .any(|param| {
+                    generated_type(param.ty.split('[').next().unwrap_or_default()).is_some()
+                })
+        }) {
+            anyhow::bail!(
+                "--mode implement can't export {}: stylus-sdk has no Rust type for `function` or \
+                 fixed-point types",
+                function.signature()
+            );
+        }
     }
 
-    code.push_str("}\n");
+    let name = match &args.name {
+        Some(name) => name.clone(),
+        None => input
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("Interface")
+            .to_string(),
+    };
+
+    let options = Options {
+        mode: args.mode,
+        delegate: args.delegate,
+        call_context: args.call_context,
+        lazy: args.lazy.clone(),
+        name: name.to_upper_camel_case(),
+        interface_id: args.subset,
+        types: args.types.clone(),
+        enums: args.enums.clone(),
+        pack_handles: args.pack_handles,
+        plain: plain_names(&abi, args.naming),
+    };
+    let code = generate(&abi, &options);
+    internal_types::check_shadowing(
+        &code,
+        &abi,
+        &options.enums,
+        &options.types,
+        options.pack_handles,
+    )?;
+
+    fs::write(output, code)?;
+    if args.rustfmt {
+        rustfmt(std::slice::from_ref(output))?;
+    }
+    println!("Generated bindings at: {:?}", output);
+
+    Ok(())
+}
+
+/// `--module STEM=MODULE`: the module generated for the `--input-dir` ABI `<STEM>.json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ModuleName {
+    stem: String,
+    module: String,
+}
+
+impl ModuleName {
+    fn new(stem: &str, module: &str) -> anyhow::Result<Self> {
+        let (stem, module) = (stem.trim(), module.trim());
+        if stem.is_empty() {
+            anyhow::bail!("module `{}` has an empty file stem", module);
+        }
+        let identifier = module.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && module
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !identifier || module == "_" || KEYWORDS.contains(&module) {
+            anyhow::bail!("`{}`: `{}` is not a module name", stem, module);
+        }
+        Ok(Self {
+            stem: stem.to_string(),
+            module: module.to_string(),
+        })
+    }
+
+    fn parse(spec: &str) -> anyhow::Result<Self> {
+        let (stem, module) = spec
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("`{}` is not STEM=MODULE", spec))?;
+        Self::new(stem, module)
+    }
+}
+
+/// `pub mod` index of generated modules, used as `mod.rs` or `lib.rs`.
+fn module_index<'a>(modules: impl Iterator<Item = &'a str>) -> String {
+    let mut code = String::from("// Generated by stylus-bindgen\n");
+    // Selector-suffixed names such as `approve__0x095ea7b3` are intentional.
+    code.push_str("#![allow(non_snake_case)]\n\n");
+    for module in modules {
+        code.push_str(&format!("pub mod {};\n", module));
+    }
+    code
+}
+
+/// `--interfaces-dep`: where a generated crate gets `stylus-interfaces` from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Dependency {
+    Version(String),
+    Path(String),
+    Git(String),
+}
+
+impl Default for Dependency {
+    /// The published `stylus-interfaces`, released with stylus-bindgen at the same version.
+    fn default() -> Self {
+        Self::Version(env!("CARGO_PKG_VERSION").to_string())
+    }
+}
+
+impl Dependency {
+    fn parse(spec: &str) -> anyhow::Result<Self> {
+        let invalid = || anyhow::anyhow!("`{}` is not VERSION, path=DIR or git=URL", spec);
+        let (kind, value) = match spec.trim().split_once('=') {
+            Some((kind, value)) => (Some(kind), value.trim()),
+            None => (None, spec.trim()),
+        };
+        if value.is_empty() {
+            return Err(invalid());
+        }
+        match kind {
+            None => Ok(Self::Version(value.to_string())),
+            Some("path") => Ok(Self::Path(value.to_string())),
+            Some("git") => Ok(Self::Git(value.to_string())),
+            Some(_) => Err(invalid()),
+        }
+    }
+
+    /// The dependency's value in `Cargo.toml`.
+    fn toml(&self) -> String {
+        match self {
+            Self::Version(version) => format!("{:?}", version),
+            Self::Path(path) => format!("{{ path = {:?} }}", path),
+            Self::Git(url) => format!("{{ git = {:?} }}", url),
+        }
+    }
+}
+
+/// `Cargo.toml` of a generated bindings crate.
+fn crate_manifest(name: &str, interfaces: &Dependency) -> String {
+    format!(
+        r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+stylus-sdk = "0.6.0"
+stylus-interfaces = {}
+"#,
+        name,
+        interfaces.toml()
+    )
+}
+
+/// Generates every job on a pool of scoped threads; results keep the order of `jobs`.
+fn generate_all(jobs: &[(String, JsonAbi, Options)]) -> Vec<String> {
+    let workers = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = jobs.len().div_ceil(workers).max(1);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = jobs
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|(_, abi, options)| generate(abi, options))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("generator thread panicked"))
+            .collect()
+    })
+}
+
+/// Generates one module per `*.json` ABI in `--input-dir`, named after the file unless
+/// `--module` names it, plus an index.
+fn generate_directory(args: &Target) -> anyhow::Result<()> {
+    let (Some(input_dir), Some(out_dir)) = (&args.input_dir, &args.out_dir) else {
+        unreachable!("clap and sipb.toml require --out-dir with --input-dir");
+    };
+    if args.mode == Mode::Implement {
+        anyhow::bail!("--mode implement declares an entrypoint per ABI; generate it with --input");
+    }
+
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(input_dir)? {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    // Module name to the ABI it comes from, to reject collisions such as `ERC20.json` and
+    // `erc20.json`.
+    let mut modules: BTreeMap<String, PathBuf> = BTreeMap::new();
+    let mut jobs = Vec::new();
+    for path in paths {
+        let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            anyhow::bail!("{:?}: file name is not valid UTF-8", path);
+        };
+        let renamed = args.modules.iter().find(|name| name.stem == stem);
+        let module = match renamed {
+            Some(name) => name.module.clone(),
+            None => stem.to_snake_case(),
+        };
+        if let Some(other) = modules.get(&module) {
+            anyhow::bail!(
+                "{:?} and {:?} both generate module `{}`",
+                other,
+                path,
+                module
+            );
+        }
+        let abi = source::read_abi(&path)?;
+        let options = Options {
+            mode: args.mode,
+            delegate: args.delegate,
+            call_context: args.call_context,
+            lazy: args.lazy.clone(),
+            name: renamed
+                .map_or(stem, |name| &name.module)
+                .to_upper_camel_case(),
+            interface_id: args.subset,
+            types: args.types.clone(),
+            enums: args.enums.clone(),
+            pack_handles: args.pack_handles,
+            plain: BTreeSet::new(),
+        };
+        modules.insert(module.clone(), path);
+        jobs.push((module, abi, options));
+    }
+    if jobs.is_empty() {
+        anyhow::bail!("no *.json ABIs in {:?}", input_dir);
+    }
+    if let Some(name) = args.modules.iter().find(|name| {
+        !modules
+            .values()
+            .any(|path| path.file_stem() == Some(name.stem.as_ref()))
+    }) {
+        anyhow::bail!(
+            "--module {}: no {}.json in {:?}",
+            name.module,
+            name.stem,
+            input_dir
+        );
+    }
+    let mut abis: Vec<&mut JsonAbi> = jobs.iter_mut().map(|(_, abi, _)| abi).collect();
+    filter_abis(args, &mut abis, input_dir)?;
+    if let Some((module, _, _)) = jobs.iter().find(|(_, abi, _)| abi.functions.is_empty()) {
+        anyhow::bail!("the filters leave no functions in {:?}", modules[module]);
+    }
+    for (_, abi, options) in &mut jobs {
+        options.plain = plain_names(abi, args.naming);
+    }
+    let abis: Vec<&JsonAbi> = jobs.iter().map(|(_, abi, _)| abi).collect();
+    check_patterns("lazy", &args.lazy, &abis, input_dir)?;
+    check_lazy_outputs(&args.lazy, &abis)?;
+    check_fixed_point(&abis)?;
+    overrides::check(&args.types, &abis)?;
+    internal_types::check(&args.enums, &args.types, args.pack_handles, &abis)?;
 
-    fs::write(&args.output, code)?;
-    println!("Generated bindings at: {:?}", args.output);
+    // Module order, so the output doesn't depend on how file names sort.
+    jobs.sort_by(|a, b| a.0.cmp(&b.0));
+    let codes = generate_all(&jobs);
+    for ((_, abi, options), code) in jobs.iter().zip(&codes) {
+        internal_types::check_shadowing(
+            code,
+            abi,
+            &options.enums,
+            &options.types,
+            options.pack_handles,
+        )?;
+    }
+    let src = match &args.crate_name {
+        Some(_) => out_dir.join("src"),
+        None => out_dir.clone(),
+    };
+    fs::create_dir_all(&src)?;
+    let mut written = Vec::new();
+    for ((module, _, _), code) in jobs.iter().zip(&codes) {
+        let path = src.join(format!("{}.rs", module));
+        fs::write(&path, code)?;
+        written.push(path);
+    }
+    let index = src.join(if args.lib || args.crate_name.is_some() {
+        "lib.rs"
+    } else {
+        "mod.rs"
+    });
+    fs::write(&index, module_index(modules.keys().map(String::as_str)))?;
+    written.push(index);
+    if let Some(name) = &args.crate_name {
+        let interfaces = args.interfaces_dep.clone().unwrap_or_default();
+        fs::write(
+            out_dir.join("Cargo.toml"),
+            crate_manifest(name, &interfaces),
+        )?;
+    }
+    if args.rustfmt {
+        rustfmt(&written)?;
+    }
+    println!("Generated {} modules in: {:?}", modules.len(), out_dir);
 
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::to_rust_type;
+    use super::{
+        context_trait, encoder_return_type, fixed_point, generated_type, head_words, input_names,
+        names_function, output_accessor, plain_names, public_signature, static_calldata_len,
+        to_public_type, to_rust_return_type, to_rust_type, to_sol_data_type, tuple_of, use_group,
+        Naming, Options,
+    };
+    use alloy_json_abi::{Function, JsonAbi, StateMutability};
+
+    /// `Function::parse`, plus a `view`, `pure` or `payable` after the parameters, which the
+    /// human-readable parser of alloy-json-abi 0.7.6 rejects.
+    pub(crate) fn parse_function(signature: &str) -> Function {
+        let mut state_mutability = StateMutability::NonPayable;
+        let mut words = Vec::new();
+        for word in signature.split(' ') {
+            match word {
+                "view" => state_mutability = StateMutability::View,
+                "pure" => state_mutability = StateMutability::Pure,
+                "payable" => state_mutability = StateMutability::Payable,
+                word => words.push(word),
+            }
+        }
+        let mut function = Function::parse(&words.join(" ")).unwrap();
+        function.state_mutability = state_mutability;
+        function
+    }
+
+    /// `JsonAbi::parse`, with its functions read by [`parse_function`].
+    pub(crate) fn parse_abi(items: &[&str]) -> JsonAbi {
+        let (functions, others): (Vec<&str>, Vec<&str>) =
+            items.iter().partition(|item| item.starts_with("function "));
+        let mut abi = JsonAbi::parse(others).unwrap();
+        for function in functions.into_iter().map(parse_function) {
+            abi.functions
+                .entry(function.name.clone())
+                .or_default()
+                .push(function);
+        }
+        abi
+    }
 
     #[test]
     fn to_rust_type_address() {
@@ -113,7 +2462,7 @@ mod tests {
 
     #[test]
     fn to_rust_type_bytes4() {
-        assert_eq!(to_rust_type("bytes4"), "Vec<u8>");
+        assert_eq!(to_rust_type("bytes4"), "FixedBytes<4>");
     }
 
     #[test]
@@ -134,6 +2483,254 @@ mod tests {
     #[test]
     #[should_panic(expected = "unsupported Solidity type")]
     fn to_rust_type_unknown_panics() {
-        to_rust_type("uint8");
+        to_rust_type("uint7");
+    }
+
+    #[test]
+    fn to_rust_type_integers_follow_alloy_widths() {
+        assert_eq!(to_rust_type("uint8"), "u8");
+        assert_eq!(to_rust_type("uint24"), "u32");
+        assert_eq!(to_rust_type("uint64"), "u64");
+        assert_eq!(to_rust_type("uint128"), "u128");
+        assert_eq!(to_rust_type("uint160"), "U256");
+        assert_eq!(to_rust_type("int16"), "i16");
+        assert_eq!(to_rust_type("int256"), "I256");
+    }
+
+    #[test]
+    fn to_rust_type_other_elementary_types() {
+        assert_eq!(to_rust_type("bytes32"), "FixedBytes<32>");
+        assert_eq!(to_rust_type("string"), "String");
+        assert_eq!(to_rust_type("bytes[]"), "Vec<Bytes>");
+        assert_eq!(to_rust_type("uint8[3]"), "[u8; 3]");
+        assert_eq!(to_rust_type("address[2][]"), "Vec<[Address; 2]>");
+        assert_eq!(to_rust_type("function[]"), "Vec<Function>");
+    }
+
+    #[test]
+    fn fixed_point_types_are_their_raw_integers() {
+        assert_eq!(fixed_point("fixed128x18"), Some((true, 128, 18)));
+        assert_eq!(fixed_point("ufixed256x80"), Some((false, 256, 80)));
+        assert_eq!(fixed_point("fixed"), Some((true, 128, 18)));
+        assert_eq!(fixed_point("ufixed"), Some((false, 128, 18)));
+        for invalid in [
+            "ufixed128",
+            "fixed7x1",
+            "fixed128x0",
+            "fixed128x81",
+            "fixedx18",
+        ] {
+            assert_eq!(fixed_point(invalid), None, "{}", invalid);
+        }
+        assert_eq!(generated_type("fixed").as_deref(), Some("Fixed128x18"));
+        assert_eq!(to_rust_type("ufixed[]"), "Vec<u128>");
+        assert_eq!(to_rust_type("fixed128x18"), "i128");
+        assert_eq!(to_rust_type("ufixed256x80[]"), "Vec<U256>");
+        assert_eq!(to_sol_data_type("ufixed32x4"), "sol_data::Uint<32>");
+        assert_eq!(generated_type("ufixed32x4").as_deref(), Some("Ufixed32x4"));
+    }
+
+    #[test]
+    fn to_rust_return_type_bytes() {
+        assert_eq!(to_rust_return_type("bytes"), "Bytes");
+    }
+
+    #[test]
+    fn to_rust_return_type_matches_params_otherwise() {
+        for ty in ["address", "uint256", "bool", "bytes4", "uint256[]"] {
+            assert_eq!(to_rust_return_type(ty), to_rust_type(ty));
+        }
+    }
+
+    #[test]
+    fn to_sol_data_type_scalars() {
+        assert_eq!(to_sol_data_type("address"), "sol_data::Address");
+        assert_eq!(to_sol_data_type("uint256"), "sol_data::Uint<256>");
+        assert_eq!(to_sol_data_type("uint128"), "sol_data::Uint<128>");
+        assert_eq!(to_sol_data_type("bool"), "sol_data::Bool");
+        assert_eq!(to_sol_data_type("bytes"), "sol_data::Bytes");
+        assert_eq!(to_sol_data_type("bytes4"), "sol_data::FixedBytes<4>");
+    }
+
+    #[test]
+    fn to_sol_data_type_arrays() {
+        assert_eq!(
+            to_sol_data_type("address[]"),
+            "sol_data::Array<sol_data::Address>"
+        );
+        assert_eq!(
+            to_sol_data_type("uint256[]"),
+            "sol_data::Array<sol_data::Uint<256>>"
+        );
+        assert_eq!(
+            to_sol_data_type("bool[]"),
+            "sol_data::Array<sol_data::Bool>"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported Solidity type")]
+    fn to_sol_data_type_unknown_panics() {
+        to_sol_data_type("bytes33");
+    }
+
+    #[test]
+    fn to_sol_data_type_other_elementary_types() {
+        assert_eq!(to_sol_data_type("int24"), "sol_data::Int<24>");
+        assert_eq!(to_sol_data_type("bytes32"), "sol_data::FixedBytes<32>");
+        assert_eq!(to_sol_data_type("string"), "sol_data::String");
+        assert_eq!(to_sol_data_type("function"), "sol_data::Function");
+        assert_eq!(
+            to_sol_data_type("uint8[3][]"),
+            "sol_data::Array<sol_data::FixedArray<sol_data::Uint<8>, 3>>"
+        );
+    }
+
+    #[test]
+    fn tuple_of_single_has_trailing_comma() {
+        assert_eq!(tuple_of(&["a".to_string()]), "(a,)");
+    }
+
+    #[test]
+    fn tuple_of_many_and_empty() {
+        assert_eq!(tuple_of(&["a".to_string(), "b".to_string()]), "(a, b)");
+        assert_eq!(tuple_of(&[]), "()");
+    }
+
+    #[test]
+    fn use_group_single_and_many() {
+        assert_eq!(use_group(&["RawCall"]), "RawCall");
+        assert_eq!(use_group(&["Address", "U256"]), "{Address, U256}");
+    }
+
+    #[test]
+    fn context_trait_follows_state_mutability() {
+        let view = parse_function("function balanceOf(address) view returns (uint256)");
+        let pure = parse_function("function add(uint256) pure returns (uint256)");
+        let nonpayable = parse_function("function transfer(address,uint256)");
+        let payable = parse_function("function deposit() payable");
+        assert_eq!(context_trait(&view), "StaticCallContext");
+        assert_eq!(context_trait(&pure), "StaticCallContext");
+        assert_eq!(context_trait(&nonpayable), "NonPayableCallContext");
+        assert_eq!(context_trait(&payable), "MutatingCallContext");
+    }
+
+    #[test]
+    fn static_calldata_len_only_for_word_arguments() {
+        let transfer = Function::parse("function transfer(address,uint256)").unwrap();
+        let none = Function::parse("function name()").unwrap();
+        let bytes = Function::parse("function f(address,bytes)").unwrap();
+        let array = Function::parse("function g(uint256[])").unwrap();
+        let string = Function::parse("function h(string)").unwrap();
+        assert_eq!(static_calldata_len(&transfer), Some(68));
+        assert_eq!(static_calldata_len(&none), Some(4));
+        assert_eq!(static_calldata_len(&bytes), None);
+        assert_eq!(static_calldata_len(&array), None);
+        assert_eq!(static_calldata_len(&string), None);
+        assert_eq!(encoder_return_type(&transfer), "[u8; 68]");
+        assert_eq!(encoder_return_type(&bytes), "Vec<u8>");
+    }
+
+    #[test]
+    fn static_arrays_take_one_head_word_per_element() {
+        assert_eq!(head_words("uint256"), 1);
+        assert_eq!(head_words("uint256[2]"), 2);
+        assert_eq!(head_words("address[2][3]"), 6);
+        for dynamic in ["bytes", "string", "uint256[]", "string[2]", "uint256[][2]"] {
+            assert_eq!(head_words(dynamic), 1, "{}", dynamic);
+        }
+    }
+
+    #[test]
+    fn names_function_by_name_signature_or_selector() {
+        let batch =
+            Function::parse("function balanceOfBatch(address[],uint256[]) returns (uint256[])")
+                .unwrap();
+        assert!(names_function("balanceOfBatch", &batch));
+        assert!(names_function(
+            "balanceOfBatch(address[],uint256[])",
+            &batch
+        ));
+        assert!(names_function("0x4e1273f4", &batch));
+        assert!(!names_function("4e1273f4", &batch));
+        assert!(!names_function("balanceOf", &batch));
+    }
+
+    #[test]
+    fn output_accessor_falls_back_to_index() {
+        assert_eq!(output_accessor("", 0), "output_0");
+        assert_eq!(output_accessor("tokenBalances", 1), "token_balances");
+    }
+
+    #[test]
+    fn public_signature_receiver_follows_state_mutability() {
+        let cases = [
+            (
+                "function add(uint256 a) pure returns (uint256)",
+                "fn add__0x1003e2d2(a: U256) -> Result<U256, Vec<u8>>",
+            ),
+            (
+                "function ownerOf(uint256 tokenId) view returns (address)",
+                "fn owner_of__0x6352211e(&self, tokenId: U256) -> Result<Address, Vec<u8>>",
+            ),
+            (
+                "function setData(bytes data)",
+                "fn set_data__0xab62f0e1(&mut self, data: Bytes) -> Result<(), Vec<u8>>",
+            ),
+            (
+                "function deposit() payable returns (bool, bytes)",
+                "fn deposit__0xd0e30db0(&mut self) -> Result<(bool, Bytes), Vec<u8>>",
+            ),
+        ];
+        for (solidity, expected) in cases {
+            let function = parse_function(solidity);
+            assert_eq!(public_signature(&function, &Options::default()), expected);
+        }
+    }
+
+    #[test]
+    fn public_inputs_are_rust_identifiers() {
+        let function: Function = serde_json::from_str(
+            r#"{"type": "function", "name": "put", "stateMutability": "nonpayable",
+                "inputs": [{"name": "", "type": "address"}, {"name": "type", "type": "uint256"},
+                           {"name": "self", "type": "bool"}, {"name": "to", "type": "bytes"}],
+                "outputs": []}"#,
+        )
+        .unwrap();
+        assert_eq!(input_names(&function), ["arg0", "r#type", "self_", "to"]);
+        assert_eq!(
+            public_signature(&function, &Options::default()),
+            format!(
+                "fn put__0x{}(&mut self, arg0: Address, r#type: U256, self_: bool, to: Bytes) -> \
+                 Result<(), Vec<u8>>",
+                alloy_primitives::hex::encode(function.selector())
+            )
+        );
+    }
+
+    #[test]
+    fn public_types_export_their_own_width() {
+        assert_eq!(to_public_type("uint64"), "u64");
+        assert_eq!(to_public_type("uint96"), "Uint<96, 2>");
+        assert_eq!(to_public_type("int24[]"), "Vec<Signed<24, 1>>");
+        assert_eq!(to_public_type("int256"), "I256");
+        assert_eq!(to_public_type("bytes"), "Bytes");
+    }
+
+    #[test]
+    fn overloads_naming_suffixes_only_clashing_names() {
+        let abi = JsonAbi::parse([
+            "function transfer(address to, uint256 amount)",
+            "function safeMint(address to)",
+            "function safeMint(address to, bytes data)",
+            "function getValue()",
+            "function get_value()",
+            "function type()",
+            "function new()",
+        ])
+        .unwrap();
+        assert!(plain_names(&abi, Naming::Selector).is_empty());
+        let plain: Vec<String> = plain_names(&abi, Naming::Overloads).into_iter().collect();
+        assert_eq!(plain, ["transfer(address,uint256)"]);
     }
 }
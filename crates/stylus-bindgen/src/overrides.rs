@@ -0,0 +1,214 @@
+//! `--type KEY=TYPE`: Rust types to use instead of the ones bindgen picks, for parameters
+//! selected by Solidity type (`bytes32`), `internalType` (`contract IERC20`) or
+//! `function.param` path, where both sides are globs (`*.*Hash`).
+//!
+//! An override must encode as the Solidity type it replaces, which alloy derives from `Borrow`
+//! of the decoded type for words (`Borrow<U256>` for `uint256`, `Borrow<[u8; 32]>` for
+//! `bytes32`) and `AsRef` for `bytes` and `string`, and implement `From` the decoded type.
+//! Generated files check both next to the overrides, so a missing impl is reported there
+//! rather than somewhere in the encoders.
+
+use crate::{filter::glob_matches, names_function};
+use alloy_json_abi::{Function, JsonAbi, Param};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Override {
+    pub(crate) key: String,
+    pub(crate) rust_type: String,
+}
+
+/// How an override key selected a parameter, from least to most specific.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Match {
+    SolidityType,
+    InternalType,
+    Path,
+}
+
+/// Whether `rust_type` looks like a Rust type: a path with generics, an array or a tuple.
+fn is_rust_type(rust_type: &str) -> bool {
+    rust_type.starts_with(|c: char| c.is_ascii_alphabetic() || "_:[(".contains(c))
+        && rust_type
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_:<>,; []()".contains(c))
+}
+
+impl Override {
+    pub(crate) fn new(key: &str, rust_type: &str) -> anyhow::Result<Self> {
+        let (key, rust_type) = (key.trim(), rust_type.trim());
+        if key.is_empty() {
+            anyhow::bail!("override of `{}` has an empty key", rust_type);
+        }
+        if !is_rust_type(rust_type) {
+            anyhow::bail!("`{}`: `{}` is not a Rust type", key, rust_type);
+        }
+        Ok(Self {
+            key: key.to_string(),
+            rust_type: rust_type.to_string(),
+        })
+    }
+
+    /// Parses `KEY=TYPE`, as given to `--type`.
+    pub(crate) fn parse(spec: &str) -> anyhow::Result<Self> {
+        let (key, rust_type) = spec
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("`{}` is not KEY=TYPE", spec))?;
+        Self::new(key, rust_type)
+    }
+
+    fn matches(&self, function: &Function, param: &Param) -> Option<Match> {
+        if self.key == param.ty {
+            return Some(Match::SolidityType);
+        }
+        if param
+            .internal_type()
+            .is_some_and(|internal| internal.to_string() == self.key)
+        {
+            return Some(Match::InternalType);
+        }
+        let (function_pattern, param_pattern) = self.key.rsplit_once('.')?;
+        (!param.name.is_empty()
+            && names_function(function_pattern, function)
+            && glob_matches(param_pattern, &param.name))
+        .then_some(Match::Path)
+    }
+}
+
+/// The overrides of `param` that apply: those of the most specific kind that matches it.
+fn best<'a>(overrides: &'a [Override], function: &Function, param: &Param) -> Vec<&'a Override> {
+    let matches: Vec<(Match, &Override)> = overrides
+        .iter()
+        .filter_map(|o| o.matches(function, param).map(|kind| (kind, o)))
+        .collect();
+    let Some(top) = matches.iter().map(|(kind, _)| *kind).max() else {
+        return Vec::new();
+    };
+    matches
+        .into_iter()
+        .filter(|(kind, _)| *kind == top)
+        .map(|(_, o)| o)
+        .collect()
+}
+
+/// Rust type overriding `param` of `function`, if any.
+pub(crate) fn lookup<'a>(
+    overrides: &'a [Override],
+    function: &Function,
+    param: &Param,
+) -> Option<&'a str> {
+    best(overrides, function, param)
+        .first()
+        .map(|o| o.rust_type.as_str())
+}
+
+/// Fails unless every override selects a parameter of `abis` and none is ambiguous.
+pub(crate) fn check(overrides: &[Override], abis: &[&JsonAbi]) -> anyhow::Result<()> {
+    let params = || {
+        abis.iter()
+            .flat_map(|abi| abi.functions())
+            .flat_map(|function| {
+                function
+                    .inputs
+                    .iter()
+                    .chain(&function.outputs)
+                    .map(move |param| (function, param))
+            })
+    };
+    for o in overrides {
+        if !params().any(|(function, param)| o.matches(function, param).is_some()) {
+            anyhow::bail!("--type {}: matches no parameter", o.key);
+        }
+    }
+    for (function, param) in params() {
+        if let [first, .., other] = best(overrides, function, param)[..] {
+            if first.rust_type != other.rust_type {
+                anyhow::bail!(
+                    "--type {} and --type {} both match `{}` of {}",
+                    first.key,
+                    other.key,
+                    if param.name.is_empty() {
+                        &param.ty
+                    } else {
+                        &param.name
+                    },
+                    function.signature()
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, lookup, Override};
+    use alloy_json_abi::JsonAbi;
+
+    fn abi() -> JsonAbi {
+        serde_json::from_str(
+            r#"[
+                {"type": "function", "name": "settle", "stateMutability": "nonpayable",
+                 "inputs": [
+                    {"name": "orderHash", "type": "bytes32", "internalType": "bytes32"},
+                    {"name": "token", "type": "address", "internalType": "contract IERC20"},
+                    {"name": "tokenId", "type": "uint256", "internalType": "uint256"}],
+                 "outputs": [{"name": "", "type": "uint256", "internalType": "uint256"}]}
+            ]"#,
+        )
+        .unwrap()
+    }
+
+    fn overrides(specs: &[&str]) -> Vec<Override> {
+        specs
+            .iter()
+            .map(|spec| Override::parse(spec).unwrap())
+            .collect()
+    }
+
+    fn resolved(specs: &[&str]) -> Vec<Option<String>> {
+        let (abi, overrides) = (abi(), overrides(specs));
+        let function = abi.functions().next().unwrap();
+        function
+            .inputs
+            .iter()
+            .chain(&function.outputs)
+            .map(|param| lookup(&overrides, function, param).map(str::to_string))
+            .collect()
+    }
+
+    #[test]
+    fn specific_keys_win() {
+        assert_eq!(
+            resolved(&[
+                "uint256=crate::Amount",
+                "settle.tokenId=crate::TokenId",
+                "*.*Hash=crate::OrderHash",
+                "contract IERC20=crate::Erc20Handle",
+            ]),
+            [
+                Some("crate::OrderHash".to_string()),
+                Some("crate::Erc20Handle".to_string()),
+                Some("crate::TokenId".to_string()),
+                Some("crate::Amount".to_string()),
+            ]
+        );
+        // Unnamed outputs are only reachable by type.
+        assert_eq!(resolved(&["settle.*=X"])[3], None);
+    }
+
+    #[test]
+    fn invalid_overrides_are_rejected() {
+        assert!(Override::parse("uint256").is_err());
+        assert!(Override::parse("=crate::Amount").is_err());
+        assert!(Override::parse("uint256=not a type!").is_err());
+
+        let abi = abi();
+        let error = |specs: &[&str]| check(&overrides(specs), &[&abi]).unwrap_err().to_string();
+        assert_eq!(error(&["uint8=u8"]), "--type uint8: matches no parameter");
+        assert_eq!(
+            error(&["*.token*=A", "settle.tokenId=B"]),
+            "--type *.token* and --type settle.tokenId both match `tokenId` of settle(bytes32,address,uint256)"
+        );
+        assert!(check(&overrides(&["*.token*=A", "uint256=A"]), &[&abi]).is_ok());
+    }
+}
@@ -0,0 +1,153 @@
+//! Reading ABIs from what projects have on disk: ABI JSON, Foundry/Hardhat artifacts, which
+//! carry the ABI in an `abi` field, and Solidity sources compiled with `solc`.
+
+use alloy_json_abi::JsonAbi;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Reads the ABI of `path`; for `.sol` sources, that of the contract named like the file, or of
+/// its only contract.
+pub(crate) fn read_abi(path: &Path) -> anyhow::Result<JsonAbi> {
+    let with_path = |e: &dyn core::fmt::Display| anyhow::anyhow!("{}: {}", path.display(), e);
+    let abi = if path.extension().is_some_and(|extension| extension == "sol") {
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+        contract_abi(&solc(path)?, name).map_err(|e| with_path(&e))?
+    } else {
+        let content = fs::read_to_string(path).map_err(|e| with_path(&e))?;
+        let json: Value = serde_json::from_str(&content).map_err(|e| with_path(&e))?;
+        artifact_abi(json)
+    };
+    // alloy-json-abi borrows its strings while deserializing, which a `Value` can't lend.
+    serde_json::from_str(&abi.to_string()).map_err(|e| with_path(&e))
+}
+
+/// The `abi` field of a compiler artifact, or `json` itself for a plain ABI.
+fn artifact_abi(json: Value) -> Value {
+    match json {
+        Value::Object(mut artifact) if artifact.contains_key("abi") => {
+            artifact.remove("abi").unwrap()
+        }
+        abi => abi,
+    }
+}
+
+/// `solc --combined-json abi` output for `path`.
+fn solc(path: &Path) -> anyhow::Result<Value> {
+    let output = Command::new("solc")
+        .args(["--combined-json", "abi"])
+        .arg(path)
+        .output()
+        .map_err(|e| anyhow::anyhow!("{}: running solc: {}", path.display(), e))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "{}: solc failed:\n{}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    Ok(serde_json::from_slice(&output.stdout)?)
+}
+
+/// ABI of contract `name` in `solc --combined-json abi` output, falling back to the only
+/// contract when none is called `name`.
+fn contract_abi(combined: &Value, name: &str) -> anyhow::Result<Value> {
+    let contracts = combined["contracts"]
+        .as_object()
+        .ok_or_else(|| anyhow::anyhow!("solc output has no contracts"))?;
+    // Keys are `<source path>:<contract name>`.
+    let named = contracts
+        .iter()
+        .find(|(key, _)| key.rsplit(':').next() == Some(name));
+    let (_, contract) = match named {
+        Some(contract) => contract,
+        None if contracts.len() == 1 => contracts.iter().next().unwrap(),
+        None => {
+            let names: Vec<&str> = contracts
+                .keys()
+                .filter_map(|key| key.rsplit(':').next())
+                .collect();
+            anyhow::bail!(
+                "no contract `{}` among {}; name the file after the contract to bind",
+                name,
+                names.join(", ")
+            );
+        }
+    };
+    // solc before 0.8.10 embeds the ABI as a JSON string.
+    match &contract["abi"] {
+        Value::String(json) => Ok(serde_json::from_str(json)?),
+        abi => Ok(abi.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{artifact_abi, contract_abi, read_abi};
+    use serde_json::json;
+    use std::fs;
+
+    #[test]
+    fn abis_and_artifacts_read_the_same() {
+        let abi = json!([{
+            "type": "function",
+            "name": "transfer",
+            "inputs": [
+                { "name": "to", "type": "address" },
+                { "name": "amount", "type": "uint256" },
+            ],
+            "outputs": [{ "name": "", "type": "bool" }],
+            "stateMutability": "nonpayable",
+        }]);
+        let artifact = json!({ "abi": abi, "bytecode": { "object": "0x" } });
+        let dir =
+            std::env::temp_dir().join(format!("stylus_bindgen_source_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (plain, built) = (dir.join("Token.json"), dir.join("Token.artifact.json"));
+        fs::write(&plain, abi.to_string()).unwrap();
+        fs::write(&built, artifact.to_string()).unwrap();
+        let (plain, built) = (read_abi(&plain).unwrap(), read_abi(&built).unwrap());
+        fs::remove_dir_all(dir).unwrap();
+        assert_eq!(plain, built);
+        let signatures: Vec<String> = plain.functions().map(|f| f.signature()).collect();
+        assert_eq!(signatures, ["transfer(address,uint256)"]);
+    }
+
+    #[test]
+    fn artifacts_unwrap_to_their_abi() {
+        let abi = json!([{ "type": "function", "name": "f" }]);
+        let artifact = json!({ "abi": abi, "bytecode": { "object": "0x" } });
+        assert_eq!(artifact_abi(artifact), abi);
+        assert_eq!(artifact_abi(abi.clone()), abi);
+    }
+
+    #[test]
+    fn solc_contract_by_file_name() {
+        let combined = json!({
+            "contracts": {
+                "src/Vault.sol:IERC20": { "abi": [{ "type": "function", "name": "transfer" }] },
+                "src/Vault.sol:Vault": { "abi": "[{\"type\":\"function\",\"name\":\"deposit\"}]" },
+            },
+            "version": "0.8.9",
+        });
+        assert_eq!(
+            contract_abi(&combined, "Vault").unwrap(),
+            json!([{ "type": "function", "name": "deposit" }])
+        );
+        let error = contract_abi(&combined, "Other").unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "no contract `Other` among IERC20, Vault; name the file after the contract to bind"
+        );
+    }
+
+    #[test]
+    fn solc_only_contract() {
+        let combined = json!({ "contracts": { "a.sol:Token": { "abi": [] } } });
+        assert_eq!(contract_abi(&combined, "a").unwrap(), json!([]));
+    }
+}
@@ -0,0 +1,384 @@
+//! `stylus-bindgen verify-bytecode`: checks offline that runtime bytecode dispatches the
+//! selectors of an ABI.
+//!
+//! Dispatchers compare the calldata selector against `PUSH <selector>` immediates: Solidity
+//! with `EQ` (and `GT`/`LT` when it binary-searches many functions), Vyper with `XOR`. Vyper's
+//! dense jump tables keep selectors as data instead: when the code buckets the selector that
+//! way, selectors that only appear as raw bytes are reported as embedded rather than absent.
+
+use crate::Format;
+use alloy_json_abi::{Function, JsonAbi};
+use alloy_primitives::hex;
+use serde_json::json;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(clap::Args)]
+pub(crate) struct Args {
+    /// ABI JSON whose functions should be dispatched
+    #[arg(long)]
+    abi: PathBuf,
+
+    /// Runtime bytecode as hex, with or without `0x`
+    #[arg(long)]
+    code: PathBuf,
+
+    /// Report format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+const PUSH1: u8 = 0x60;
+const PUSH4: u8 = 0x63;
+const PUSH32: u8 = 0x7f;
+const LT: u8 = 0x10;
+const GT: u8 = 0x11;
+const EQ: u8 = 0x14;
+const XOR: u8 = 0x18;
+const MOD: u8 = 0x06;
+const SHR: u8 = 0x1c;
+const CODECOPY: u8 = 0x39;
+const POP: u8 = 0x50;
+const DUP1: u8 = 0x80;
+const SWAP16: u8 = 0x9f;
+
+/// Instructions after a `PUSH4` searched for the comparison, skipping stack shuffles.
+const COMPARISON_WINDOW: usize = 3;
+
+/// How a function's selector shows up in the bytecode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Status {
+    /// Compared against the calldata selector by the dispatcher.
+    Dispatched,
+    /// Present only as raw bytes, e.g. in a Vyper jump table.
+    Embedded,
+    Absent,
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Dispatched => "dispatched",
+            Self::Embedded => "embedded",
+            Self::Absent => "absent",
+        }
+    }
+}
+
+/// Opcodes and `PUSH` immediates of `code`, in order. A truncated trailing `PUSH` keeps the
+/// bytes that are there, as the EVM pads them with zeros.
+fn instructions(code: &[u8]) -> Vec<(u8, &[u8])> {
+    let mut instructions = Vec::new();
+    let mut pc = 0;
+    while pc < code.len() {
+        let opcode = code[pc];
+        let size = match opcode {
+            PUSH1..=PUSH32 => usize::from(opcode - PUSH1) + 1,
+            _ => 0,
+        };
+        let end = (pc + 1 + size).min(code.len());
+        instructions.push((opcode, &code[pc + 1..end]));
+        pc = end;
+    }
+    instructions
+}
+
+/// Selectors pushed with `PUSH1` to `PUSH4` and compared within the next few instructions.
+/// solc pushes selectors with leading zero bytes in fewer bytes, e.g. `0x00fdd58e` as
+/// `PUSH3 0xfdd58e`.
+pub(crate) fn dispatched_selectors(code: &[u8]) -> BTreeSet<[u8; 4]> {
+    let instructions = instructions(code);
+    let mut selectors = BTreeSet::new();
+    for (index, (opcode, immediate)) in instructions.iter().enumerate() {
+        if !(PUSH1..=PUSH4).contains(opcode) {
+            continue;
+        }
+        // Truncated at the end of the code.
+        let size = usize::from(opcode - PUSH1) + 1;
+        if immediate.len() != size {
+            continue;
+        }
+        let mut selector = [0; 4];
+        selector[4 - size..].copy_from_slice(immediate);
+        let compared = instructions[index + 1..]
+            .iter()
+            .take(COMPARISON_WINDOW)
+            .map(|(opcode, _)| *opcode)
+            .find(|opcode| !(DUP1..=SWAP16).contains(opcode))
+            .is_some_and(|opcode| matches!(opcode, EQ | XOR | GT | LT));
+        if compared {
+            selectors.insert(selector);
+        }
+    }
+    selectors
+}
+
+/// Whether `code` looks up selectors in a Vyper dense jump table: it reduces the calldata
+/// selector (`PUSH1 0xe0 SHR`) modulo the bucket count and copies the bucket out of the code.
+pub(crate) fn has_selector_table(code: &[u8]) -> bool {
+    let instructions = instructions(code);
+    let buckets = instructions.windows(2).enumerate().any(|(index, pair)| {
+        pair == [(PUSH1, &[0xe0][..]), (SHR, &[][..])]
+            && instructions[index + 2..]
+                .iter()
+                .take(COMPARISON_WINDOW + 1)
+                .map(|(opcode, _)| *opcode)
+                .find(|opcode| {
+                    !(PUSH1..=PUSH32).contains(opcode)
+                        && !(DUP1..=SWAP16).contains(opcode)
+                        && *opcode != POP
+                })
+                == Some(MOD)
+    });
+    buckets && instructions.iter().any(|(opcode, _)| *opcode == CODECOPY)
+}
+
+/// Status of `selector` given the dispatched selectors of `code` and whether it has a
+/// [selector table](has_selector_table).
+pub(crate) fn status(
+    code: &[u8],
+    dispatched: &BTreeSet<[u8; 4]>,
+    selector_table: bool,
+    selector: [u8; 4],
+) -> Status {
+    if dispatched.contains(&selector) {
+        Status::Dispatched
+    } else if selector_table && code.windows(4).any(|window| window == selector) {
+        Status::Embedded
+    } else {
+        Status::Absent
+    }
+}
+
+/// Decodes a bytecode hex file, ignoring a `0x` prefix and whitespace.
+fn parse_code(content: &str) -> anyhow::Result<Vec<u8>> {
+    let digits: String = content.split_whitespace().collect();
+    let digits = digits.strip_prefix("0x").unwrap_or(&digits);
+    hex::decode(digits).map_err(|e| anyhow::anyhow!("bytecode is not valid hex: {}", e))
+}
+
+struct Report<'a> {
+    functions: Vec<(&'a Function, Status)>,
+    /// Dispatched selectors that match no function of the ABI.
+    unknown: Vec<[u8; 4]>,
+}
+
+fn render_text(args: &Args, report: &Report<'_>) -> String {
+    let present = report
+        .functions
+        .iter()
+        .filter(|(_, status)| *status != Status::Absent)
+        .count();
+    let mut text = format!(
+        "{} implements {} of {} functions in {}:\n",
+        args.code.display(),
+        present,
+        report.functions.len(),
+        args.abi.display()
+    );
+    for (function, status) in &report.functions {
+        text.push_str(&format!(
+            "  {:<11}0x{}  {}\n",
+            status.name(),
+            hex::encode(function.selector()),
+            function.signature()
+        ));
+    }
+    if !report.unknown.is_empty() {
+        let unknown: Vec<String> = report
+            .unknown
+            .iter()
+            .map(|selector| format!("0x{}", hex::encode(selector)))
+            .collect();
+        text.push_str(&format!(
+            "  also dispatches {} selector{} not in the ABI: {}\n",
+            unknown.len(),
+            if unknown.len() == 1 { "" } else { "s" },
+            unknown.join(", ")
+        ));
+    }
+    text
+}
+
+fn render_json(args: &Args, report: &Report<'_>) -> serde_json::Value {
+    let functions: Vec<serde_json::Value> = report
+        .functions
+        .iter()
+        .map(|(function, status)| {
+            json!({
+                "signature": function.signature(),
+                "selector": format!("0x{}", hex::encode(function.selector())),
+                "status": status.name(),
+            })
+        })
+        .collect();
+    let unknown: Vec<String> = report
+        .unknown
+        .iter()
+        .map(|selector| format!("0x{}", hex::encode(selector)))
+        .collect();
+    json!({
+        "abi": args.abi.display().to_string(),
+        "code": args.code.display().to_string(),
+        "functions": functions,
+        "unknown_selectors": unknown,
+    })
+}
+
+/// Prints the report; returns whether every ABI function was found.
+pub(crate) fn run(args: &Args) -> anyhow::Result<bool> {
+    let abi: JsonAbi = serde_json::from_str(&fs::read_to_string(&args.abi)?)?;
+    let code = parse_code(&fs::read_to_string(&args.code)?)?;
+
+    let dispatched = dispatched_selectors(&code);
+    let selector_table = has_selector_table(&code);
+    let functions: Vec<(&Function, Status)> = abi
+        .functions()
+        .map(|function| {
+            let selector = function.selector().0;
+            (
+                function,
+                status(&code, &dispatched, selector_table, selector),
+            )
+        })
+        .collect();
+    let known: BTreeSet<[u8; 4]> = abi.functions().map(|f| f.selector().0).collect();
+    let report = Report {
+        unknown: dispatched.difference(&known).copied().collect(),
+        functions,
+    };
+
+    match args.format {
+        Format::Text => print!("{}", render_text(args, &report)),
+        Format::Json => println!("{:#}", render_json(args, &report)),
+    }
+    Ok(report
+        .functions
+        .iter()
+        .all(|(_, status)| *status != Status::Absent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dispatched_selectors, has_selector_table, parse_code, status, Status};
+    use alloy_primitives::hex;
+
+    const TRANSFER: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+    const BALANCE_OF: [u8; 4] = [0x70, 0xa0, 0x82, 0x31];
+    const APPROVE: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+    const BALANCE_OF_ID: [u8; 4] = [0x00, 0xfd, 0xd5, 0x8e];
+
+    /// `PUSH1 0 CALLDATALOAD PUSH1 0xe0 SHR PUSH1 3 DUP2 MOD`, then a `CODECOPY` of the bucket.
+    const BUCKETED_SELECTOR: [u8; 13] = [
+        0x60, 0x00, 0x35, 0x60, 0xe0, 0x1c, 0x60, 0x03, 0x81, 0x06, 0x60, 0x07, 0x39,
+    ];
+
+    /// `DUP1 PUSH4 <selector> <comparison> PUSH2 <dest> JUMPI`
+    fn dispatch(selector: [u8; 4], comparison: u8) -> Vec<u8> {
+        let mut code = vec![0x80, 0x63];
+        code.extend(selector);
+        code.extend([comparison, 0x61, 0x00, 0x42, 0x57]);
+        code
+    }
+
+    #[test]
+    fn solidity_eq_dispatch() {
+        // `PUSH1 0 CALLDATALOAD PUSH1 0xe0 SHR`, then the dispatcher.
+        let mut code = hex::decode("60003560e01c").unwrap();
+        code.extend(dispatch(TRANSFER, 0x14));
+        code.extend(dispatch(BALANCE_OF, 0x14));
+        let dispatched = dispatched_selectors(&code);
+        assert_eq!(dispatched.len(), 2);
+        assert_eq!(
+            status(&code, &dispatched, false, TRANSFER),
+            Status::Dispatched
+        );
+        assert_eq!(status(&code, &dispatched, false, APPROVE), Status::Absent);
+    }
+
+    #[test]
+    fn selectors_with_leading_zeros_use_shorter_pushes() {
+        // `DUP1 PUSH3 0xfdd58e EQ`, and `DUP1 PUSH1 0x01 EQ` for selector 0x00000001.
+        let code = [0x80, 0x62, 0xfd, 0xd5, 0x8e, 0x14, 0x80, 0x60, 0x01, 0x14];
+        let dispatched = dispatched_selectors(&code);
+        assert!(dispatched.contains(&BALANCE_OF_ID));
+        assert!(dispatched.contains(&[0, 0, 0, 1]));
+        assert_eq!(dispatched.len(), 2);
+    }
+
+    #[test]
+    fn solidity_binary_search_and_legacy_order() {
+        // `DUP1 PUSH4 pivot GT` then `PUSH4 sel DUP2 EQ` (solc < 0.5 pushes before duplicating).
+        let mut code = dispatch(BALANCE_OF, 0x11);
+        code.extend([0x63]);
+        code.extend(TRANSFER);
+        code.extend([0x81, 0x14]);
+        let dispatched = dispatched_selectors(&code);
+        assert!(dispatched.contains(&BALANCE_OF));
+        assert!(dispatched.contains(&TRANSFER));
+    }
+
+    #[test]
+    fn vyper_xor_dispatch() {
+        // `PUSH4 sel DUP2 XOR PUSH2 next JUMPI`
+        let mut code = vec![0x63];
+        code.extend(APPROVE);
+        code.extend([0x81, 0x18, 0x61, 0x00, 0x10, 0x57]);
+        assert!(dispatched_selectors(&code).contains(&APPROVE));
+    }
+
+    #[test]
+    fn push_data_and_masks_are_not_dispatch() {
+        // PUSH5 whose data looks like `PUSH4 <transfer>`, and a PUSH4 mask followed by AND.
+        let mut code = vec![0x64, 0x63];
+        code.extend(TRANSFER);
+        code.extend([0x14, 0x63, 0xff, 0xff, 0xff, 0xff, 0x16, 0x14]);
+        let dispatched = dispatched_selectors(&code);
+        assert!(dispatched.is_empty());
+        assert!(!has_selector_table(&code));
+        assert_eq!(status(&code, &dispatched, false, TRANSFER), Status::Absent);
+    }
+
+    #[test]
+    fn jump_table_selectors_are_embedded() {
+        // Vyper's dense selector table: selector, jump destination, metadata.
+        let mut code = BUCKETED_SELECTOR.to_vec();
+        code.extend([0x00, 0xfe]);
+        code.extend(TRANSFER);
+        code.extend([0x01, 0x23, 0x44]);
+        let dispatched = dispatched_selectors(&code);
+        assert!(has_selector_table(&code));
+        assert_eq!(status(&code, &dispatched, true, TRANSFER), Status::Embedded);
+        assert_eq!(status(&code, &dispatched, true, APPROVE), Status::Absent);
+    }
+
+    #[test]
+    fn selector_tables_need_the_bucketing() {
+        // The same table bytes after a Solidity-style `PUSH1 0xe0 SHR DUP1 PUSH4 ... EQ`.
+        let mut code = hex::decode("60003560e01c").unwrap();
+        code.extend(dispatch(APPROVE, 0x14));
+        code.extend([0x39, 0x00, 0xfe]);
+        code.extend(TRANSFER);
+        assert!(!has_selector_table(&code));
+        // Without the `CODECOPY` there is no table to read.
+        assert!(!has_selector_table(&BUCKETED_SELECTOR[..12]));
+    }
+
+    #[test]
+    fn truncated_push_at_end_is_ignored() {
+        let mut code = vec![0x80, 0x63];
+        code.extend(&TRANSFER[..2]);
+        assert!(dispatched_selectors(&code).is_empty());
+        assert!(dispatched_selectors(&[0x80, 0x62, 0xfd]).is_empty());
+    }
+
+    #[test]
+    fn parse_code_accepts_prefix_and_whitespace() {
+        assert_eq!(
+            parse_code("0x6080\n6040 52\n").unwrap(),
+            [0x60, 0x80, 0x60, 0x40, 0x52]
+        );
+        assert!(parse_code("0x608").is_err());
+    }
+}
@@ -0,0 +1,145 @@
+//! `--input-dir`/`--out-dir`: one module per ABI plus an index, optionally as a crate.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+use common::{bindgen_binary, read_expected, unique_output_path, workspace_root, STANDARDS};
+
+fn run_batch(extra_args: &[&str]) -> Output {
+    Command::new(bindgen_binary())
+        .args(extra_args)
+        .current_dir(workspace_root())
+        .stderr(Stdio::null())
+        .output()
+        .expect("Failed to execute stylus-bindgen")
+}
+
+/// Generates `abis/` into a fresh directory and returns it.
+fn generate_abis(extra_args: &[&str]) -> String {
+    let out_dir = unique_output_path().with_extension("d");
+    let out = out_dir.to_str().unwrap();
+    let output = run_batch(&[&["--input-dir", "abis", "--out-dir", out], extra_args].concat());
+    assert!(output.status.success(), "batch generation failed");
+    out.to_string()
+}
+
+fn read(dir: &str, file: &str) -> String {
+    fs::read_to_string(Path::new(dir).join(file)).unwrap()
+}
+
+#[test]
+fn modules_match_single_file_output() {
+    let out = generate_abis(&[]);
+    for name in STANDARDS {
+        assert_eq!(read(&out, &format!("{}.rs", name)), read_expected(name));
+    }
+    assert_eq!(
+        read(&out, "mod.rs"),
+        "// Generated by stylus-bindgen\n#![allow(non_snake_case)]\n\npub mod erc1155;\npub mod erc20;\npub mod erc721;\npub mod ierc165;\n"
+    );
+}
+
+#[test]
+fn output_is_deterministic() {
+    let (first, second) = (
+        generate_abis(&["--lazy", "balanceOf"]),
+        generate_abis(&["--lazy", "balanceOf"]),
+    );
+    assert_eq!(read(&first, "mod.rs"), read(&second, "mod.rs"));
+    for name in STANDARDS {
+        let file = format!("{}.rs", name);
+        assert_eq!(read(&first, &file), read(&second, &file), "{}", file);
+    }
+}
+
+#[test]
+fn lib_index_and_crate_layout() {
+    let out = generate_abis(&["--lib"]);
+    assert!(read(&out, "lib.rs").contains("pub mod erc20;\n"));
+    assert!(!Path::new(&out).join("mod.rs").exists());
+
+    let out = generate_abis(&["--crate", "partner-bindings"]);
+    let manifest = read(&out, "Cargo.toml");
+    assert!(manifest.contains("name = \"partner-bindings\"\n"));
+    let published = format!("stylus-interfaces = \"{}\"\n", env!("CARGO_PKG_VERSION"));
+    assert!(manifest.contains(&published));
+    assert!(read(&out, "src/lib.rs").contains("pub mod ierc165;\n"));
+    assert_eq!(read(&out, "src/erc721.rs"), read_expected("erc721"));
+
+    let out = generate_abis(&["--crate", "local", "--interfaces-dep", "path=../interfaces"]);
+    let manifest = read(&out, "Cargo.toml");
+    assert!(manifest.contains("stylus-interfaces = { path = \"../interfaces\" }\n"));
+    let url = "git=https://example.com/stylus-interfaces.git";
+    let out = generate_abis(&["--crate", "pinned", "--interfaces-dep", url]);
+    let manifest = read(&out, "Cargo.toml");
+    assert!(manifest
+        .contains("stylus-interfaces = { git = \"https://example.com/stylus-interfaces.git\" }\n"));
+}
+
+#[test]
+fn modules_can_be_renamed() {
+    let out = generate_abis(&["--module", "erc20=token", "--naming", "overloads"]);
+    assert!(read(&out, "mod.rs")
+        .contains("pub mod erc1155;\npub mod erc721;\npub mod ierc165;\npub mod token;\n"));
+    let token = read(&out, "token.rs");
+    assert!(token.contains("pub trait TokenApi") && token.contains("pub struct MockToken"));
+    assert!(token.contains("pub fn balance_of(&self, owner: Address)"));
+    assert!(read(&out, "erc721.rs").contains("pub fn safe_transfer_from__0x42842e0e("));
+
+    for module in [
+        &["--module", "erc20=erc721"][..],
+        &["--module", "erc4626=vault"],
+    ] {
+        let out = unique_output_path().with_extension("d");
+        let output = run_batch(
+            &[
+                &["--input-dir", "abis", "--out-dir", out.to_str().unwrap()],
+                module,
+            ]
+            .concat(),
+        );
+        assert!(!output.status.success(), "{:?}", module);
+        assert!(!out.exists());
+    }
+}
+
+#[test]
+fn colliding_module_names_are_rejected() {
+    let input = unique_output_path().with_extension("abis");
+    fs::create_dir_all(&input).unwrap();
+    let abi = fs::read_to_string(Path::new(&workspace_root()).join("abis/erc20.json")).unwrap();
+    fs::write(input.join("ERC20.json"), &abi).unwrap();
+    fs::write(input.join("erc20.json"), &abi).unwrap();
+    let out = unique_output_path().with_extension("d");
+    let output = run_batch(&[
+        "--input-dir",
+        input.to_str().unwrap(),
+        "--out-dir",
+        out.to_str().unwrap(),
+    ]);
+    assert!(!output.status.success());
+    assert!(!out.exists());
+}
+
+#[test]
+fn single_file_flags_conflict_with_batch() {
+    assert!(!run_batch(&["--input-dir", "abis"]).status.success());
+    assert!(!run_batch(&[
+        "--input-dir",
+        "abis",
+        "--out-dir",
+        "x",
+        "-i",
+        "abis/erc20.json"
+    ])
+    .status
+    .success());
+    assert!(
+        !run_batch(&["--input-dir", "abis", "--out-dir", "x", "--name", "Token"])
+            .status
+            .success()
+    );
+}
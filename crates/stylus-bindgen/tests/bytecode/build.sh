@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+# Rebuilds the runtime bytecode fixtures of tests/verify_bytecode.rs: solc.hex from Token.sol and
+# vyper.hex from token.vy. Needs exactly solc 0.8.24 and vyper 0.3.10, as other versions
+# produce different bytecode. Vyper optimizes for code size, which dispatches through its dense
+# selector table.
+set -euo pipefail
+
+SOLC_VERSION=0.8.24
+VYPER_VERSION=0.3.10
+cd "$(dirname "$0")"
+
+if ! solc --version | grep -q "Version: $SOLC_VERSION+"; then
+    echo "build.sh needs solc $SOLC_VERSION, found: $(solc --version | tail -n 1)" >&2
+    exit 1
+fi
+if ! vyper --version | grep -q "^$VYPER_VERSION+"; then
+    echo "build.sh needs vyper $VYPER_VERSION, found: $(vyper --version)" >&2
+    exit 1
+fi
+
+solc --optimize --optimize-runs 200 --evm-version cancun --metadata-hash none --bin-runtime Token.sol \
+    | sed -n '/^Binary of the runtime part:$/{n;p;}' > solc.hex
+vyper --optimize codesize -f bytecode_runtime token.vy > vyper.hex
@@ -0,0 +1,47 @@
+# @version 0.3.10
+# The functions of abis/erc20.json and nothing else, for `verify-bytecode` tests.
+
+event Transfer:
+    sender: indexed(address)
+    receiver: indexed(address)
+    value: uint256
+
+event Approval:
+    owner: indexed(address)
+    spender: indexed(address)
+    value: uint256
+
+balanceOf: public(HashMap[address, uint256])
+allowance: public(HashMap[address, HashMap[address, uint256]])
+totalSupply: public(uint256)
+
+
+@external
+def __init__():
+    self.balanceOf[msg.sender] = 1000
+    self.totalSupply = 1000
+    log Transfer(empty(address), msg.sender, 1000)
+
+
+@external
+def transfer(receiver: address, value: uint256) -> bool:
+    self.balanceOf[msg.sender] -= value
+    self.balanceOf[receiver] += value
+    log Transfer(msg.sender, receiver, value)
+    return True
+
+
+@external
+def transferFrom(sender: address, receiver: address, value: uint256) -> bool:
+    self.allowance[sender][msg.sender] -= value
+    self.balanceOf[sender] -= value
+    self.balanceOf[receiver] += value
+    log Transfer(sender, receiver, value)
+    return True
+
+
+@external
+def approve(spender: address, value: uint256) -> bool:
+    self.allowance[msg.sender][spender] = value
+    log Approval(msg.sender, spender, value)
+    return True
@@ -56,6 +56,11 @@ pub fn unique_output_path() -> PathBuf {
 }
 
 pub fn run_bindgen(input: &str) -> String {
+    run_bindgen_with(input, &[])
+}
+
+/// Like [`run_bindgen`], with extra CLI flags (e.g. `--delegate`).
+pub fn run_bindgen_with(input: &str, extra_args: &[&str]) -> String {
     let output = unique_output_path();
     let bin = bindgen_binary();
     assert!(
@@ -65,6 +70,7 @@ pub fn run_bindgen(input: &str) -> String {
     );
     let status = Command::new(&bin)
         .args(["--input", input, "--output", output.to_str().unwrap()])
+        .args(extra_args)
         .current_dir(workspace_root())
         .status()
         .expect("Failed to execute stylus-bindgen");
@@ -75,7 +81,7 @@ pub fn run_bindgen(input: &str) -> String {
 
 pub fn read_expected(name: &str) -> String {
     let path = format!("{}/tests/expected/{}.rs", env!("CARGO_MANIFEST_DIR"), name);
-    fs::read_to_string(&path).expect(&format!("Failed to read expected file: {}", path))
+    fs::read_to_string(&path).unwrap_or_else(|_| panic!("Failed to read expected file: {}", path))
 }
 
 /// Extract all `pub fn <name>(` function names from source code.
@@ -92,11 +98,80 @@ pub fn extract_fn_names(src: &str) -> Vec<String> {
     names
 }
 
-/// Extract selector-suffixed function names (excluding `new`).
+/// The `impl Contract { ... }` block of generated source.
+pub fn contract_impl(src: &str) -> &str {
+    impl_block(src, "Contract")
+}
+
+/// The `impl <type_name> { ... }` block of generated source.
+pub fn impl_block<'a>(src: &'a str, type_name: &str) -> &'a str {
+    let generic = format!("impl<T: Transport> {}<T>", type_name);
+    if src.contains(&format!("{} {{", generic)) {
+        return item_block(src, &generic);
+    }
+    item_block(src, &format!("impl {}", type_name))
+}
+
+/// Top-level item starting with `header {`, e.g. `pub trait Erc20Api` or `impl X for Y`.
+pub fn item_block<'a>(src: &'a str, header: &str) -> &'a str {
+    let header = format!("{} {{", header);
+    let start = src
+        .find(&header)
+        .unwrap_or_else(|| panic!("generated source must contain `{}`", header));
+    let end = src[start..]
+        .find("\n}\n")
+        .map(|e| start + e + 2)
+        .unwrap_or(src.len());
+    &src[start..end]
+}
+
+/// `Contract` methods that are not selector-suffixed bindings.
+pub const NON_SELECTOR_METHODS: &[&str] = &["new", "with_transport", "with"];
+
+/// Extract selector-suffixed `Contract` method names (excluding constructors and options).
 pub fn extract_selector_fns(src: &str) -> Vec<String> {
+    extract_fn_names(contract_impl(src))
+        .into_iter()
+        .filter(|n| !NON_SELECTOR_METHODS.contains(&n.as_str()))
+        .collect()
+}
+
+/// Extract `encode_<name>__0x<selector>` calldata encoder names.
+pub fn extract_encoder_fns(src: &str) -> Vec<String> {
     extract_fn_names(src)
         .into_iter()
-        .filter(|n| n != "new")
+        .filter(|n| is_encoder(n))
+        .collect()
+}
+
+/// Extract `decode_<name>__0x<selector>_returns` return-data decoder names.
+pub fn extract_decoder_fns(src: &str) -> Vec<String> {
+    extract_fn_names(src)
+        .into_iter()
+        .filter(|n| is_decoder(n))
+        .collect()
+}
+
+pub fn is_encoder(name: &str) -> bool {
+    name.starts_with("encode_")
+}
+
+pub fn is_decoder(name: &str) -> bool {
+    name.starts_with("decode_") && name.ends_with("_returns")
+}
+
+/// Extract `Calls` enum variant names (`UpperCamelName__0x<selector>`).
+pub fn extract_calls_variants(src: &str) -> Vec<String> {
+    let start = src
+        .find("pub enum Calls {")
+        .expect("generated source must contain `pub enum Calls {`");
+    let body = &src[start..start + src[start..].find("\n}\n").unwrap_or(src.len() - start)];
+    body.lines()
+        .skip(1)
+        .map(str::trim)
+        .filter(|l| !l.starts_with("//") && !l.is_empty())
+        .filter_map(|l| l.split([' ', ',']).next())
+        .map(str::to_string)
         .collect()
 }
 
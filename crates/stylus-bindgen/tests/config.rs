@@ -0,0 +1,145 @@
+//! `sipb.toml`: running `stylus-bindgen` without arguments regenerates every target.
+
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+use common::{bindgen_binary, read_expected, unique_output_path, workspace_root};
+
+fn run_in(dir: &Path, args: &[&str]) -> Output {
+    Command::new(bindgen_binary())
+        .args(args)
+        .current_dir(dir)
+        .stderr(Stdio::null())
+        .output()
+        .expect("Failed to execute stylus-bindgen")
+}
+
+/// A fresh project directory holding `abis/` and `sipb.toml`.
+fn project(sipb: &str) -> PathBuf {
+    let dir = unique_output_path().with_extension("project");
+    fs::create_dir_all(dir.join("abis")).unwrap();
+    for name in ["erc20", "erc721"] {
+        let abi = fs::read_to_string(format!("{}/abis/{}.json", workspace_root(), name)).unwrap();
+        fs::write(dir.join("abis").join(format!("{}.json", name)), abi).unwrap();
+    }
+    fs::write(dir.join("sipb.toml"), sipb).unwrap();
+    dir
+}
+
+#[test]
+fn no_arguments_regenerate_every_target() {
+    let dir = project(
+        r#"
+[[target]]
+input = "abis/erc20.json"
+output = "erc20.rs"
+
+[[target]]
+input-dir = "abis"
+out-dir = "bindings"
+lib = true
+"#,
+    );
+    assert!(run_in(&dir, &[]).status.success());
+    let read = |path: &str| fs::read_to_string(dir.join(path)).unwrap();
+    assert_eq!(read("erc20.rs"), read_expected("erc20"));
+    assert_eq!(read("bindings/erc721.rs"), read_expected("erc721"));
+    assert!(read("bindings/lib.rs").contains("pub mod erc20;\n"));
+}
+
+#[test]
+fn config_paths_are_relative_to_the_file() {
+    let dir = project(
+        r#"
+[[target]]
+input = "abis/erc20.json"
+output = "token.rs"
+name = "Token"
+"#,
+    );
+    let config = dir.join("sipb.toml");
+    let elsewhere = std::env::temp_dir();
+    assert!(run_in(&elsewhere, &["--config", config.to_str().unwrap()])
+        .status
+        .success());
+    assert!(fs::read_to_string(dir.join("token.rs"))
+        .unwrap()
+        .contains("pub trait TokenApi"));
+}
+
+#[test]
+fn artifacts_are_read_through_their_abi_field() {
+    let abi = fs::read_to_string(format!("{}/abis/erc20.json", workspace_root())).unwrap();
+    let artifact = format!(r#"{{"abi": {}, "bytecode": {{"object": "0x"}}}}"#, abi);
+    let dir =
+        project("[[target]]\ninput = \"Token.json\"\noutput = \"erc20.rs\"\nname = \"erc20\"\n");
+    fs::write(dir.join("Token.json"), artifact).unwrap();
+    assert!(run_in(&dir, &[]).status.success());
+    assert_eq!(
+        fs::read_to_string(dir.join("erc20.rs")).unwrap(),
+        read_expected("erc20")
+    );
+}
+
+#[test]
+fn missing_or_invalid_config_is_an_error() {
+    let empty = unique_output_path().with_extension("empty");
+    fs::create_dir_all(&empty).unwrap();
+    assert!(!run_in(&empty, &[]).status.success());
+
+    let dir = project("[[target]]\ninput = \"abis/erc20.json\"\n");
+    assert!(!run_in(&dir, &[]).status.success());
+    // Generation flags without an input don't fall back to sipb.toml.
+    let dir = project("[[target]]\ninput = \"abis/erc20.json\"\noutput = \"erc20.rs\"\n");
+    assert!(!run_in(&dir, &["--delegate"]).status.success());
+    assert!(!dir.join("erc20.rs").exists());
+}
+
+/// The workspace `sipb.toml` records how the `stylus-interfaces` packs and the reference contract
+/// skeletons are generated.
+#[test]
+fn workspace_config_reproduces_the_packs() {
+    let root = workspace_root();
+    let packs = format!("{}/crates/stylus-interfaces/src/", root);
+    let contracts = format!("{}/crates/stylus-reference/contracts/src/", root);
+    let out = unique_output_path().with_extension("packs");
+    fs::create_dir_all(&out).unwrap();
+    let sipb = fs::read_to_string(format!("{}/sipb.toml", root))
+        .unwrap()
+        .replace("\"abis/", &format!("\"{}/abis/", root))
+        .replace(
+            "\"crates/stylus-reference/contracts/abis/",
+            &format!("\"{}/crates/stylus-reference/contracts/abis/", root),
+        )
+        .replace(
+            "\"crates/stylus-interfaces/src/",
+            &format!("\"{}/", out.display()),
+        )
+        .replace(
+            "\"crates/stylus-reference/contracts/src/",
+            &format!("\"{}/", out.display()),
+        );
+    let config = out.join("sipb.toml");
+    fs::write(&config, sipb).unwrap();
+    assert!(run_in(&out, &["--config", config.to_str().unwrap()])
+        .status
+        .success());
+    let generated = ["erc20", "erc721", "erc1155", "ierc165"]
+        .map(|name| (&packs, format!("{}.rs", name)))
+        .into_iter()
+        .chain(
+            ["erc20", "erc721", "erc1155"]
+                .map(|name| (&contracts, format!("{}_interface.rs", name))),
+        );
+    for (dir, file) in generated {
+        assert_eq!(
+            fs::read_to_string(out.join(&file)).unwrap(),
+            fs::read_to_string(format!("{}{}", dir, file)).unwrap(),
+            "{} is out of date; run `stylus-bindgen` in the workspace root",
+            file
+        );
+    }
+}
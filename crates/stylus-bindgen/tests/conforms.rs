@@ -0,0 +1,107 @@
+//! `stylus-bindgen conforms` end to end: exit status and report formats.
+
+mod common;
+
+use std::fs;
+use std::process::{Command, Output};
+
+use common::{abi_path, bindgen_binary, unique_output_path, workspace_root, STANDARDS};
+
+/// USDT's ERC20 ABI shape: `transfer`, `transferFrom` and `approve` return nothing, no
+/// `Approval` event.
+const USDT: &str = r#"[
+  {"constant":false,"inputs":[{"name":"_to","type":"address"},{"name":"_value","type":"uint256"}],"name":"transfer","outputs":[],"type":"function"},
+  {"constant":true,"inputs":[{"name":"who","type":"address"}],"name":"balanceOf","outputs":[{"name":"","type":"uint256"}],"type":"function"},
+  {"constant":false,"inputs":[{"name":"_spender","type":"address"},{"name":"_value","type":"uint256"}],"name":"approve","outputs":[],"type":"function"},
+  {"constant":false,"inputs":[{"name":"_from","type":"address"},{"name":"_to","type":"address"},{"name":"_value","type":"uint256"}],"name":"transferFrom","outputs":[],"type":"function"},
+  {"constant":true,"inputs":[{"name":"_owner","type":"address"},{"name":"_spender","type":"address"}],"name":"allowance","outputs":[{"name":"remaining","type":"uint256"}],"type":"function"},
+  {"constant":true,"inputs":[],"name":"totalSupply","outputs":[{"name":"","type":"uint256"}],"type":"function"},
+  {"anonymous":false,"inputs":[{"indexed":true,"name":"from","type":"address"},{"indexed":true,"name":"to","type":"address"},{"indexed":false,"name":"value","type":"uint256"}],"name":"Transfer","type":"event"}
+]"#;
+
+fn conforms(abi: &str, extra_args: &[&str]) -> Output {
+    Command::new(bindgen_binary())
+        .args(["conforms", abi])
+        .args(extra_args)
+        .current_dir(workspace_root())
+        .output()
+        .expect("Failed to execute stylus-bindgen")
+}
+
+fn usdt_path() -> String {
+    let path = unique_output_path().with_extension("json");
+    fs::write(&path, USDT).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+#[test]
+fn standards_conform() {
+    for name in STANDARDS {
+        let output = conforms(&abi_path(name), &["--to", name]);
+        assert!(output.status.success(), "{} must conform to itself", name);
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.ends_with(&format!("conforms to {}\n", name)));
+    }
+}
+
+#[test]
+fn usdt_fails_erc20_in_text() {
+    let output = conforms(&usdt_path(), &["--to", "erc20"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("does not conform to erc20 (4 issues):"));
+    assert!(stdout
+        .contains("  wrong returns    transfer(address,uint256): expected (bool), found ()\n"));
+    assert!(stdout.contains("  missing event    Approval(address,address,uint256)\n"));
+}
+
+#[test]
+fn usdt_fails_erc20_in_json() {
+    let output = conforms(&usdt_path(), &["--to", "erc20", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["standard"], "erc20");
+    assert_eq!(report["conforms"], false);
+    let issues = report["issues"].as_array().unwrap();
+    assert_eq!(issues.len(), 4);
+    assert!(issues.contains(&serde_json::json!({
+        "kind": "wrong_returns",
+        "signature": "transfer(address,uint256)",
+        "expected": "(bool)",
+        "found": "()",
+    })));
+}
+
+#[test]
+fn erc20_is_not_erc721() {
+    let output = conforms(&abi_path("erc20"), &["--to", "erc721", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let kinds: Vec<&str> = report["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|issue| issue["kind"].as_str().unwrap())
+        .collect();
+    assert!(kinds.contains(&"missing_function"));
+    assert!(kinds.contains(&"wrong_returns")); // approve returns bool in ERC20, nothing in ERC721
+    assert!(kinds.contains(&"wrong_indexed")); // Transfer's third parameter
+}
+
+#[test]
+fn token_without_transfer_from_fails_erc20() {
+    let abi: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(abi_path("erc20")).unwrap()).unwrap();
+    let abi: Vec<_> = abi
+        .into_iter()
+        .filter(|item| item["name"] != "transferFrom")
+        .collect();
+    let path = unique_output_path().with_extension("json");
+    fs::write(&path, serde_json::to_string(&abi).unwrap()).unwrap();
+
+    let output = conforms(path.to_str().unwrap(), &["--to", "erc20"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("does not conform to erc20 (1 issue"));
+    assert!(stdout.contains("missing function transferFrom(address,address,uint256)"));
+}
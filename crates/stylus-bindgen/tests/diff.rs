@@ -0,0 +1,75 @@
+//! `stylus-bindgen diff` end to end: exit status and report formats.
+
+mod common;
+
+use std::fs;
+use std::process::{Command, Output};
+
+use common::{abi_path, bindgen_binary, unique_output_path, workspace_root};
+
+/// `abis/erc20.json` after an upgrade: `transfer` lost its `bool`, `approve`, `transferFrom`,
+/// `allowance` and the events are gone, and `decimals` is new.
+const ERC20_V2: &str = r#"[
+  {"type":"function","name":"transfer","inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],"outputs":[],"stateMutability":"nonpayable"},
+  {"type":"function","name":"balanceOf","inputs":[{"name":"account","type":"address"}],"outputs":[{"name":"","type":"uint256"}],"stateMutability":"view"},
+  {"type":"function","name":"totalSupply","inputs":[],"outputs":[{"name":"","type":"uint256"}],"stateMutability":"view"},
+  {"type":"function","name":"decimals","inputs":[],"outputs":[{"name":"","type":"uint8"}],"stateMutability":"view"}
+]"#;
+
+fn diff(old: &str, new: &str, extra_args: &[&str]) -> Output {
+    Command::new(bindgen_binary())
+        .args(["diff", old, new])
+        .args(extra_args)
+        .current_dir(workspace_root())
+        .output()
+        .expect("Failed to execute stylus-bindgen")
+}
+
+fn v2_path() -> String {
+    let path = unique_output_path().with_extension("json");
+    fs::write(&path, ERC20_V2).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+#[test]
+fn unchanged_abi_is_not_breaking() {
+    let output = diff(&abi_path("erc721"), &abi_path("erc721"), &[]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.ends_with("\n\n0 breaking, 0 non-breaking change(s).\n"));
+}
+
+#[test]
+fn breaking_upgrade_in_markdown() {
+    let output = diff(&abi_path("erc20"), &v2_path(), &[]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("## Breaking\n"));
+    assert!(stdout.contains("| function | `0x095ea7b3` | `approve(address,uint256)` | removed |\n"));
+    assert!(stdout.contains(
+        "| function | `0xa9059cbb` | `transfer(address,uint256)` | outputs changed: `(bool)` → `()` |\n"
+    ));
+    assert!(stdout.contains("| function | `0x313ce567` | `decimals()` | added |\n"));
+    assert!(stdout.contains("- removed `approve__0x095ea7b3`\n"));
+    assert!(stdout.contains("- added `decimals__0x313ce567`\n"));
+}
+
+#[test]
+fn breaking_upgrade_in_json() {
+    let output = diff(&abi_path("erc20"), &v2_path(), &["--format", "json"]);
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["breaking"], true);
+    let changes = report["changes"].as_array().unwrap();
+    let renamed = changes
+        .iter()
+        .find(|change| {
+            change["change"] == "inputs_renamed"
+                && change["signature"] == "transfer(address,uint256)"
+        })
+        .unwrap();
+    assert_eq!(renamed["breaking"], false);
+    assert_eq!(renamed["old"], "(to, value)");
+    let removed = report["rust_names"]["removed"].as_array().unwrap();
+    assert!(removed.contains(&serde_json::json!("Calls::Approve__0x095ea7b3")));
+}
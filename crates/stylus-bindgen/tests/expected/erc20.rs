@@ -1,35 +1,370 @@
 // Generated by stylus-bindgen
 #![allow(clippy::all)]
-use stylus_sdk::{alloy_primitives::{Address, U256}, alloy_sol_types::SolType, call::RawCall, prelude::*};
+use stylus_sdk::{alloy_primitives::{Address, U256}, alloy_sol_types::{sol_data, SolType}};
+use stylus_interfaces::{CallError, mock::MockState, transport::CallKind, transport::Request, transport::StylusTransport, transport::Transport};
 
-pub struct Contract {
+/// Per-call overrides applied to every call made through a `Contract`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CallOpts {
+    /// Gas forwarded to the callee; all remaining gas when `None`.
+    pub gas: Option<u64>,
+    /// Wei sent with non-view calls. View and pure functions always use STATICCALL.
+    pub value: U256,
+    /// Maximum bytes of return data copied back, guarding against return bombs.
+    pub max_return: Option<usize>,
+}
+
+pub struct Contract<T = StylusTransport> {
     pub address: Address,
+    pub opts: CallOpts,
+    pub transport: T,
 }
 
 impl Contract {
-    pub fn new(address: Address) -> Self { Self { address } }
+    pub fn new(address: Address) -> Self { Self::with_transport(address, StylusTransport) }
+}
+
+impl<T: Transport> Contract<T> {
+    pub fn with_transport(address: Address, transport: T) -> Self { Self { address, opts: CallOpts::default(), transport } }
+
+    pub fn with(&self, opts: CallOpts) -> Self where T: Clone { Self { address: self.address, opts, transport: self.transport.clone() } }
+
+    fn send(&self, kind: CallKind, input: &[u8]) -> Result<Vec<u8>, CallError> {
+        self.transport.call(Request {
+            address: self.address,
+            calldata: input,
+            kind,
+            value: if kind == CallKind::Call { self.opts.value } else { U256::ZERO },
+            gas: self.opts.gas,
+            max_return: self.opts.max_return,
+        })
+    }
+
+    // Original: allowance(address,address)
+    pub fn allowance__0xdd62ed3e(&self, owner: Address, spender: Address) -> Result<U256, CallError> {
+        let input = encode_allowance__0xdd62ed3e(owner, spender);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_allowance__0xdd62ed3e_returns)
+    }
 
     // Original: approve(address,uint256)
-    pub fn approve__0x095ea7b3(&self, spender: Address, value: U256) -> Result<Vec<u8>, Vec<u8>> {
-        let input = hex::decode("095ea7b3").unwrap();
-        // note: real encoding requires SolType definitions (Milestone 2)
-        let result = unsafe { RawCall::new_static().call(self.address, &input)? };
-        Ok(result)
+    pub fn approve__0x095ea7b3(&self, spender: Address, value: U256) -> Result<bool, CallError> {
+        let input = encode_approve__0x095ea7b3(spender, value);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_approve__0x095ea7b3_returns)
     }
 
     // Original: balanceOf(address)
-    pub fn balance_of__0x70a08231(&self, owner: Address) -> Result<Vec<u8>, Vec<u8>> {
-        let input = hex::decode("70a08231").unwrap();
-        // note: real encoding requires SolType definitions (Milestone 2)
-        let result = unsafe { RawCall::new_static().call(self.address, &input)? };
-        Ok(result)
+    pub fn balance_of__0x70a08231(&self, owner: Address) -> Result<U256, CallError> {
+        let input = encode_balance_of__0x70a08231(owner);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_balance_of__0x70a08231_returns)
+    }
+
+    // Original: totalSupply()
+    pub fn total_supply__0x18160ddd(&self, ) -> Result<U256, CallError> {
+        let input = encode_total_supply__0x18160ddd();
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_total_supply__0x18160ddd_returns)
     }
 
     // Original: transfer(address,uint256)
-    pub fn transfer__0xa9059cbb(&self, to: Address, value: U256) -> Result<Vec<u8>, Vec<u8>> {
-        let input = hex::decode("a9059cbb").unwrap();
-        // note: real encoding requires SolType definitions (Milestone 2)
-        let result = unsafe { RawCall::new_static().call(self.address, &input)? };
-        Ok(result)
+    pub fn transfer__0xa9059cbb(&self, to: Address, value: U256) -> Result<bool, CallError> {
+        let input = encode_transfer__0xa9059cbb(to, value);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_transfer__0xa9059cbb_returns)
+    }
+
+    // Original: transferFrom(address,address,uint256)
+    pub fn transfer_from__0x23b872dd(&self, from: Address, to: Address, value: U256) -> Result<bool, CallError> {
+        let input = encode_transfer_from__0x23b872dd(from, to, value);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_transfer_from__0x23b872dd_returns)
+    }
+}
+
+/// Calls of the `Erc20` interface, so logic can be generic over `Contract` and `MockErc20`.
+pub trait Erc20Api {
+    // Original: allowance(address,address)
+    fn allowance__0xdd62ed3e(&self, owner: Address, spender: Address) -> Result<U256, CallError>;
+
+    // Original: approve(address,uint256)
+    fn approve__0x095ea7b3(&self, spender: Address, value: U256) -> Result<bool, CallError>;
+
+    // Original: balanceOf(address)
+    fn balance_of__0x70a08231(&self, owner: Address) -> Result<U256, CallError>;
+
+    // Original: totalSupply()
+    fn total_supply__0x18160ddd(&self, ) -> Result<U256, CallError>;
+
+    // Original: transfer(address,uint256)
+    fn transfer__0xa9059cbb(&self, to: Address, value: U256) -> Result<bool, CallError>;
+
+    // Original: transferFrom(address,address,uint256)
+    fn transfer_from__0x23b872dd(&self, from: Address, to: Address, value: U256) -> Result<bool, CallError>;
+}
+
+impl<T: Transport> Erc20Api for Contract<T> {
+    fn allowance__0xdd62ed3e(&self, owner: Address, spender: Address) -> Result<U256, CallError> { Contract::allowance__0xdd62ed3e(self, owner, spender) }
+
+    fn approve__0x095ea7b3(&self, spender: Address, value: U256) -> Result<bool, CallError> { Contract::approve__0x095ea7b3(self, spender, value) }
+
+    fn balance_of__0x70a08231(&self, owner: Address) -> Result<U256, CallError> { Contract::balance_of__0x70a08231(self, owner) }
+
+    fn total_supply__0x18160ddd(&self, ) -> Result<U256, CallError> { Contract::total_supply__0x18160ddd(self, ) }
+
+    fn transfer__0xa9059cbb(&self, to: Address, value: U256) -> Result<bool, CallError> { Contract::transfer__0xa9059cbb(self, to, value) }
+
+    fn transfer_from__0x23b872dd(&self, from: Address, to: Address, value: U256) -> Result<bool, CallError> { Contract::transfer_from__0x23b872dd(self, from, to, value) }
+}
+
+/// Function selectors, named like the methods that call them.
+#[allow(non_upper_case_globals)]
+pub mod selectors {
+    // Original: allowance(address,address)
+    pub const allowance__0xdd62ed3e: [u8; 4] = [0xdd, 0x62, 0xed, 0x3e];
+    // Original: approve(address,uint256)
+    pub const approve__0x095ea7b3: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+    // Original: balanceOf(address)
+    pub const balance_of__0x70a08231: [u8; 4] = [0x70, 0xa0, 0x82, 0x31];
+    // Original: totalSupply()
+    pub const total_supply__0x18160ddd: [u8; 4] = [0x18, 0x16, 0x0d, 0xdd];
+    // Original: transfer(address,uint256)
+    pub const transfer__0xa9059cbb: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+    // Original: transferFrom(address,address,uint256)
+    pub const transfer_from__0x23b872dd: [u8; 4] = [0x23, 0xb8, 0x72, 0xdd];
+}
+
+// Original: allowance(address,address)
+pub fn encode_allowance__0xdd62ed3e(owner: Address, spender: Address) -> [u8; 68] {
+    let mut input = [0u8; 68];
+    input[..4].copy_from_slice(&selectors::allowance__0xdd62ed3e);
+    input[4..36].copy_from_slice(<sol_data::Address as SolType>::tokenize(&owner).0.as_slice());
+    input[36..68].copy_from_slice(<sol_data::Address as SolType>::tokenize(&spender).0.as_slice());
+    input
+}
+
+// Original: allowance(address,address) returns (uint256)
+pub fn decode_allowance__0xdd62ed3e_returns(data: &[u8]) -> Result<U256, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Uint<256>,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+// Original: approve(address,uint256)
+pub fn encode_approve__0x095ea7b3(spender: Address, value: U256) -> [u8; 68] {
+    let mut input = [0u8; 68];
+    input[..4].copy_from_slice(&selectors::approve__0x095ea7b3);
+    input[4..36].copy_from_slice(<sol_data::Address as SolType>::tokenize(&spender).0.as_slice());
+    input[36..68].copy_from_slice(<sol_data::Uint<256> as SolType>::tokenize(&value).0.as_slice());
+    input
+}
+
+// Original: approve(address,uint256) returns (bool)
+pub fn decode_approve__0x095ea7b3_returns(data: &[u8]) -> Result<bool, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Bool,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+// Original: balanceOf(address)
+pub fn encode_balance_of__0x70a08231(owner: Address) -> [u8; 36] {
+    let mut input = [0u8; 36];
+    input[..4].copy_from_slice(&selectors::balance_of__0x70a08231);
+    input[4..36].copy_from_slice(<sol_data::Address as SolType>::tokenize(&owner).0.as_slice());
+    input
+}
+
+// Original: balanceOf(address) returns (uint256)
+pub fn decode_balance_of__0x70a08231_returns(data: &[u8]) -> Result<U256, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Uint<256>,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+// Original: totalSupply()
+pub fn encode_total_supply__0x18160ddd() -> [u8; 4] {
+    selectors::total_supply__0x18160ddd
+}
+
+// Original: totalSupply() returns (uint256)
+pub fn decode_total_supply__0x18160ddd_returns(data: &[u8]) -> Result<U256, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Uint<256>,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+// Original: transfer(address,uint256)
+pub fn encode_transfer__0xa9059cbb(to: Address, value: U256) -> [u8; 68] {
+    let mut input = [0u8; 68];
+    input[..4].copy_from_slice(&selectors::transfer__0xa9059cbb);
+    input[4..36].copy_from_slice(<sol_data::Address as SolType>::tokenize(&to).0.as_slice());
+    input[36..68].copy_from_slice(<sol_data::Uint<256> as SolType>::tokenize(&value).0.as_slice());
+    input
+}
+
+// Original: transfer(address,uint256) returns (bool)
+pub fn decode_transfer__0xa9059cbb_returns(data: &[u8]) -> Result<bool, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Bool,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+// Original: transferFrom(address,address,uint256)
+pub fn encode_transfer_from__0x23b872dd(from: Address, to: Address, value: U256) -> [u8; 100] {
+    let mut input = [0u8; 100];
+    input[..4].copy_from_slice(&selectors::transfer_from__0x23b872dd);
+    input[4..36].copy_from_slice(<sol_data::Address as SolType>::tokenize(&from).0.as_slice());
+    input[36..68].copy_from_slice(<sol_data::Address as SolType>::tokenize(&to).0.as_slice());
+    input[68..100].copy_from_slice(<sol_data::Uint<256> as SolType>::tokenize(&value).0.as_slice());
+    input
+}
+
+// Original: transferFrom(address,address,uint256) returns (bool)
+pub fn decode_transfer_from__0x23b872dd_returns(data: &[u8]) -> Result<bool, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Bool,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+#[allow(non_camel_case_types, non_snake_case)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Calls {
+    // Original: allowance(address,address)
+    Allowance__0xdd62ed3e { owner: Address, spender: Address },
+    // Original: approve(address,uint256)
+    Approve__0x095ea7b3 { spender: Address, value: U256 },
+    // Original: balanceOf(address)
+    BalanceOf__0x70a08231 { owner: Address },
+    // Original: totalSupply()
+    TotalSupply__0x18160ddd,
+    // Original: transfer(address,uint256)
+    Transfer__0xa9059cbb { to: Address, value: U256 },
+    // Original: transferFrom(address,address,uint256)
+    TransferFrom__0x23b872dd { from: Address, to: Address, value: U256 },
+}
+
+impl Calls {
+    pub fn abi_decode(data: &[u8]) -> Result<Self, stylus_sdk::alloy_sol_types::Error> {
+        let Some((selector, args)) = data.split_first_chunk::<4>() else {
+            return Err(stylus_sdk::alloy_sol_types::Error::Overrun);
+        };
+        match *selector {
+            selectors::allowance__0xdd62ed3e => {
+                let (owner, spender) = <(sol_data::Address, sol_data::Address)>::abi_decode_params(args, true)?;
+                Ok(Self::Allowance__0xdd62ed3e { owner, spender })
+            }
+            selectors::approve__0x095ea7b3 => {
+                let (spender, value) = <(sol_data::Address, sol_data::Uint<256>)>::abi_decode_params(args, true)?;
+                Ok(Self::Approve__0x095ea7b3 { spender, value })
+            }
+            selectors::balance_of__0x70a08231 => {
+                let (owner,) = <(sol_data::Address,)>::abi_decode_params(args, true)?;
+                Ok(Self::BalanceOf__0x70a08231 { owner })
+            }
+            selectors::total_supply__0x18160ddd => {
+                Ok(Self::TotalSupply__0x18160ddd)
+            }
+            selectors::transfer__0xa9059cbb => {
+                let (to, value) = <(sol_data::Address, sol_data::Uint<256>)>::abi_decode_params(args, true)?;
+                Ok(Self::Transfer__0xa9059cbb { to, value })
+            }
+            selectors::transfer_from__0x23b872dd => {
+                let (from, to, value) = <(sol_data::Address, sol_data::Address, sol_data::Uint<256>)>::abi_decode_params(args, true)?;
+                Ok(Self::TransferFrom__0x23b872dd { from, to, value })
+            }
+            _ => Err(stylus_sdk::alloy_sol_types::Error::unknown_selector("Calls", *selector)),
+        }
+    }
+
+    pub fn abi_encode(&self) -> Vec<u8> {
+        match self.clone() {
+            Self::Allowance__0xdd62ed3e { owner, spender } => encode_allowance__0xdd62ed3e(owner, spender).to_vec(),
+            Self::Approve__0x095ea7b3 { spender, value } => encode_approve__0x095ea7b3(spender, value).to_vec(),
+            Self::BalanceOf__0x70a08231 { owner } => encode_balance_of__0x70a08231(owner).to_vec(),
+            Self::TotalSupply__0x18160ddd => encode_total_supply__0x18160ddd().to_vec(),
+            Self::Transfer__0xa9059cbb { to, value } => encode_transfer__0xa9059cbb(to, value).to_vec(),
+            Self::TransferFrom__0x23b872dd { from, to, value } => encode_transfer_from__0x23b872dd(from, to, value).to_vec(),
+        }
+    }
+
+    pub fn selector(&self) -> [u8; 4] {
+        match self {
+            Self::Allowance__0xdd62ed3e { .. } => selectors::allowance__0xdd62ed3e,
+            Self::Approve__0x095ea7b3 { .. } => selectors::approve__0x095ea7b3,
+            Self::BalanceOf__0x70a08231 { .. } => selectors::balance_of__0x70a08231,
+            Self::TotalSupply__0x18160ddd => selectors::total_supply__0x18160ddd,
+            Self::Transfer__0xa9059cbb { .. } => selectors::transfer__0xa9059cbb,
+            Self::TransferFrom__0x23b872dd { .. } => selectors::transfer_from__0x23b872dd,
+        }
+    }
+}
+
+impl core::fmt::Display for Calls {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Allowance__0xdd62ed3e { owner, spender } => write!(f, "allowance(owner={}, spender={})", owner, spender),
+            Self::Approve__0x095ea7b3 { spender, value } => write!(f, "approve(spender={}, value={})", spender, value),
+            Self::BalanceOf__0x70a08231 { owner } => write!(f, "balanceOf(owner={})", owner),
+            Self::TotalSupply__0x18160ddd => write!(f, "totalSupply()"),
+            Self::Transfer__0xa9059cbb { to, value } => write!(f, "transfer(to={}, value={})", to, value),
+            Self::TransferFrom__0x23b872dd { from, to, value } => write!(f, "transferFrom(from={}, to={}, value={})", from, to, value),
+        }
+    }
+}
+
+/// In-memory `Erc20Api` for native unit tests: configurable responses and a log of calls.
+///
+/// Responses are raw return data, decoded exactly like a real `Contract` call.
+#[derive(Default)]
+pub struct MockErc20 {
+    pub state: MockState<Calls>,
+}
+
+impl MockErc20 {
+    pub fn new() -> Self { Self::default() }
+
+    /// Every call made so far, oldest first.
+    pub fn calls(&self) -> Vec<Calls> { self.state.calls() }
+
+    /// Makes calls to `selector` fail with `error` until replaced.
+    pub fn fail(&self, selector: [u8; 4], error: CallError) { self.state.respond(selector, Err(error)); }
+
+    /// Makes `allowance(address,address)` return `value` until replaced.
+    pub fn returns_allowance__0xdd62ed3e(&self, value: U256) { self.state.respond(selectors::allowance__0xdd62ed3e, Ok(<(sol_data::Uint<256>,)>::abi_encode_params(&(value,)))); }
+
+    /// Makes `approve(address,uint256)` return `value` until replaced.
+    pub fn returns_approve__0x095ea7b3(&self, value: bool) { self.state.respond(selectors::approve__0x095ea7b3, Ok(<(sol_data::Bool,)>::abi_encode_params(&(value,)))); }
+
+    /// Makes `balanceOf(address)` return `value` until replaced.
+    pub fn returns_balance_of__0x70a08231(&self, value: U256) { self.state.respond(selectors::balance_of__0x70a08231, Ok(<(sol_data::Uint<256>,)>::abi_encode_params(&(value,)))); }
+
+    /// Makes `totalSupply()` return `value` until replaced.
+    pub fn returns_total_supply__0x18160ddd(&self, value: U256) { self.state.respond(selectors::total_supply__0x18160ddd, Ok(<(sol_data::Uint<256>,)>::abi_encode_params(&(value,)))); }
+
+    /// Makes `transfer(address,uint256)` return `value` until replaced.
+    pub fn returns_transfer__0xa9059cbb(&self, value: bool) { self.state.respond(selectors::transfer__0xa9059cbb, Ok(<(sol_data::Bool,)>::abi_encode_params(&(value,)))); }
+
+    /// Makes `transferFrom(address,address,uint256)` return `value` until replaced.
+    pub fn returns_transfer_from__0x23b872dd(&self, value: bool) { self.state.respond(selectors::transfer_from__0x23b872dd, Ok(<(sol_data::Bool,)>::abi_encode_params(&(value,)))); }
+}
+
+impl Erc20Api for MockErc20 {
+    fn allowance__0xdd62ed3e(&self, owner: Address, spender: Address) -> Result<U256, CallError> {
+        let output = self.state.record(selectors::allowance__0xdd62ed3e, Calls::Allowance__0xdd62ed3e { owner, spender })?;
+        CallError::decode(output, decode_allowance__0xdd62ed3e_returns)
+    }
+
+    fn approve__0x095ea7b3(&self, spender: Address, value: U256) -> Result<bool, CallError> {
+        let output = self.state.record(selectors::approve__0x095ea7b3, Calls::Approve__0x095ea7b3 { spender, value })?;
+        CallError::decode(output, decode_approve__0x095ea7b3_returns)
+    }
+
+    fn balance_of__0x70a08231(&self, owner: Address) -> Result<U256, CallError> {
+        let output = self.state.record(selectors::balance_of__0x70a08231, Calls::BalanceOf__0x70a08231 { owner })?;
+        CallError::decode(output, decode_balance_of__0x70a08231_returns)
+    }
+
+    fn total_supply__0x18160ddd(&self, ) -> Result<U256, CallError> {
+        let output = self.state.record(selectors::total_supply__0x18160ddd, Calls::TotalSupply__0x18160ddd)?;
+        CallError::decode(output, decode_total_supply__0x18160ddd_returns)
+    }
+
+    fn transfer__0xa9059cbb(&self, to: Address, value: U256) -> Result<bool, CallError> {
+        let output = self.state.record(selectors::transfer__0xa9059cbb, Calls::Transfer__0xa9059cbb { to, value })?;
+        CallError::decode(output, decode_transfer__0xa9059cbb_returns)
+    }
+
+    fn transfer_from__0x23b872dd(&self, from: Address, to: Address, value: U256) -> Result<bool, CallError> {
+        let output = self.state.record(selectors::transfer_from__0x23b872dd, Calls::TransferFrom__0x23b872dd { from, to, value })?;
+        CallError::decode(output, decode_transfer_from__0x23b872dd_returns)
     }
 }
@@ -0,0 +1,97 @@
+// Generated by stylus-bindgen
+#![allow(clippy::all)]
+use stylus_sdk::{abi::Bytes, alloy_primitives::{Address, U256}, prelude::*};
+
+/// Functions of the `Erc721` interface, as a Stylus contract implements them.
+///
+/// Implement this for `Erc721`; its `#[public]` block routes every selector of the ABI here.
+pub trait Erc721Interface {
+    // Original: approve(address,uint256)
+    fn approve__0x095ea7b3(&mut self, to: Address, tokenId: U256) -> Result<(), Vec<u8>>;
+
+    // Original: balanceOf(address)
+    fn balance_of__0x70a08231(&self, owner: Address) -> Result<U256, Vec<u8>>;
+
+    // Original: getApproved(uint256)
+    fn get_approved__0x081812fc(&self, tokenId: U256) -> Result<Address, Vec<u8>>;
+
+    // Original: isApprovedForAll(address,address)
+    fn is_approved_for_all__0xe985e9c5(&self, owner: Address, operator: Address) -> Result<bool, Vec<u8>>;
+
+    // Original: ownerOf(uint256)
+    fn owner_of__0x6352211e(&self, tokenId: U256) -> Result<Address, Vec<u8>>;
+
+    // Original: safeTransferFrom(address,address,uint256)
+    fn safe_transfer_from__0x42842e0e(&mut self, from: Address, to: Address, tokenId: U256) -> Result<(), Vec<u8>>;
+
+    // Original: safeTransferFrom(address,address,uint256,bytes)
+    fn safe_transfer_from__0xb88d4fde(&mut self, from: Address, to: Address, tokenId: U256, data: Bytes) -> Result<(), Vec<u8>>;
+
+    // Original: setApprovalForAll(address,bool)
+    fn set_approval_for_all__0xa22cb465(&mut self, operator: Address, approved: bool) -> Result<(), Vec<u8>>;
+
+    // Original: transferFrom(address,address,uint256)
+    fn transfer_from__0x23b872dd(&mut self, from: Address, to: Address, tokenId: U256) -> Result<(), Vec<u8>>;
+}
+
+/// Contract storage; add fields as needed.
+#[storage]
+#[entrypoint]
+pub struct Erc721 {}
+
+#[public]
+impl Erc721 {
+    // Original: approve(address,uint256)
+    #[selector(name = "approve")]
+    pub fn approve__0x095ea7b3(&mut self, to: Address, tokenId: U256) -> Result<(), Vec<u8>> {
+        <Self as Erc721Interface>::approve__0x095ea7b3(self, to, tokenId)
+    }
+
+    // Original: balanceOf(address)
+    #[selector(name = "balanceOf")]
+    pub fn balance_of__0x70a08231(&self, owner: Address) -> Result<U256, Vec<u8>> {
+        <Self as Erc721Interface>::balance_of__0x70a08231(self, owner)
+    }
+
+    // Original: getApproved(uint256)
+    #[selector(name = "getApproved")]
+    pub fn get_approved__0x081812fc(&self, tokenId: U256) -> Result<Address, Vec<u8>> {
+        <Self as Erc721Interface>::get_approved__0x081812fc(self, tokenId)
+    }
+
+    // Original: isApprovedForAll(address,address)
+    #[selector(name = "isApprovedForAll")]
+    pub fn is_approved_for_all__0xe985e9c5(&self, owner: Address, operator: Address) -> Result<bool, Vec<u8>> {
+        <Self as Erc721Interface>::is_approved_for_all__0xe985e9c5(self, owner, operator)
+    }
+
+    // Original: ownerOf(uint256)
+    #[selector(name = "ownerOf")]
+    pub fn owner_of__0x6352211e(&self, tokenId: U256) -> Result<Address, Vec<u8>> {
+        <Self as Erc721Interface>::owner_of__0x6352211e(self, tokenId)
+    }
+
+    // Original: safeTransferFrom(address,address,uint256)
+    #[selector(name = "safeTransferFrom")]
+    pub fn safe_transfer_from__0x42842e0e(&mut self, from: Address, to: Address, tokenId: U256) -> Result<(), Vec<u8>> {
+        <Self as Erc721Interface>::safe_transfer_from__0x42842e0e(self, from, to, tokenId)
+    }
+
+    // Original: safeTransferFrom(address,address,uint256,bytes)
+    #[selector(name = "safeTransferFrom")]
+    pub fn safe_transfer_from__0xb88d4fde(&mut self, from: Address, to: Address, tokenId: U256, data: Bytes) -> Result<(), Vec<u8>> {
+        <Self as Erc721Interface>::safe_transfer_from__0xb88d4fde(self, from, to, tokenId, data)
+    }
+
+    // Original: setApprovalForAll(address,bool)
+    #[selector(name = "setApprovalForAll")]
+    pub fn set_approval_for_all__0xa22cb465(&mut self, operator: Address, approved: bool) -> Result<(), Vec<u8>> {
+        <Self as Erc721Interface>::set_approval_for_all__0xa22cb465(self, operator, approved)
+    }
+
+    // Original: transferFrom(address,address,uint256)
+    #[selector(name = "transferFrom")]
+    pub fn transfer_from__0x23b872dd(&mut self, from: Address, to: Address, tokenId: U256) -> Result<(), Vec<u8>> {
+        <Self as Erc721Interface>::transfer_from__0x23b872dd(self, from, to, tokenId)
+    }
+}
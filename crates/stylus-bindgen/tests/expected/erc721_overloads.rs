@@ -0,0 +1,492 @@
+// Generated by stylus-bindgen
+#![allow(clippy::all)]
+use stylus_sdk::{alloy_primitives::{Address, U256}, alloy_sol_types::{sol_data, SolType}};
+use stylus_interfaces::{CallError, mock::MockState, transport::CallKind, transport::Request, transport::StylusTransport, transport::Transport};
+
+/// Per-call overrides applied to every call made through a `Contract`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CallOpts {
+    /// Gas forwarded to the callee; all remaining gas when `None`.
+    pub gas: Option<u64>,
+    /// Wei sent with non-view calls. View and pure functions always use STATICCALL.
+    pub value: U256,
+    /// Maximum bytes of return data copied back, guarding against return bombs.
+    pub max_return: Option<usize>,
+}
+
+pub struct Contract<T = StylusTransport> {
+    pub address: Address,
+    pub opts: CallOpts,
+    pub transport: T,
+}
+
+impl Contract {
+    pub fn new(address: Address) -> Self { Self::with_transport(address, StylusTransport) }
+}
+
+impl<T: Transport> Contract<T> {
+    pub fn with_transport(address: Address, transport: T) -> Self { Self { address, opts: CallOpts::default(), transport } }
+
+    pub fn with(&self, opts: CallOpts) -> Self where T: Clone { Self { address: self.address, opts, transport: self.transport.clone() } }
+
+    fn send(&self, kind: CallKind, input: &[u8]) -> Result<Vec<u8>, CallError> {
+        self.transport.call(Request {
+            address: self.address,
+            calldata: input,
+            kind,
+            value: if kind == CallKind::Call { self.opts.value } else { U256::ZERO },
+            gas: self.opts.gas,
+            max_return: self.opts.max_return,
+        })
+    }
+
+    // Original: approve(address,uint256)
+    pub fn approve(&self, to: Address, tokenId: U256) -> Result<(), CallError> {
+        let input = encode_approve(to, tokenId);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_approve_returns)
+    }
+
+    // Original: balanceOf(address)
+    pub fn balance_of(&self, owner: Address) -> Result<U256, CallError> {
+        let input = encode_balance_of(owner);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_balance_of_returns)
+    }
+
+    // Original: getApproved(uint256)
+    pub fn get_approved(&self, tokenId: U256) -> Result<Address, CallError> {
+        let input = encode_get_approved(tokenId);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_get_approved_returns)
+    }
+
+    // Original: isApprovedForAll(address,address)
+    pub fn is_approved_for_all(&self, owner: Address, operator: Address) -> Result<bool, CallError> {
+        let input = encode_is_approved_for_all(owner, operator);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_is_approved_for_all_returns)
+    }
+
+    // Original: ownerOf(uint256)
+    pub fn owner_of(&self, tokenId: U256) -> Result<Address, CallError> {
+        let input = encode_owner_of(tokenId);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_owner_of_returns)
+    }
+
+    // Original: safeTransferFrom(address,address,uint256)
+    pub fn safe_transfer_from__0x42842e0e(&self, from: Address, to: Address, tokenId: U256) -> Result<(), CallError> {
+        let input = encode_safe_transfer_from__0x42842e0e(from, to, tokenId);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_safe_transfer_from__0x42842e0e_returns)
+    }
+
+    // Original: safeTransferFrom(address,address,uint256,bytes)
+    pub fn safe_transfer_from__0xb88d4fde(&self, from: Address, to: Address, tokenId: U256, data: Vec<u8>) -> Result<(), CallError> {
+        let input = encode_safe_transfer_from__0xb88d4fde(from, to, tokenId, data);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_safe_transfer_from__0xb88d4fde_returns)
+    }
+
+    // Original: setApprovalForAll(address,bool)
+    pub fn set_approval_for_all(&self, operator: Address, approved: bool) -> Result<(), CallError> {
+        let input = encode_set_approval_for_all(operator, approved);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_set_approval_for_all_returns)
+    }
+
+    // Original: transferFrom(address,address,uint256)
+    pub fn transfer_from(&self, from: Address, to: Address, tokenId: U256) -> Result<(), CallError> {
+        let input = encode_transfer_from(from, to, tokenId);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_transfer_from_returns)
+    }
+}
+
+/// Calls of the `Erc721` interface, so logic can be generic over `Contract` and `MockErc721`.
+pub trait Erc721Api {
+    // Original: approve(address,uint256)
+    fn approve(&self, to: Address, tokenId: U256) -> Result<(), CallError>;
+
+    // Original: balanceOf(address)
+    fn balance_of(&self, owner: Address) -> Result<U256, CallError>;
+
+    // Original: getApproved(uint256)
+    fn get_approved(&self, tokenId: U256) -> Result<Address, CallError>;
+
+    // Original: isApprovedForAll(address,address)
+    fn is_approved_for_all(&self, owner: Address, operator: Address) -> Result<bool, CallError>;
+
+    // Original: ownerOf(uint256)
+    fn owner_of(&self, tokenId: U256) -> Result<Address, CallError>;
+
+    // Original: safeTransferFrom(address,address,uint256)
+    fn safe_transfer_from__0x42842e0e(&self, from: Address, to: Address, tokenId: U256) -> Result<(), CallError>;
+
+    // Original: safeTransferFrom(address,address,uint256,bytes)
+    fn safe_transfer_from__0xb88d4fde(&self, from: Address, to: Address, tokenId: U256, data: Vec<u8>) -> Result<(), CallError>;
+
+    // Original: setApprovalForAll(address,bool)
+    fn set_approval_for_all(&self, operator: Address, approved: bool) -> Result<(), CallError>;
+
+    // Original: transferFrom(address,address,uint256)
+    fn transfer_from(&self, from: Address, to: Address, tokenId: U256) -> Result<(), CallError>;
+}
+
+impl<T: Transport> Erc721Api for Contract<T> {
+    fn approve(&self, to: Address, tokenId: U256) -> Result<(), CallError> { Contract::approve(self, to, tokenId) }
+
+    fn balance_of(&self, owner: Address) -> Result<U256, CallError> { Contract::balance_of(self, owner) }
+
+    fn get_approved(&self, tokenId: U256) -> Result<Address, CallError> { Contract::get_approved(self, tokenId) }
+
+    fn is_approved_for_all(&self, owner: Address, operator: Address) -> Result<bool, CallError> { Contract::is_approved_for_all(self, owner, operator) }
+
+    fn owner_of(&self, tokenId: U256) -> Result<Address, CallError> { Contract::owner_of(self, tokenId) }
+
+    fn safe_transfer_from__0x42842e0e(&self, from: Address, to: Address, tokenId: U256) -> Result<(), CallError> { Contract::safe_transfer_from__0x42842e0e(self, from, to, tokenId) }
+
+    fn safe_transfer_from__0xb88d4fde(&self, from: Address, to: Address, tokenId: U256, data: Vec<u8>) -> Result<(), CallError> { Contract::safe_transfer_from__0xb88d4fde(self, from, to, tokenId, data) }
+
+    fn set_approval_for_all(&self, operator: Address, approved: bool) -> Result<(), CallError> { Contract::set_approval_for_all(self, operator, approved) }
+
+    fn transfer_from(&self, from: Address, to: Address, tokenId: U256) -> Result<(), CallError> { Contract::transfer_from(self, from, to, tokenId) }
+}
+
+/// Function selectors, named like the methods that call them.
+#[allow(non_upper_case_globals)]
+pub mod selectors {
+    // Original: approve(address,uint256)
+    pub const approve: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+    // Original: balanceOf(address)
+    pub const balance_of: [u8; 4] = [0x70, 0xa0, 0x82, 0x31];
+    // Original: getApproved(uint256)
+    pub const get_approved: [u8; 4] = [0x08, 0x18, 0x12, 0xfc];
+    // Original: isApprovedForAll(address,address)
+    pub const is_approved_for_all: [u8; 4] = [0xe9, 0x85, 0xe9, 0xc5];
+    // Original: ownerOf(uint256)
+    pub const owner_of: [u8; 4] = [0x63, 0x52, 0x21, 0x1e];
+    // Original: safeTransferFrom(address,address,uint256)
+    pub const safe_transfer_from__0x42842e0e: [u8; 4] = [0x42, 0x84, 0x2e, 0x0e];
+    // Original: safeTransferFrom(address,address,uint256,bytes)
+    pub const safe_transfer_from__0xb88d4fde: [u8; 4] = [0xb8, 0x8d, 0x4f, 0xde];
+    // Original: setApprovalForAll(address,bool)
+    pub const set_approval_for_all: [u8; 4] = [0xa2, 0x2c, 0xb4, 0x65];
+    // Original: transferFrom(address,address,uint256)
+    pub const transfer_from: [u8; 4] = [0x23, 0xb8, 0x72, 0xdd];
+}
+
+// Original: approve(address,uint256)
+pub fn encode_approve(to: Address, tokenId: U256) -> [u8; 68] {
+    let mut input = [0u8; 68];
+    input[..4].copy_from_slice(&selectors::approve);
+    input[4..36].copy_from_slice(<sol_data::Address as SolType>::tokenize(&to).0.as_slice());
+    input[36..68].copy_from_slice(<sol_data::Uint<256> as SolType>::tokenize(&tokenId).0.as_slice());
+    input
+}
+
+// Original: approve(address,uint256) returns ()
+pub fn decode_approve_returns(data: &[u8]) -> Result<(), stylus_sdk::alloy_sol_types::Error> {
+    <()>::abi_decode_params(data, true)
+}
+
+// Original: balanceOf(address)
+pub fn encode_balance_of(owner: Address) -> [u8; 36] {
+    let mut input = [0u8; 36];
+    input[..4].copy_from_slice(&selectors::balance_of);
+    input[4..36].copy_from_slice(<sol_data::Address as SolType>::tokenize(&owner).0.as_slice());
+    input
+}
+
+// Original: balanceOf(address) returns (uint256)
+pub fn decode_balance_of_returns(data: &[u8]) -> Result<U256, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Uint<256>,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+// Original: getApproved(uint256)
+pub fn encode_get_approved(tokenId: U256) -> [u8; 36] {
+    let mut input = [0u8; 36];
+    input[..4].copy_from_slice(&selectors::get_approved);
+    input[4..36].copy_from_slice(<sol_data::Uint<256> as SolType>::tokenize(&tokenId).0.as_slice());
+    input
+}
+
+// Original: getApproved(uint256) returns (address)
+pub fn decode_get_approved_returns(data: &[u8]) -> Result<Address, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Address,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+// Original: isApprovedForAll(address,address)
+pub fn encode_is_approved_for_all(owner: Address, operator: Address) -> [u8; 68] {
+    let mut input = [0u8; 68];
+    input[..4].copy_from_slice(&selectors::is_approved_for_all);
+    input[4..36].copy_from_slice(<sol_data::Address as SolType>::tokenize(&owner).0.as_slice());
+    input[36..68].copy_from_slice(<sol_data::Address as SolType>::tokenize(&operator).0.as_slice());
+    input
+}
+
+// Original: isApprovedForAll(address,address) returns (bool)
+pub fn decode_is_approved_for_all_returns(data: &[u8]) -> Result<bool, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Bool,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+// Original: ownerOf(uint256)
+pub fn encode_owner_of(tokenId: U256) -> [u8; 36] {
+    let mut input = [0u8; 36];
+    input[..4].copy_from_slice(&selectors::owner_of);
+    input[4..36].copy_from_slice(<sol_data::Uint<256> as SolType>::tokenize(&tokenId).0.as_slice());
+    input
+}
+
+// Original: ownerOf(uint256) returns (address)
+pub fn decode_owner_of_returns(data: &[u8]) -> Result<Address, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Address,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+// Original: safeTransferFrom(address,address,uint256)
+pub fn encode_safe_transfer_from__0x42842e0e(from: Address, to: Address, tokenId: U256) -> [u8; 100] {
+    let mut input = [0u8; 100];
+    input[..4].copy_from_slice(&selectors::safe_transfer_from__0x42842e0e);
+    input[4..36].copy_from_slice(<sol_data::Address as SolType>::tokenize(&from).0.as_slice());
+    input[36..68].copy_from_slice(<sol_data::Address as SolType>::tokenize(&to).0.as_slice());
+    input[68..100].copy_from_slice(<sol_data::Uint<256> as SolType>::tokenize(&tokenId).0.as_slice());
+    input
+}
+
+// Original: safeTransferFrom(address,address,uint256) returns ()
+pub fn decode_safe_transfer_from__0x42842e0e_returns(data: &[u8]) -> Result<(), stylus_sdk::alloy_sol_types::Error> {
+    <()>::abi_decode_params(data, true)
+}
+
+// Original: safeTransferFrom(address,address,uint256,bytes)
+pub fn encode_safe_transfer_from__0xb88d4fde(from: Address, to: Address, tokenId: U256, data: Vec<u8>) -> Vec<u8> {
+    let mut input = selectors::safe_transfer_from__0xb88d4fde.to_vec();
+    input.extend(<(sol_data::Address, sol_data::Address, sol_data::Uint<256>, sol_data::Bytes)>::abi_encode_params(&(from, to, tokenId, data)));
+    input
+}
+
+// Original: safeTransferFrom(address,address,uint256,bytes) returns ()
+pub fn decode_safe_transfer_from__0xb88d4fde_returns(data: &[u8]) -> Result<(), stylus_sdk::alloy_sol_types::Error> {
+    <()>::abi_decode_params(data, true)
+}
+
+// Original: setApprovalForAll(address,bool)
+pub fn encode_set_approval_for_all(operator: Address, approved: bool) -> [u8; 68] {
+    let mut input = [0u8; 68];
+    input[..4].copy_from_slice(&selectors::set_approval_for_all);
+    input[4..36].copy_from_slice(<sol_data::Address as SolType>::tokenize(&operator).0.as_slice());
+    input[36..68].copy_from_slice(<sol_data::Bool as SolType>::tokenize(&approved).0.as_slice());
+    input
+}
+
+// Original: setApprovalForAll(address,bool) returns ()
+pub fn decode_set_approval_for_all_returns(data: &[u8]) -> Result<(), stylus_sdk::alloy_sol_types::Error> {
+    <()>::abi_decode_params(data, true)
+}
+
+// Original: transferFrom(address,address,uint256)
+pub fn encode_transfer_from(from: Address, to: Address, tokenId: U256) -> [u8; 100] {
+    let mut input = [0u8; 100];
+    input[..4].copy_from_slice(&selectors::transfer_from);
+    input[4..36].copy_from_slice(<sol_data::Address as SolType>::tokenize(&from).0.as_slice());
+    input[36..68].copy_from_slice(<sol_data::Address as SolType>::tokenize(&to).0.as_slice());
+    input[68..100].copy_from_slice(<sol_data::Uint<256> as SolType>::tokenize(&tokenId).0.as_slice());
+    input
+}
+
+// Original: transferFrom(address,address,uint256) returns ()
+pub fn decode_transfer_from_returns(data: &[u8]) -> Result<(), stylus_sdk::alloy_sol_types::Error> {
+    <()>::abi_decode_params(data, true)
+}
+
+#[allow(non_camel_case_types, non_snake_case)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Calls {
+    // Original: approve(address,uint256)
+    Approve { to: Address, tokenId: U256 },
+    // Original: balanceOf(address)
+    BalanceOf { owner: Address },
+    // Original: getApproved(uint256)
+    GetApproved { tokenId: U256 },
+    // Original: isApprovedForAll(address,address)
+    IsApprovedForAll { owner: Address, operator: Address },
+    // Original: ownerOf(uint256)
+    OwnerOf { tokenId: U256 },
+    // Original: safeTransferFrom(address,address,uint256)
+    SafeTransferFrom__0x42842e0e { from: Address, to: Address, tokenId: U256 },
+    // Original: safeTransferFrom(address,address,uint256,bytes)
+    SafeTransferFrom__0xb88d4fde { from: Address, to: Address, tokenId: U256, data: Vec<u8> },
+    // Original: setApprovalForAll(address,bool)
+    SetApprovalForAll { operator: Address, approved: bool },
+    // Original: transferFrom(address,address,uint256)
+    TransferFrom { from: Address, to: Address, tokenId: U256 },
+}
+
+impl Calls {
+    pub fn abi_decode(data: &[u8]) -> Result<Self, stylus_sdk::alloy_sol_types::Error> {
+        let Some((selector, args)) = data.split_first_chunk::<4>() else {
+            return Err(stylus_sdk::alloy_sol_types::Error::Overrun);
+        };
+        match *selector {
+            selectors::approve => {
+                let (to, tokenId) = <(sol_data::Address, sol_data::Uint<256>)>::abi_decode_params(args, true)?;
+                Ok(Self::Approve { to, tokenId })
+            }
+            selectors::balance_of => {
+                let (owner,) = <(sol_data::Address,)>::abi_decode_params(args, true)?;
+                Ok(Self::BalanceOf { owner })
+            }
+            selectors::get_approved => {
+                let (tokenId,) = <(sol_data::Uint<256>,)>::abi_decode_params(args, true)?;
+                Ok(Self::GetApproved { tokenId })
+            }
+            selectors::is_approved_for_all => {
+                let (owner, operator) = <(sol_data::Address, sol_data::Address)>::abi_decode_params(args, true)?;
+                Ok(Self::IsApprovedForAll { owner, operator })
+            }
+            selectors::owner_of => {
+                let (tokenId,) = <(sol_data::Uint<256>,)>::abi_decode_params(args, true)?;
+                Ok(Self::OwnerOf { tokenId })
+            }
+            selectors::safe_transfer_from__0x42842e0e => {
+                let (from, to, tokenId) = <(sol_data::Address, sol_data::Address, sol_data::Uint<256>)>::abi_decode_params(args, true)?;
+                Ok(Self::SafeTransferFrom__0x42842e0e { from, to, tokenId })
+            }
+            selectors::safe_transfer_from__0xb88d4fde => {
+                let (from, to, tokenId, data) = <(sol_data::Address, sol_data::Address, sol_data::Uint<256>, sol_data::Bytes)>::abi_decode_params(args, true)?;
+                Ok(Self::SafeTransferFrom__0xb88d4fde { from, to, tokenId, data: data.into() })
+            }
+            selectors::set_approval_for_all => {
+                let (operator, approved) = <(sol_data::Address, sol_data::Bool)>::abi_decode_params(args, true)?;
+                Ok(Self::SetApprovalForAll { operator, approved })
+            }
+            selectors::transfer_from => {
+                let (from, to, tokenId) = <(sol_data::Address, sol_data::Address, sol_data::Uint<256>)>::abi_decode_params(args, true)?;
+                Ok(Self::TransferFrom { from, to, tokenId })
+            }
+            _ => Err(stylus_sdk::alloy_sol_types::Error::unknown_selector("Calls", *selector)),
+        }
+    }
+
+    pub fn abi_encode(&self) -> Vec<u8> {
+        match self.clone() {
+            Self::Approve { to, tokenId } => encode_approve(to, tokenId).to_vec(),
+            Self::BalanceOf { owner } => encode_balance_of(owner).to_vec(),
+            Self::GetApproved { tokenId } => encode_get_approved(tokenId).to_vec(),
+            Self::IsApprovedForAll { owner, operator } => encode_is_approved_for_all(owner, operator).to_vec(),
+            Self::OwnerOf { tokenId } => encode_owner_of(tokenId).to_vec(),
+            Self::SafeTransferFrom__0x42842e0e { from, to, tokenId } => encode_safe_transfer_from__0x42842e0e(from, to, tokenId).to_vec(),
+            Self::SafeTransferFrom__0xb88d4fde { from, to, tokenId, data } => encode_safe_transfer_from__0xb88d4fde(from, to, tokenId, data),
+            Self::SetApprovalForAll { operator, approved } => encode_set_approval_for_all(operator, approved).to_vec(),
+            Self::TransferFrom { from, to, tokenId } => encode_transfer_from(from, to, tokenId).to_vec(),
+        }
+    }
+
+    pub fn selector(&self) -> [u8; 4] {
+        match self {
+            Self::Approve { .. } => selectors::approve,
+            Self::BalanceOf { .. } => selectors::balance_of,
+            Self::GetApproved { .. } => selectors::get_approved,
+            Self::IsApprovedForAll { .. } => selectors::is_approved_for_all,
+            Self::OwnerOf { .. } => selectors::owner_of,
+            Self::SafeTransferFrom__0x42842e0e { .. } => selectors::safe_transfer_from__0x42842e0e,
+            Self::SafeTransferFrom__0xb88d4fde { .. } => selectors::safe_transfer_from__0xb88d4fde,
+            Self::SetApprovalForAll { .. } => selectors::set_approval_for_all,
+            Self::TransferFrom { .. } => selectors::transfer_from,
+        }
+    }
+}
+
+impl core::fmt::Display for Calls {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Approve { to, tokenId } => write!(f, "approve(to={}, tokenId={})", to, tokenId),
+            Self::BalanceOf { owner } => write!(f, "balanceOf(owner={})", owner),
+            Self::GetApproved { tokenId } => write!(f, "getApproved(tokenId={})", tokenId),
+            Self::IsApprovedForAll { owner, operator } => write!(f, "isApprovedForAll(owner={}, operator={})", owner, operator),
+            Self::OwnerOf { tokenId } => write!(f, "ownerOf(tokenId={})", tokenId),
+            Self::SafeTransferFrom__0x42842e0e { from, to, tokenId } => write!(f, "safeTransferFrom(from={}, to={}, tokenId={})", from, to, tokenId),
+            Self::SafeTransferFrom__0xb88d4fde { from, to, tokenId, data } => write!(f, "safeTransferFrom(from={}, to={}, tokenId={}, data=0x{})", from, to, tokenId, stylus_sdk::alloy_primitives::hex::encode(data)),
+            Self::SetApprovalForAll { operator, approved } => write!(f, "setApprovalForAll(operator={}, approved={})", operator, approved),
+            Self::TransferFrom { from, to, tokenId } => write!(f, "transferFrom(from={}, to={}, tokenId={})", from, to, tokenId),
+        }
+    }
+}
+
+/// In-memory `Erc721Api` for native unit tests: configurable responses and a log of calls.
+///
+/// Responses are raw return data, decoded exactly like a real `Contract` call.
+#[derive(Default)]
+pub struct MockErc721 {
+    pub state: MockState<Calls>,
+}
+
+impl MockErc721 {
+    pub fn new() -> Self { Self::default() }
+
+    /// Every call made so far, oldest first.
+    pub fn calls(&self) -> Vec<Calls> { self.state.calls() }
+
+    /// Makes calls to `selector` fail with `error` until replaced.
+    pub fn fail(&self, selector: [u8; 4], error: CallError) { self.state.respond(selector, Err(error)); }
+
+    /// Makes `balanceOf(address)` return `value` until replaced.
+    pub fn returns_balance_of(&self, value: U256) { self.state.respond(selectors::balance_of, Ok(<(sol_data::Uint<256>,)>::abi_encode_params(&(value,)))); }
+
+    /// Makes `getApproved(uint256)` return `value` until replaced.
+    pub fn returns_get_approved(&self, value: Address) { self.state.respond(selectors::get_approved, Ok(<(sol_data::Address,)>::abi_encode_params(&(value,)))); }
+
+    /// Makes `isApprovedForAll(address,address)` return `value` until replaced.
+    pub fn returns_is_approved_for_all(&self, value: bool) { self.state.respond(selectors::is_approved_for_all, Ok(<(sol_data::Bool,)>::abi_encode_params(&(value,)))); }
+
+    /// Makes `ownerOf(uint256)` return `value` until replaced.
+    pub fn returns_owner_of(&self, value: Address) { self.state.respond(selectors::owner_of, Ok(<(sol_data::Address,)>::abi_encode_params(&(value,)))); }
+}
+
+impl Erc721Api for MockErc721 {
+    fn approve(&self, to: Address, tokenId: U256) -> Result<(), CallError> {
+        let output = self.state.record(selectors::approve, Calls::Approve { to, tokenId })?;
+        CallError::decode(output, decode_approve_returns)
+    }
+
+    fn balance_of(&self, owner: Address) -> Result<U256, CallError> {
+        let output = self.state.record(selectors::balance_of, Calls::BalanceOf { owner })?;
+        CallError::decode(output, decode_balance_of_returns)
+    }
+
+    fn get_approved(&self, tokenId: U256) -> Result<Address, CallError> {
+        let output = self.state.record(selectors::get_approved, Calls::GetApproved { tokenId })?;
+        CallError::decode(output, decode_get_approved_returns)
+    }
+
+    fn is_approved_for_all(&self, owner: Address, operator: Address) -> Result<bool, CallError> {
+        let output = self.state.record(selectors::is_approved_for_all, Calls::IsApprovedForAll { owner, operator })?;
+        CallError::decode(output, decode_is_approved_for_all_returns)
+    }
+
+    fn owner_of(&self, tokenId: U256) -> Result<Address, CallError> {
+        let output = self.state.record(selectors::owner_of, Calls::OwnerOf { tokenId })?;
+        CallError::decode(output, decode_owner_of_returns)
+    }
+
+    fn safe_transfer_from__0x42842e0e(&self, from: Address, to: Address, tokenId: U256) -> Result<(), CallError> {
+        let output = self.state.record(selectors::safe_transfer_from__0x42842e0e, Calls::SafeTransferFrom__0x42842e0e { from, to, tokenId })?;
+        CallError::decode(output, decode_safe_transfer_from__0x42842e0e_returns)
+    }
+
+    fn safe_transfer_from__0xb88d4fde(&self, from: Address, to: Address, tokenId: U256, data: Vec<u8>) -> Result<(), CallError> {
+        let output = self.state.record(selectors::safe_transfer_from__0xb88d4fde, Calls::SafeTransferFrom__0xb88d4fde { from, to, tokenId, data })?;
+        CallError::decode(output, decode_safe_transfer_from__0xb88d4fde_returns)
+    }
+
+    fn set_approval_for_all(&self, operator: Address, approved: bool) -> Result<(), CallError> {
+        let output = self.state.record(selectors::set_approval_for_all, Calls::SetApprovalForAll { operator, approved })?;
+        CallError::decode(output, decode_set_approval_for_all_returns)
+    }
+
+    fn transfer_from(&self, from: Address, to: Address, tokenId: U256) -> Result<(), CallError> {
+        let output = self.state.record(selectors::transfer_from, Calls::TransferFrom { from, to, tokenId })?;
+        CallError::decode(output, decode_transfer_from_returns)
+    }
+}
@@ -0,0 +1,328 @@
+// Generated by stylus-bindgen
+#![allow(clippy::all)]
+use stylus_sdk::{alloy_primitives::{Address, FixedBytes, U256}, alloy_sol_types::{sol_data, SolType}};
+use stylus_interfaces::{CallError, mock::MockState, transport::CallKind, transport::Request, transport::StylusTransport, transport::Transport};
+
+/// Address of a Solidity `contract IERC20`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IERC20(pub Address);
+
+impl core::borrow::Borrow<[u8; 20]> for IERC20 {
+    fn borrow(&self) -> &[u8; 20] {
+        core::borrow::Borrow::borrow(&self.0)
+    }
+}
+
+impl From<Address> for IERC20 {
+    fn from(value: Address) -> Self {
+        Self(value)
+    }
+}
+
+/// Address of a Solidity `contract IOracle`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IOracle(pub Address);
+
+impl core::borrow::Borrow<[u8; 20]> for IOracle {
+    fn borrow(&self) -> &[u8; 20] {
+        core::borrow::Borrow::borrow(&self.0)
+    }
+}
+
+impl From<Address> for IOracle {
+    fn from(value: Address) -> Self {
+        Self(value)
+    }
+}
+
+/// Solidity user-defined value type `OrderId`, over `bytes32`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OrderId(pub FixedBytes<32>);
+
+impl core::borrow::Borrow<[u8; 32]> for OrderId {
+    fn borrow(&self) -> &[u8; 32] {
+        core::borrow::Borrow::borrow(&self.0)
+    }
+}
+
+impl From<FixedBytes<32>> for OrderId {
+    fn from(value: FixedBytes<32>) -> Self {
+        Self(value)
+    }
+}
+
+/// Solidity user-defined value type `Price`, over `uint128`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Price(pub u128);
+
+impl core::borrow::Borrow<u128> for Price {
+    fn borrow(&self) -> &u128 {
+        core::borrow::Borrow::borrow(&self.0)
+    }
+}
+
+impl From<u128> for Price {
+    fn from(value: u128) -> Self {
+        Self(value)
+    }
+}
+
+/// Solidity `enum Exchange.Side`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Side {
+    Buy = 0,
+    Sell = 1,
+}
+
+impl core::borrow::Borrow<u8> for Side {
+    fn borrow(&self) -> &u8 {
+        match self {
+            Self::Buy => &0,
+            Self::Sell => &1,
+        }
+    }
+}
+
+impl TryFrom<u8> for Side {
+    type Error = stylus_sdk::alloy_sol_types::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Buy),
+            1 => Ok(Self::Sell),
+            _ => Err(stylus_sdk::alloy_sol_types::Error::custom("value out of range for enum Exchange.Side")),
+        }
+    }
+}
+
+/// Per-call overrides applied to every call made through a `Contract`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CallOpts {
+    /// Gas forwarded to the callee; all remaining gas when `None`.
+    pub gas: Option<u64>,
+    /// Wei sent with non-view calls. View and pure functions always use STATICCALL.
+    pub value: U256,
+    /// Maximum bytes of return data copied back, guarding against return bombs.
+    pub max_return: Option<usize>,
+}
+
+pub struct Contract<T = StylusTransport> {
+    pub address: Address,
+    pub opts: CallOpts,
+    pub transport: T,
+}
+
+impl Contract {
+    pub fn new(address: Address) -> Self { Self::with_transport(address, StylusTransport) }
+}
+
+impl<T: Transport> Contract<T> {
+    pub fn with_transport(address: Address, transport: T) -> Self { Self { address, opts: CallOpts::default(), transport } }
+
+    pub fn with(&self, opts: CallOpts) -> Self where T: Clone { Self { address: self.address, opts, transport: self.transport.clone() } }
+
+    fn send(&self, kind: CallKind, input: &[u8]) -> Result<Vec<u8>, CallError> {
+        self.transport.call(Request {
+            address: self.address,
+            calldata: input,
+            kind,
+            value: if kind == CallKind::Call { self.opts.value } else { U256::ZERO },
+            gas: self.opts.gas,
+            max_return: self.opts.max_return,
+        })
+    }
+
+    // Original: oracle()
+    pub fn oracle__0x7dc0d1d0(&self, ) -> Result<(IOracle, Side), CallError> {
+        let input = encode_oracle__0x7dc0d1d0();
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_oracle__0x7dc0d1d0_returns)
+    }
+
+    // Original: place(uint8,address,uint128)
+    pub fn place__0x20648652(&self, side: Side, token: IERC20, price: Price) -> Result<OrderId, CallError> {
+        let input = encode_place__0x20648652(side, token, price);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_place__0x20648652_returns)
+    }
+
+    // Original: sideOf(bytes32)
+    pub fn side_of__0xa2e08620(&self, orderId: OrderId) -> Result<Side, CallError> {
+        let input = encode_side_of__0xa2e08620(orderId);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_side_of__0xa2e08620_returns)
+    }
+}
+
+/// Calls of the `Exchange` interface, so logic can be generic over `Contract` and `MockExchange`.
+pub trait ExchangeApi {
+    // Original: oracle()
+    fn oracle__0x7dc0d1d0(&self, ) -> Result<(IOracle, Side), CallError>;
+
+    // Original: place(uint8,address,uint128)
+    fn place__0x20648652(&self, side: Side, token: IERC20, price: Price) -> Result<OrderId, CallError>;
+
+    // Original: sideOf(bytes32)
+    fn side_of__0xa2e08620(&self, orderId: OrderId) -> Result<Side, CallError>;
+}
+
+impl<T: Transport> ExchangeApi for Contract<T> {
+    fn oracle__0x7dc0d1d0(&self, ) -> Result<(IOracle, Side), CallError> { Contract::oracle__0x7dc0d1d0(self, ) }
+
+    fn place__0x20648652(&self, side: Side, token: IERC20, price: Price) -> Result<OrderId, CallError> { Contract::place__0x20648652(self, side, token, price) }
+
+    fn side_of__0xa2e08620(&self, orderId: OrderId) -> Result<Side, CallError> { Contract::side_of__0xa2e08620(self, orderId) }
+}
+
+/// Function selectors, named like the methods that call them.
+#[allow(non_upper_case_globals)]
+pub mod selectors {
+    // Original: oracle()
+    pub const oracle__0x7dc0d1d0: [u8; 4] = [0x7d, 0xc0, 0xd1, 0xd0];
+    // Original: place(uint8,address,uint128)
+    pub const place__0x20648652: [u8; 4] = [0x20, 0x64, 0x86, 0x52];
+    // Original: sideOf(bytes32)
+    pub const side_of__0xa2e08620: [u8; 4] = [0xa2, 0xe0, 0x86, 0x20];
+}
+
+// Original: oracle()
+pub fn encode_oracle__0x7dc0d1d0() -> [u8; 4] {
+    selectors::oracle__0x7dc0d1d0
+}
+
+// Original: oracle() returns (address,uint8)
+pub fn decode_oracle__0x7dc0d1d0_returns(data: &[u8]) -> Result<(IOracle, Side), stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Address, sol_data::Uint<8>)>::abi_decode_params(data, true).and_then(|(value0, value1)| Ok((<IOracle>::from(value0), <Side>::try_from(value1)?)))
+}
+
+// Original: place(uint8,address,uint128)
+pub fn encode_place__0x20648652(side: Side, token: IERC20, price: Price) -> [u8; 100] {
+    let mut input = [0u8; 100];
+    input[..4].copy_from_slice(&selectors::place__0x20648652);
+    input[4..36].copy_from_slice(<sol_data::Uint<8> as SolType>::tokenize(&side).0.as_slice());
+    input[36..68].copy_from_slice(<sol_data::Address as SolType>::tokenize(&token).0.as_slice());
+    input[68..100].copy_from_slice(<sol_data::Uint<128> as SolType>::tokenize(&price).0.as_slice());
+    input
+}
+
+// Original: place(uint8,address,uint128) returns (bytes32)
+pub fn decode_place__0x20648652_returns(data: &[u8]) -> Result<OrderId, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::FixedBytes<32>,)>::abi_decode_params(data, true).map(|(value,)| <OrderId>::from(value))
+}
+
+// Original: sideOf(bytes32)
+pub fn encode_side_of__0xa2e08620(orderId: OrderId) -> [u8; 36] {
+    let mut input = [0u8; 36];
+    input[..4].copy_from_slice(&selectors::side_of__0xa2e08620);
+    input[4..36].copy_from_slice(<sol_data::FixedBytes<32> as SolType>::tokenize(&orderId).0.as_slice());
+    input
+}
+
+// Original: sideOf(bytes32) returns (uint8)
+pub fn decode_side_of__0xa2e08620_returns(data: &[u8]) -> Result<Side, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Uint<8>,)>::abi_decode_params(data, true).and_then(|(value,)| <Side>::try_from(value))
+}
+
+#[allow(non_camel_case_types, non_snake_case)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Calls {
+    // Original: oracle()
+    Oracle__0x7dc0d1d0,
+    // Original: place(uint8,address,uint128)
+    Place__0x20648652 { side: Side, token: IERC20, price: Price },
+    // Original: sideOf(bytes32)
+    SideOf__0xa2e08620 { orderId: OrderId },
+}
+
+impl Calls {
+    pub fn abi_decode(data: &[u8]) -> Result<Self, stylus_sdk::alloy_sol_types::Error> {
+        let Some((selector, args)) = data.split_first_chunk::<4>() else {
+            return Err(stylus_sdk::alloy_sol_types::Error::Overrun);
+        };
+        match *selector {
+            selectors::oracle__0x7dc0d1d0 => {
+                Ok(Self::Oracle__0x7dc0d1d0)
+            }
+            selectors::place__0x20648652 => {
+                let (side, token, price) = <(sol_data::Uint<8>, sol_data::Address, sol_data::Uint<128>)>::abi_decode_params(args, true)?;
+                Ok(Self::Place__0x20648652 { side: side.try_into()?, token: token.into(), price: price.into() })
+            }
+            selectors::side_of__0xa2e08620 => {
+                let (orderId,) = <(sol_data::FixedBytes<32>,)>::abi_decode_params(args, true)?;
+                Ok(Self::SideOf__0xa2e08620 { orderId: orderId.into() })
+            }
+            _ => Err(stylus_sdk::alloy_sol_types::Error::unknown_selector("Calls", *selector)),
+        }
+    }
+
+    pub fn abi_encode(&self) -> Vec<u8> {
+        match self.clone() {
+            Self::Oracle__0x7dc0d1d0 => encode_oracle__0x7dc0d1d0().to_vec(),
+            Self::Place__0x20648652 { side, token, price } => encode_place__0x20648652(side, token, price).to_vec(),
+            Self::SideOf__0xa2e08620 { orderId } => encode_side_of__0xa2e08620(orderId).to_vec(),
+        }
+    }
+
+    pub fn selector(&self) -> [u8; 4] {
+        match self {
+            Self::Oracle__0x7dc0d1d0 => selectors::oracle__0x7dc0d1d0,
+            Self::Place__0x20648652 { .. } => selectors::place__0x20648652,
+            Self::SideOf__0xa2e08620 { .. } => selectors::side_of__0xa2e08620,
+        }
+    }
+}
+
+impl core::fmt::Display for Calls {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Oracle__0x7dc0d1d0 => write!(f, "oracle()"),
+            Self::Place__0x20648652 { side, token, price } => write!(f, "place(side={:?}, token={:?}, price={:?})", side, token, price),
+            Self::SideOf__0xa2e08620 { orderId } => write!(f, "sideOf(orderId={:?})", orderId),
+        }
+    }
+}
+
+/// In-memory `ExchangeApi` for native unit tests: configurable responses and a log of calls.
+///
+/// Responses are raw return data, decoded exactly like a real `Contract` call.
+#[derive(Default)]
+pub struct MockExchange {
+    pub state: MockState<Calls>,
+}
+
+impl MockExchange {
+    pub fn new() -> Self { Self::default() }
+
+    /// Every call made so far, oldest first.
+    pub fn calls(&self) -> Vec<Calls> { self.state.calls() }
+
+    /// Makes calls to `selector` fail with `error` until replaced.
+    pub fn fail(&self, selector: [u8; 4], error: CallError) { self.state.respond(selector, Err(error)); }
+
+    /// Makes `oracle()` return `value` until replaced.
+    pub fn returns_oracle__0x7dc0d1d0(&self, value: (IOracle, Side)) { self.state.respond(selectors::oracle__0x7dc0d1d0, Ok(<(sol_data::Address, sol_data::Uint<8>)>::abi_encode_params(&value))); }
+
+    /// Makes `place(uint8,address,uint128)` return `value` until replaced.
+    pub fn returns_place__0x20648652(&self, value: OrderId) { self.state.respond(selectors::place__0x20648652, Ok(<(sol_data::FixedBytes<32>,)>::abi_encode_params(&(value,)))); }
+
+    /// Makes `sideOf(bytes32)` return `value` until replaced.
+    pub fn returns_side_of__0xa2e08620(&self, value: Side) { self.state.respond(selectors::side_of__0xa2e08620, Ok(<(sol_data::Uint<8>,)>::abi_encode_params(&(value,)))); }
+}
+
+impl ExchangeApi for MockExchange {
+    fn oracle__0x7dc0d1d0(&self, ) -> Result<(IOracle, Side), CallError> {
+        let output = self.state.record(selectors::oracle__0x7dc0d1d0, Calls::Oracle__0x7dc0d1d0)?;
+        CallError::decode(output, decode_oracle__0x7dc0d1d0_returns)
+    }
+
+    fn place__0x20648652(&self, side: Side, token: IERC20, price: Price) -> Result<OrderId, CallError> {
+        let output = self.state.record(selectors::place__0x20648652, Calls::Place__0x20648652 { side, token, price })?;
+        CallError::decode(output, decode_place__0x20648652_returns)
+    }
+
+    fn side_of__0xa2e08620(&self, orderId: OrderId) -> Result<Side, CallError> {
+        let output = self.state.record(selectors::side_of__0xa2e08620, Calls::SideOf__0xa2e08620 { orderId })?;
+        CallError::decode(output, decode_side_of__0xa2e08620_returns)
+    }
+}
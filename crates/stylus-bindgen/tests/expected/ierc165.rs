@@ -1,19 +1,149 @@
 // Generated by stylus-bindgen
 #![allow(clippy::all)]
-use stylus_sdk::{alloy_primitives::{Address, U256}, alloy_sol_types::SolType, call::RawCall, prelude::*};
+use stylus_sdk::{alloy_primitives::{Address, FixedBytes, U256}, alloy_sol_types::{sol_data, SolType}};
+use stylus_interfaces::{CallError, mock::MockState, transport::CallKind, transport::Request, transport::StylusTransport, transport::Transport};
 
-pub struct Contract {
+/// Per-call overrides applied to every call made through a `Contract`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CallOpts {
+    /// Gas forwarded to the callee; all remaining gas when `None`.
+    pub gas: Option<u64>,
+    /// Wei sent with non-view calls. View and pure functions always use STATICCALL.
+    pub value: U256,
+    /// Maximum bytes of return data copied back, guarding against return bombs.
+    pub max_return: Option<usize>,
+}
+
+pub struct Contract<T = StylusTransport> {
     pub address: Address,
+    pub opts: CallOpts,
+    pub transport: T,
 }
 
 impl Contract {
-    pub fn new(address: Address) -> Self { Self { address } }
+    pub fn new(address: Address) -> Self { Self::with_transport(address, StylusTransport) }
+}
+
+impl<T: Transport> Contract<T> {
+    pub fn with_transport(address: Address, transport: T) -> Self { Self { address, opts: CallOpts::default(), transport } }
+
+    pub fn with(&self, opts: CallOpts) -> Self where T: Clone { Self { address: self.address, opts, transport: self.transport.clone() } }
+
+    fn send(&self, kind: CallKind, input: &[u8]) -> Result<Vec<u8>, CallError> {
+        self.transport.call(Request {
+            address: self.address,
+            calldata: input,
+            kind,
+            value: if kind == CallKind::Call { self.opts.value } else { U256::ZERO },
+            gas: self.opts.gas,
+            max_return: self.opts.max_return,
+        })
+    }
+
+    // Original: supportsInterface(bytes4)
+    pub fn supports_interface__0x01ffc9a7(&self, interfaceId: FixedBytes<4>) -> Result<bool, CallError> {
+        let input = encode_supports_interface__0x01ffc9a7(interfaceId);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_supports_interface__0x01ffc9a7_returns)
+    }
+}
+
+/// Calls of the `Ierc165` interface, so logic can be generic over `Contract` and `MockIerc165`.
+pub trait Ierc165Api {
+    // Original: supportsInterface(bytes4)
+    fn supports_interface__0x01ffc9a7(&self, interfaceId: FixedBytes<4>) -> Result<bool, CallError>;
+}
+
+impl<T: Transport> Ierc165Api for Contract<T> {
+    fn supports_interface__0x01ffc9a7(&self, interfaceId: FixedBytes<4>) -> Result<bool, CallError> { Contract::supports_interface__0x01ffc9a7(self, interfaceId) }
+}
+
+/// Function selectors, named like the methods that call them.
+#[allow(non_upper_case_globals)]
+pub mod selectors {
+    // Original: supportsInterface(bytes4)
+    pub const supports_interface__0x01ffc9a7: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+}
+
+// Original: supportsInterface(bytes4)
+pub fn encode_supports_interface__0x01ffc9a7(interfaceId: FixedBytes<4>) -> [u8; 36] {
+    let mut input = [0u8; 36];
+    input[..4].copy_from_slice(&selectors::supports_interface__0x01ffc9a7);
+    input[4..36].copy_from_slice(<sol_data::FixedBytes<4> as SolType>::tokenize(&interfaceId).0.as_slice());
+    input
+}
 
+// Original: supportsInterface(bytes4) returns (bool)
+pub fn decode_supports_interface__0x01ffc9a7_returns(data: &[u8]) -> Result<bool, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Bool,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+#[allow(non_camel_case_types, non_snake_case)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Calls {
     // Original: supportsInterface(bytes4)
-    pub fn supports_interface__0x01ffc9a7(&self, interfaceId: Vec<u8>) -> Result<Vec<u8>, Vec<u8>> {
-        let input = hex::decode("01ffc9a7").unwrap();
-        // note: real encoding requires SolType definitions (Milestone 2)
-        let result = unsafe { RawCall::new_static().call(self.address, &input)? };
-        Ok(result)
+    SupportsInterface__0x01ffc9a7 { interfaceId: FixedBytes<4> },
+}
+
+impl Calls {
+    pub fn abi_decode(data: &[u8]) -> Result<Self, stylus_sdk::alloy_sol_types::Error> {
+        let Some((selector, args)) = data.split_first_chunk::<4>() else {
+            return Err(stylus_sdk::alloy_sol_types::Error::Overrun);
+        };
+        match *selector {
+            selectors::supports_interface__0x01ffc9a7 => {
+                let (interfaceId,) = <(sol_data::FixedBytes<4>,)>::abi_decode_params(args, true)?;
+                Ok(Self::SupportsInterface__0x01ffc9a7 { interfaceId })
+            }
+            _ => Err(stylus_sdk::alloy_sol_types::Error::unknown_selector("Calls", *selector)),
+        }
+    }
+
+    pub fn abi_encode(&self) -> Vec<u8> {
+        match self.clone() {
+            Self::SupportsInterface__0x01ffc9a7 { interfaceId } => encode_supports_interface__0x01ffc9a7(interfaceId).to_vec(),
+        }
+    }
+
+    pub fn selector(&self) -> [u8; 4] {
+        match self {
+            Self::SupportsInterface__0x01ffc9a7 { .. } => selectors::supports_interface__0x01ffc9a7,
+        }
+    }
+}
+
+impl core::fmt::Display for Calls {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::SupportsInterface__0x01ffc9a7 { interfaceId } => write!(f, "supportsInterface(interfaceId={})", interfaceId),
+        }
+    }
+}
+
+/// In-memory `Ierc165Api` for native unit tests: configurable responses and a log of calls.
+///
+/// Responses are raw return data, decoded exactly like a real `Contract` call.
+#[derive(Default)]
+pub struct MockIerc165 {
+    pub state: MockState<Calls>,
+}
+
+impl MockIerc165 {
+    pub fn new() -> Self { Self::default() }
+
+    /// Every call made so far, oldest first.
+    pub fn calls(&self) -> Vec<Calls> { self.state.calls() }
+
+    /// Makes calls to `selector` fail with `error` until replaced.
+    pub fn fail(&self, selector: [u8; 4], error: CallError) { self.state.respond(selector, Err(error)); }
+
+    /// Makes `supportsInterface(bytes4)` return `value` until replaced.
+    pub fn returns_supports_interface__0x01ffc9a7(&self, value: bool) { self.state.respond(selectors::supports_interface__0x01ffc9a7, Ok(<(sol_data::Bool,)>::abi_encode_params(&(value,)))); }
+}
+
+impl Ierc165Api for MockIerc165 {
+    fn supports_interface__0x01ffc9a7(&self, interfaceId: FixedBytes<4>) -> Result<bool, CallError> {
+        let output = self.state.record(selectors::supports_interface__0x01ffc9a7, Calls::SupportsInterface__0x01ffc9a7 { interfaceId })?;
+        CallError::decode(output, decode_supports_interface__0x01ffc9a7_returns)
     }
 }
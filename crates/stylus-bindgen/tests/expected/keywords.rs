@@ -0,0 +1,190 @@
+// Generated by stylus-bindgen
+#![allow(clippy::all)]
+use stylus_sdk::{alloy_primitives::{Address, U256}, alloy_sol_types::{sol_data, SolType}};
+use stylus_interfaces::{CallError, mock::MockState, transport::CallKind, transport::Request, transport::StylusTransport, transport::Transport};
+
+/// Per-call overrides applied to every call made through a `Contract`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CallOpts {
+    /// Gas forwarded to the callee; all remaining gas when `None`.
+    pub gas: Option<u64>,
+    /// Wei sent with non-view calls. View and pure functions always use STATICCALL.
+    pub value: U256,
+    /// Maximum bytes of return data copied back, guarding against return bombs.
+    pub max_return: Option<usize>,
+}
+
+pub struct Contract<T = StylusTransport> {
+    pub address: Address,
+    pub opts: CallOpts,
+    pub transport: T,
+}
+
+impl Contract {
+    pub fn new(address: Address) -> Self { Self::with_transport(address, StylusTransport) }
+}
+
+impl<T: Transport> Contract<T> {
+    pub fn with_transport(address: Address, transport: T) -> Self { Self { address, opts: CallOpts::default(), transport } }
+
+    pub fn with(&self, opts: CallOpts) -> Self where T: Clone { Self { address: self.address, opts, transport: self.transport.clone() } }
+
+    fn send(&self, kind: CallKind, input: &[u8]) -> Result<Vec<u8>, CallError> {
+        self.transport.call(Request {
+            address: self.address,
+            calldata: input,
+            kind,
+            value: if kind == CallKind::Call { self.opts.value } else { U256::ZERO },
+            gas: self.opts.gas,
+            max_return: self.opts.max_return,
+        })
+    }
+
+    // Original: get(uint8,address)
+    pub fn get__0x33e2127a(&self, arg0: u8, arg1: Address) -> Result<U256, CallError> {
+        let input = encode_get__0x33e2127a(arg0, arg1);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_get__0x33e2127a_returns)
+    }
+
+    // Original: put(address,uint256,bool,bytes)
+    pub fn put__0xe4f6a724(&self, arg0: Address, r#type: U256, self_: bool, r#match: Vec<u8>) -> Result<(), CallError> {
+        let input = encode_put__0xe4f6a724(arg0, r#type, self_, r#match);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_put__0xe4f6a724_returns)
+    }
+}
+
+/// Calls of the `Keywords` interface, so logic can be generic over `Contract` and `MockKeywords`.
+pub trait KeywordsApi {
+    // Original: get(uint8,address)
+    fn get__0x33e2127a(&self, arg0: u8, arg1: Address) -> Result<U256, CallError>;
+
+    // Original: put(address,uint256,bool,bytes)
+    fn put__0xe4f6a724(&self, arg0: Address, r#type: U256, self_: bool, r#match: Vec<u8>) -> Result<(), CallError>;
+}
+
+impl<T: Transport> KeywordsApi for Contract<T> {
+    fn get__0x33e2127a(&self, arg0: u8, arg1: Address) -> Result<U256, CallError> { Contract::get__0x33e2127a(self, arg0, arg1) }
+
+    fn put__0xe4f6a724(&self, arg0: Address, r#type: U256, self_: bool, r#match: Vec<u8>) -> Result<(), CallError> { Contract::put__0xe4f6a724(self, arg0, r#type, self_, r#match) }
+}
+
+/// Function selectors, named like the methods that call them.
+#[allow(non_upper_case_globals)]
+pub mod selectors {
+    // Original: get(uint8,address)
+    pub const get__0x33e2127a: [u8; 4] = [0x33, 0xe2, 0x12, 0x7a];
+    // Original: put(address,uint256,bool,bytes)
+    pub const put__0xe4f6a724: [u8; 4] = [0xe4, 0xf6, 0xa7, 0x24];
+}
+
+// Original: get(uint8,address)
+pub fn encode_get__0x33e2127a(arg0: u8, arg1: Address) -> [u8; 68] {
+    let mut input = [0u8; 68];
+    input[..4].copy_from_slice(&selectors::get__0x33e2127a);
+    input[4..36].copy_from_slice(<sol_data::Uint<8> as SolType>::tokenize(&arg0).0.as_slice());
+    input[36..68].copy_from_slice(<sol_data::Address as SolType>::tokenize(&arg1).0.as_slice());
+    input
+}
+
+// Original: get(uint8,address) returns (uint256)
+pub fn decode_get__0x33e2127a_returns(data: &[u8]) -> Result<U256, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Uint<256>,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+// Original: put(address,uint256,bool,bytes)
+pub fn encode_put__0xe4f6a724(arg0: Address, r#type: U256, self_: bool, r#match: Vec<u8>) -> Vec<u8> {
+    let mut input = selectors::put__0xe4f6a724.to_vec();
+    input.extend(<(sol_data::Address, sol_data::Uint<256>, sol_data::Bool, sol_data::Bytes)>::abi_encode_params(&(arg0, r#type, self_, r#match)));
+    input
+}
+
+// Original: put(address,uint256,bool,bytes) returns ()
+pub fn decode_put__0xe4f6a724_returns(data: &[u8]) -> Result<(), stylus_sdk::alloy_sol_types::Error> {
+    <()>::abi_decode_params(data, true)
+}
+
+#[allow(non_camel_case_types, non_snake_case)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Calls {
+    // Original: get(uint8,address)
+    Get__0x33e2127a { arg0: u8, arg1: Address },
+    // Original: put(address,uint256,bool,bytes)
+    Put__0xe4f6a724 { arg0: Address, r#type: U256, self_: bool, r#match: Vec<u8> },
+}
+
+impl Calls {
+    pub fn abi_decode(data: &[u8]) -> Result<Self, stylus_sdk::alloy_sol_types::Error> {
+        let Some((selector, args)) = data.split_first_chunk::<4>() else {
+            return Err(stylus_sdk::alloy_sol_types::Error::Overrun);
+        };
+        match *selector {
+            selectors::get__0x33e2127a => {
+                let (arg0, arg1) = <(sol_data::Uint<8>, sol_data::Address)>::abi_decode_params(args, true)?;
+                Ok(Self::Get__0x33e2127a { arg0, arg1 })
+            }
+            selectors::put__0xe4f6a724 => {
+                let (arg0, r#type, self_, r#match) = <(sol_data::Address, sol_data::Uint<256>, sol_data::Bool, sol_data::Bytes)>::abi_decode_params(args, true)?;
+                Ok(Self::Put__0xe4f6a724 { arg0, r#type, self_, r#match: r#match.into() })
+            }
+            _ => Err(stylus_sdk::alloy_sol_types::Error::unknown_selector("Calls", *selector)),
+        }
+    }
+
+    pub fn abi_encode(&self) -> Vec<u8> {
+        match self.clone() {
+            Self::Get__0x33e2127a { arg0, arg1 } => encode_get__0x33e2127a(arg0, arg1).to_vec(),
+            Self::Put__0xe4f6a724 { arg0, r#type, self_, r#match } => encode_put__0xe4f6a724(arg0, r#type, self_, r#match),
+        }
+    }
+
+    pub fn selector(&self) -> [u8; 4] {
+        match self {
+            Self::Get__0x33e2127a { .. } => selectors::get__0x33e2127a,
+            Self::Put__0xe4f6a724 { .. } => selectors::put__0xe4f6a724,
+        }
+    }
+}
+
+impl core::fmt::Display for Calls {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Get__0x33e2127a { arg0, arg1 } => write!(f, "get(arg0={}, arg1={})", arg0, arg1),
+            Self::Put__0xe4f6a724 { arg0, r#type, self_, r#match } => write!(f, "put(arg0={}, type={}, self={}, match=0x{})", arg0, r#type, self_, stylus_sdk::alloy_primitives::hex::encode(r#match)),
+        }
+    }
+}
+
+/// In-memory `KeywordsApi` for native unit tests: configurable responses and a log of calls.
+///
+/// Responses are raw return data, decoded exactly like a real `Contract` call.
+#[derive(Default)]
+pub struct MockKeywords {
+    pub state: MockState<Calls>,
+}
+
+impl MockKeywords {
+    pub fn new() -> Self { Self::default() }
+
+    /// Every call made so far, oldest first.
+    pub fn calls(&self) -> Vec<Calls> { self.state.calls() }
+
+    /// Makes calls to `selector` fail with `error` until replaced.
+    pub fn fail(&self, selector: [u8; 4], error: CallError) { self.state.respond(selector, Err(error)); }
+
+    /// Makes `get(uint8,address)` return `value` until replaced.
+    pub fn returns_get__0x33e2127a(&self, value: U256) { self.state.respond(selectors::get__0x33e2127a, Ok(<(sol_data::Uint<256>,)>::abi_encode_params(&(value,)))); }
+}
+
+impl KeywordsApi for MockKeywords {
+    fn get__0x33e2127a(&self, arg0: u8, arg1: Address) -> Result<U256, CallError> {
+        let output = self.state.record(selectors::get__0x33e2127a, Calls::Get__0x33e2127a { arg0, arg1 })?;
+        CallError::decode(output, decode_get__0x33e2127a_returns)
+    }
+
+    fn put__0xe4f6a724(&self, arg0: Address, r#type: U256, self_: bool, r#match: Vec<u8>) -> Result<(), CallError> {
+        let output = self.state.record(selectors::put__0xe4f6a724, Calls::Put__0xe4f6a724 { arg0, r#type, self_, r#match })?;
+        CallError::decode(output, decode_put__0xe4f6a724_returns)
+    }
+}
@@ -0,0 +1,164 @@
+// Generated by stylus-bindgen
+#![allow(clippy::all)]
+use stylus_sdk::{alloy_primitives::{Address, U256}, alloy_sol_types::{sol_data, SolType}};
+use stylus_interfaces::{CallError, mock::MockState, transport::CallKind, transport::Request, transport::StylusTransport, transport::Transport, lazy::ArrayView, lazy::ReturnData};
+
+/// Per-call overrides applied to every call made through a `Contract`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CallOpts {
+    /// Gas forwarded to the callee; all remaining gas when `None`.
+    pub gas: Option<u64>,
+    /// Wei sent with non-view calls. View and pure functions always use STATICCALL.
+    pub value: U256,
+    /// Maximum bytes of return data copied back, guarding against return bombs.
+    pub max_return: Option<usize>,
+}
+
+pub struct Contract<T = StylusTransport> {
+    pub address: Address,
+    pub opts: CallOpts,
+    pub transport: T,
+}
+
+impl Contract {
+    pub fn new(address: Address) -> Self { Self::with_transport(address, StylusTransport) }
+}
+
+impl<T: Transport> Contract<T> {
+    pub fn with_transport(address: Address, transport: T) -> Self { Self { address, opts: CallOpts::default(), transport } }
+
+    pub fn with(&self, opts: CallOpts) -> Self where T: Clone { Self { address: self.address, opts, transport: self.transport.clone() } }
+
+    fn send(&self, kind: CallKind, input: &[u8]) -> Result<Vec<u8>, CallError> {
+        self.transport.call(Request {
+            address: self.address,
+            calldata: input,
+            kind,
+            value: if kind == CallKind::Call { self.opts.value } else { U256::ZERO },
+            gas: self.opts.gas,
+            max_return: self.opts.max_return,
+        })
+    }
+
+    // Original: pairOf(address)
+    pub fn pair_of__0xa7465bdb(&self, pool: Address) -> Result<PairOf__0xa7465bdbReturns, CallError> {
+        let input = encode_pair_of__0xa7465bdb(pool);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode_lazy(output, decode_pair_of__0xa7465bdb_returns)
+    }
+}
+
+/// Calls of the `Pairs` interface, so logic can be generic over `Contract` and `MockPairs`.
+pub trait PairsApi {
+    // Original: pairOf(address)
+    fn pair_of__0xa7465bdb(&self, pool: Address) -> Result<PairOf__0xa7465bdbReturns, CallError>;
+}
+
+impl<T: Transport> PairsApi for Contract<T> {
+    fn pair_of__0xa7465bdb(&self, pool: Address) -> Result<PairOf__0xa7465bdbReturns, CallError> { Contract::pair_of__0xa7465bdb(self, pool) }
+}
+
+/// Function selectors, named like the methods that call them.
+#[allow(non_upper_case_globals)]
+pub mod selectors {
+    // Original: pairOf(address)
+    pub const pair_of__0xa7465bdb: [u8; 4] = [0xa7, 0x46, 0x5b, 0xdb];
+}
+
+// Original: pairOf(address)
+pub fn encode_pair_of__0xa7465bdb(pool: Address) -> [u8; 36] {
+    let mut input = [0u8; 36];
+    input[..4].copy_from_slice(&selectors::pair_of__0xa7465bdb);
+    input[4..36].copy_from_slice(<sol_data::Address as SolType>::tokenize(&pool).0.as_slice());
+    input
+}
+
+/// Lazily decoded outputs of `pairOf(address)`.
+#[allow(non_camel_case_types)]
+pub struct PairOf__0xa7465bdbReturns(pub ReturnData);
+
+impl PairOf__0xa7465bdbReturns {
+    // Output 0: uint256[2]
+    pub fn pair(&self) -> Result<[U256; 2], stylus_sdk::alloy_sol_types::Error> { self.0.value::<sol_data::FixedArray<sol_data::Uint<256>, 2>>(0) }
+
+    // Output 1: uint256[]
+    pub fn ids(&self) -> Result<ArrayView<'_, sol_data::Uint<256>>, stylus_sdk::alloy_sol_types::Error> { self.0.array(2) }
+
+    // Output 2: uint256
+    pub fn total(&self) -> Result<U256, stylus_sdk::alloy_sol_types::Error> { self.0.value::<sol_data::Uint<256>>(3) }
+}
+
+// Original: pairOf(address) returns (uint256[2],uint256[],uint256)
+pub fn decode_pair_of__0xa7465bdb_returns(data: Vec<u8>) -> Result<PairOf__0xa7465bdbReturns, Vec<u8>> {
+    ReturnData::new(data, 4).map(PairOf__0xa7465bdbReturns)
+}
+
+#[allow(non_camel_case_types, non_snake_case)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Calls {
+    // Original: pairOf(address)
+    PairOf__0xa7465bdb { pool: Address },
+}
+
+impl Calls {
+    pub fn abi_decode(data: &[u8]) -> Result<Self, stylus_sdk::alloy_sol_types::Error> {
+        let Some((selector, args)) = data.split_first_chunk::<4>() else {
+            return Err(stylus_sdk::alloy_sol_types::Error::Overrun);
+        };
+        match *selector {
+            selectors::pair_of__0xa7465bdb => {
+                let (pool,) = <(sol_data::Address,)>::abi_decode_params(args, true)?;
+                Ok(Self::PairOf__0xa7465bdb { pool })
+            }
+            _ => Err(stylus_sdk::alloy_sol_types::Error::unknown_selector("Calls", *selector)),
+        }
+    }
+
+    pub fn abi_encode(&self) -> Vec<u8> {
+        match self.clone() {
+            Self::PairOf__0xa7465bdb { pool } => encode_pair_of__0xa7465bdb(pool).to_vec(),
+        }
+    }
+
+    pub fn selector(&self) -> [u8; 4] {
+        match self {
+            Self::PairOf__0xa7465bdb { .. } => selectors::pair_of__0xa7465bdb,
+        }
+    }
+}
+
+impl core::fmt::Display for Calls {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PairOf__0xa7465bdb { pool } => write!(f, "pairOf(pool={})", pool),
+        }
+    }
+}
+
+/// In-memory `PairsApi` for native unit tests: configurable responses and a log of calls.
+///
+/// Responses are raw return data, decoded exactly like a real `Contract` call.
+#[derive(Default)]
+pub struct MockPairs {
+    pub state: MockState<Calls>,
+}
+
+impl MockPairs {
+    pub fn new() -> Self { Self::default() }
+
+    /// Every call made so far, oldest first.
+    pub fn calls(&self) -> Vec<Calls> { self.state.calls() }
+
+    /// Makes calls to `selector` fail with `error` until replaced.
+    pub fn fail(&self, selector: [u8; 4], error: CallError) { self.state.respond(selector, Err(error)); }
+
+    /// Makes `pairOf(address)` return `value` until replaced.
+    pub fn returns_pair_of__0xa7465bdb(&self, value: ([U256; 2], Vec<U256>, U256)) { self.state.respond(selectors::pair_of__0xa7465bdb, Ok(<(sol_data::FixedArray<sol_data::Uint<256>, 2>, sol_data::Array<sol_data::Uint<256>>, sol_data::Uint<256>)>::abi_encode_params(&value))); }
+}
+
+impl PairsApi for MockPairs {
+    fn pair_of__0xa7465bdb(&self, pool: Address) -> Result<PairOf__0xa7465bdbReturns, CallError> {
+        let output = self.state.record(selectors::pair_of__0xa7465bdb, Calls::PairOf__0xa7465bdb { pool })?;
+        CallError::decode_lazy(output, decode_pair_of__0xa7465bdb_returns)
+    }
+}
@@ -0,0 +1,355 @@
+// Generated by stylus-bindgen
+#![allow(clippy::all)]
+use stylus_sdk::{alloy_primitives::{Address, I256, U256}, alloy_sol_types::{sol_data, SolType}};
+use stylus_interfaces::{CallError, mock::MockState, transport::CallKind, transport::Request, transport::StylusTransport, transport::Transport};
+
+/// Solidity `fixed128x18`: the raw integer divided by 10^18.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed128x18(pub i128);
+
+impl Fixed128x18 {
+    /// Decimal places of the value.
+    pub const DECIMALS: u32 = 18;
+
+    pub const fn from_raw(raw: i128) -> Self {
+        Self(raw)
+    }
+
+    pub const fn raw(self) -> i128 {
+        self.0
+    }
+}
+
+impl core::borrow::Borrow<i128> for Fixed128x18 {
+    fn borrow(&self) -> &i128 {
+        &self.0
+    }
+}
+
+impl From<i128> for Fixed128x18 {
+    fn from(raw: i128) -> Self {
+        Self(raw)
+    }
+}
+
+/// The decimal value, with all `DECIMALS` places.
+impl core::fmt::Display for Fixed128x18 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (sign, digits) = (if self.0.is_negative() { "-" } else { "" }, self.0.unsigned_abs().to_string());
+        let digits = format!("{:0>1$}", digits, Self::DECIMALS as usize + 1);
+        let (int, frac) = digits.split_at(digits.len() - Self::DECIMALS as usize);
+        match frac {
+            "" => write!(f, "{}{}", sign, int),
+            _ => write!(f, "{}{}.{}", sign, int, frac),
+        }
+    }
+}
+
+/// Solidity `fixed256x1`: the raw integer divided by 10^1.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed256x1(pub I256);
+
+impl Fixed256x1 {
+    /// Decimal places of the value.
+    pub const DECIMALS: u32 = 1;
+
+    pub const fn from_raw(raw: I256) -> Self {
+        Self(raw)
+    }
+
+    pub const fn raw(self) -> I256 {
+        self.0
+    }
+}
+
+impl core::borrow::Borrow<I256> for Fixed256x1 {
+    fn borrow(&self) -> &I256 {
+        &self.0
+    }
+}
+
+impl From<I256> for Fixed256x1 {
+    fn from(raw: I256) -> Self {
+        Self(raw)
+    }
+}
+
+/// The decimal value, with all `DECIMALS` places.
+impl core::fmt::Display for Fixed256x1 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (sign, digits) = (if self.0.is_negative() { "-" } else { "" }, self.0.unsigned_abs().to_string());
+        let digits = format!("{:0>1$}", digits, Self::DECIMALS as usize + 1);
+        let (int, frac) = digits.split_at(digits.len() - Self::DECIMALS as usize);
+        match frac {
+            "" => write!(f, "{}{}", sign, int),
+            _ => write!(f, "{}{}.{}", sign, int, frac),
+        }
+    }
+}
+
+/// Solidity `ufixed128x18`: the raw integer divided by 10^18.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ufixed128x18(pub u128);
+
+impl Ufixed128x18 {
+    /// Decimal places of the value.
+    pub const DECIMALS: u32 = 18;
+
+    pub const fn from_raw(raw: u128) -> Self {
+        Self(raw)
+    }
+
+    pub const fn raw(self) -> u128 {
+        self.0
+    }
+}
+
+impl core::borrow::Borrow<u128> for Ufixed128x18 {
+    fn borrow(&self) -> &u128 {
+        &self.0
+    }
+}
+
+impl From<u128> for Ufixed128x18 {
+    fn from(raw: u128) -> Self {
+        Self(raw)
+    }
+}
+
+/// The decimal value, with all `DECIMALS` places.
+impl core::fmt::Display for Ufixed128x18 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (sign, digits) = ("", self.0.to_string());
+        let digits = format!("{:0>1$}", digits, Self::DECIMALS as usize + 1);
+        let (int, frac) = digits.split_at(digits.len() - Self::DECIMALS as usize);
+        match frac {
+            "" => write!(f, "{}{}", sign, int),
+            _ => write!(f, "{}{}.{}", sign, int, frac),
+        }
+    }
+}
+
+/// Solidity `ufixed32x4`: the raw integer divided by 10^4.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ufixed32x4(pub u32);
+
+impl Ufixed32x4 {
+    /// Decimal places of the value.
+    pub const DECIMALS: u32 = 4;
+
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    pub const fn raw(self) -> u32 {
+        self.0
+    }
+}
+
+impl core::borrow::Borrow<u32> for Ufixed32x4 {
+    fn borrow(&self) -> &u32 {
+        &self.0
+    }
+}
+
+impl From<u32> for Ufixed32x4 {
+    fn from(raw: u32) -> Self {
+        Self(raw)
+    }
+}
+
+/// The decimal value, with all `DECIMALS` places.
+impl core::fmt::Display for Ufixed32x4 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (sign, digits) = ("", self.0.to_string());
+        let digits = format!("{:0>1$}", digits, Self::DECIMALS as usize + 1);
+        let (int, frac) = digits.split_at(digits.len() - Self::DECIMALS as usize);
+        match frac {
+            "" => write!(f, "{}{}", sign, int),
+            _ => write!(f, "{}{}.{}", sign, int, frac),
+        }
+    }
+}
+
+/// Per-call overrides applied to every call made through a `Contract`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CallOpts {
+    /// Gas forwarded to the callee; all remaining gas when `None`.
+    pub gas: Option<u64>,
+    /// Wei sent with non-view calls. View and pure functions always use STATICCALL.
+    pub value: U256,
+    /// Maximum bytes of return data copied back, guarding against return bombs.
+    pub max_return: Option<usize>,
+}
+
+pub struct Contract<T = StylusTransport> {
+    pub address: Address,
+    pub opts: CallOpts,
+    pub transport: T,
+}
+
+impl Contract {
+    pub fn new(address: Address) -> Self { Self::with_transport(address, StylusTransport) }
+}
+
+impl<T: Transport> Contract<T> {
+    pub fn with_transport(address: Address, transport: T) -> Self { Self { address, opts: CallOpts::default(), transport } }
+
+    pub fn with(&self, opts: CallOpts) -> Self where T: Clone { Self { address: self.address, opts, transport: self.transport.clone() } }
+
+    fn send(&self, kind: CallKind, input: &[u8]) -> Result<Vec<u8>, CallError> {
+        self.transport.call(Request {
+            address: self.address,
+            calldata: input,
+            kind,
+            value: if kind == CallKind::Call { self.opts.value } else { U256::ZERO },
+            gas: self.opts.gas,
+            max_return: self.opts.max_return,
+        })
+    }
+
+    // Original: rates()
+    pub fn rates__0x43f48fbd(&self, ) -> Result<(Vec<U256>, Fixed128x18, Fixed256x1, Ufixed128x18, Fixed128x18), CallError> {
+        let input = encode_rates__0x43f48fbd();
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_rates__0x43f48fbd_returns)
+    }
+
+    // Original: setRate(fixed128x18,ufixed32x4)
+    pub fn set_rate__0xdba55e9a(&self, rate: Fixed128x18, fee: Ufixed32x4) -> Result<(), CallError> {
+        let input = encode_set_rate__0xdba55e9a(rate, fee);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_set_rate__0xdba55e9a_returns)
+    }
+}
+
+/// Calls of the `Rates` interface, so logic can be generic over `Contract` and `MockRates`.
+pub trait RatesApi {
+    // Original: rates()
+    fn rates__0x43f48fbd(&self, ) -> Result<(Vec<U256>, Fixed128x18, Fixed256x1, Ufixed128x18, Fixed128x18), CallError>;
+
+    // Original: setRate(fixed128x18,ufixed32x4)
+    fn set_rate__0xdba55e9a(&self, rate: Fixed128x18, fee: Ufixed32x4) -> Result<(), CallError>;
+}
+
+impl<T: Transport> RatesApi for Contract<T> {
+    fn rates__0x43f48fbd(&self, ) -> Result<(Vec<U256>, Fixed128x18, Fixed256x1, Ufixed128x18, Fixed128x18), CallError> { Contract::rates__0x43f48fbd(self, ) }
+
+    fn set_rate__0xdba55e9a(&self, rate: Fixed128x18, fee: Ufixed32x4) -> Result<(), CallError> { Contract::set_rate__0xdba55e9a(self, rate, fee) }
+}
+
+/// Function selectors, named like the methods that call them.
+#[allow(non_upper_case_globals)]
+pub mod selectors {
+    // Original: rates()
+    pub const rates__0x43f48fbd: [u8; 4] = [0x43, 0xf4, 0x8f, 0xbd];
+    // Original: setRate(fixed128x18,ufixed32x4)
+    pub const set_rate__0xdba55e9a: [u8; 4] = [0xdb, 0xa5, 0x5e, 0x9a];
+}
+
+// Original: rates()
+pub fn encode_rates__0x43f48fbd() -> [u8; 4] {
+    selectors::rates__0x43f48fbd
+}
+
+// Original: rates() returns (ufixed256x80[],fixed128x18,fixed256x1,ufixed,fixed128x18)
+pub fn decode_rates__0x43f48fbd_returns(data: &[u8]) -> Result<(Vec<U256>, Fixed128x18, Fixed256x1, Ufixed128x18, Fixed128x18), stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Array<sol_data::Uint<256>>, sol_data::Int<128>, sol_data::Int<256>, sol_data::Uint<128>, sol_data::Int<128>)>::abi_decode_params(data, true).map(|(value0, value1, value2, value3, value4)| (value0, <Fixed128x18>::from(value1), <Fixed256x1>::from(value2), <Ufixed128x18>::from(value3), <Fixed128x18>::from(value4)))
+}
+
+// Original: setRate(fixed128x18,ufixed32x4)
+pub fn encode_set_rate__0xdba55e9a(rate: Fixed128x18, fee: Ufixed32x4) -> [u8; 68] {
+    let mut input = [0u8; 68];
+    input[..4].copy_from_slice(&selectors::set_rate__0xdba55e9a);
+    input[4..36].copy_from_slice(<sol_data::Int<128> as SolType>::tokenize(&rate).0.as_slice());
+    input[36..68].copy_from_slice(<sol_data::Uint<32> as SolType>::tokenize(&fee).0.as_slice());
+    input
+}
+
+// Original: setRate(fixed128x18,ufixed32x4) returns ()
+pub fn decode_set_rate__0xdba55e9a_returns(data: &[u8]) -> Result<(), stylus_sdk::alloy_sol_types::Error> {
+    <()>::abi_decode_params(data, true)
+}
+
+#[allow(non_camel_case_types, non_snake_case)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Calls {
+    // Original: rates()
+    Rates__0x43f48fbd,
+    // Original: setRate(fixed128x18,ufixed32x4)
+    SetRate__0xdba55e9a { rate: Fixed128x18, fee: Ufixed32x4 },
+}
+
+impl Calls {
+    pub fn abi_decode(data: &[u8]) -> Result<Self, stylus_sdk::alloy_sol_types::Error> {
+        let Some((selector, args)) = data.split_first_chunk::<4>() else {
+            return Err(stylus_sdk::alloy_sol_types::Error::Overrun);
+        };
+        match *selector {
+            selectors::rates__0x43f48fbd => {
+                Ok(Self::Rates__0x43f48fbd)
+            }
+            selectors::set_rate__0xdba55e9a => {
+                let (rate, fee) = <(sol_data::Int<128>, sol_data::Uint<32>)>::abi_decode_params(args, true)?;
+                Ok(Self::SetRate__0xdba55e9a { rate: rate.into(), fee: fee.into() })
+            }
+            _ => Err(stylus_sdk::alloy_sol_types::Error::unknown_selector("Calls", *selector)),
+        }
+    }
+
+    pub fn abi_encode(&self) -> Vec<u8> {
+        match self.clone() {
+            Self::Rates__0x43f48fbd => encode_rates__0x43f48fbd().to_vec(),
+            Self::SetRate__0xdba55e9a { rate, fee } => encode_set_rate__0xdba55e9a(rate, fee).to_vec(),
+        }
+    }
+
+    pub fn selector(&self) -> [u8; 4] {
+        match self {
+            Self::Rates__0x43f48fbd => selectors::rates__0x43f48fbd,
+            Self::SetRate__0xdba55e9a { .. } => selectors::set_rate__0xdba55e9a,
+        }
+    }
+}
+
+impl core::fmt::Display for Calls {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Rates__0x43f48fbd => write!(f, "rates()"),
+            Self::SetRate__0xdba55e9a { rate, fee } => write!(f, "setRate(rate={:?}, fee={:?})", rate, fee),
+        }
+    }
+}
+
+/// In-memory `RatesApi` for native unit tests: configurable responses and a log of calls.
+///
+/// Responses are raw return data, decoded exactly like a real `Contract` call.
+#[derive(Default)]
+pub struct MockRates {
+    pub state: MockState<Calls>,
+}
+
+impl MockRates {
+    pub fn new() -> Self { Self::default() }
+
+    /// Every call made so far, oldest first.
+    pub fn calls(&self) -> Vec<Calls> { self.state.calls() }
+
+    /// Makes calls to `selector` fail with `error` until replaced.
+    pub fn fail(&self, selector: [u8; 4], error: CallError) { self.state.respond(selector, Err(error)); }
+
+    /// Makes `rates()` return `value` until replaced.
+    pub fn returns_rates__0x43f48fbd(&self, value: (Vec<U256>, Fixed128x18, Fixed256x1, Ufixed128x18, Fixed128x18)) { self.state.respond(selectors::rates__0x43f48fbd, Ok(<(sol_data::Array<sol_data::Uint<256>>, sol_data::Int<128>, sol_data::Int<256>, sol_data::Uint<128>, sol_data::Int<128>)>::abi_encode_params(&value))); }
+}
+
+impl RatesApi for MockRates {
+    fn rates__0x43f48fbd(&self, ) -> Result<(Vec<U256>, Fixed128x18, Fixed256x1, Ufixed128x18, Fixed128x18), CallError> {
+        let output = self.state.record(selectors::rates__0x43f48fbd, Calls::Rates__0x43f48fbd)?;
+        CallError::decode(output, decode_rates__0x43f48fbd_returns)
+    }
+
+    fn set_rate__0xdba55e9a(&self, rate: Fixed128x18, fee: Ufixed32x4) -> Result<(), CallError> {
+        let output = self.state.record(selectors::set_rate__0xdba55e9a, Calls::SetRate__0xdba55e9a { rate, fee })?;
+        CallError::decode(output, decode_set_rate__0xdba55e9a_returns)
+    }
+}
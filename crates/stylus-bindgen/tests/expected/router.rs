@@ -0,0 +1,287 @@
+// Generated by stylus-bindgen
+#![allow(clippy::all)]
+use stylus_sdk::{alloy_primitives::{Address, Bytes, Function, U256}, alloy_sol_types::{sol_data, SolType}};
+use stylus_interfaces::{CallError, mock::MockState, transport::CallKind, transport::Request, transport::StylusTransport, transport::Transport};
+
+/// Solidity external `function` value: a contract and one of its selectors, encoded as 24
+/// bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(C)]
+pub struct ExternalFunction {
+    pub address: Address,
+    pub selector: [u8; 4],
+}
+
+impl ExternalFunction {
+    /// Calls the function with `args`, its ABI-encoded arguments, and returns the raw return data.
+    pub fn call<T: stylus_interfaces::transport::Transport>(&self, transport: &T, args: &[u8]) -> Result<Vec<u8>, CallError> {
+        self.send(transport, stylus_interfaces::transport::CallKind::Call, args)
+    }
+
+    /// Like [`call`](Self::call), but as a STATICCALL, for `view` and `pure` functions.
+    pub fn static_call<T: stylus_interfaces::transport::Transport>(&self, transport: &T, args: &[u8]) -> Result<Vec<u8>, CallError> {
+        self.send(transport, stylus_interfaces::transport::CallKind::StaticCall, args)
+    }
+
+    fn send<T: stylus_interfaces::transport::Transport>(
+        &self,
+        transport: &T,
+        kind: stylus_interfaces::transport::CallKind,
+        args: &[u8],
+    ) -> Result<Vec<u8>, CallError> {
+        let calldata = [&self.selector[..], args].concat();
+        transport.call(stylus_interfaces::transport::Request {
+            address: self.address,
+            calldata: &calldata,
+            kind,
+            value: stylus_sdk::alloy_primitives::U256::ZERO,
+            gas: None,
+            max_return: None,
+        })
+    }
+}
+
+impl core::borrow::Borrow<[u8; 24]> for ExternalFunction {
+    fn borrow(&self) -> &[u8; 24] {
+        // SAFETY: `#[repr(C)]` lays out the 20 address bytes, then the 4 selector bytes, with
+        // alignment 1 and no padding.
+        unsafe { &*(self as *const Self).cast::<[u8; 24]>() }
+    }
+}
+
+impl From<stylus_sdk::alloy_primitives::Function> for ExternalFunction {
+    fn from(value: stylus_sdk::alloy_primitives::Function) -> Self {
+        let (address, selector) = value.to_address_and_selector();
+        Self { address, selector: selector.0 }
+    }
+}
+
+/// Per-call overrides applied to every call made through a `Contract`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CallOpts {
+    /// Gas forwarded to the callee; all remaining gas when `None`.
+    pub gas: Option<u64>,
+    /// Wei sent with non-view calls. View and pure functions always use STATICCALL.
+    pub value: U256,
+    /// Maximum bytes of return data copied back, guarding against return bombs.
+    pub max_return: Option<usize>,
+}
+
+pub struct Contract<T = StylusTransport> {
+    pub address: Address,
+    pub opts: CallOpts,
+    pub transport: T,
+}
+
+impl Contract {
+    pub fn new(address: Address) -> Self { Self::with_transport(address, StylusTransport) }
+}
+
+impl<T: Transport> Contract<T> {
+    pub fn with_transport(address: Address, transport: T) -> Self { Self { address, opts: CallOpts::default(), transport } }
+
+    pub fn with(&self, opts: CallOpts) -> Self where T: Clone { Self { address: self.address, opts, transport: self.transport.clone() } }
+
+    fn send(&self, kind: CallKind, input: &[u8]) -> Result<Vec<u8>, CallError> {
+        self.transport.call(Request {
+            address: self.address,
+            calldata: input,
+            kind,
+            value: if kind == CallKind::Call { self.opts.value } else { U256::ZERO },
+            gas: self.opts.gas,
+            max_return: self.opts.max_return,
+        })
+    }
+
+    // Original: hookOf(address)
+    pub fn hook_of__0xc8d5d871(&self, pool: Address) -> Result<ExternalFunction, CallError> {
+        let input = encode_hook_of__0xc8d5d871(pool);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_hook_of__0xc8d5d871_returns)
+    }
+
+    // Original: multicall(function[],bytes[])
+    pub fn multicall__0x0d40e7c7(&self, calls: Vec<Function>, data: Vec<Bytes>) -> Result<(), CallError> {
+        let input = encode_multicall__0x0d40e7c7(calls, data);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_multicall__0x0d40e7c7_returns)
+    }
+
+    // Original: swap(uint256,function)
+    pub fn swap__0x409fa8ff(&self, amountIn: U256, callback: ExternalFunction) -> Result<U256, CallError> {
+        let input = encode_swap__0x409fa8ff(amountIn, callback);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_swap__0x409fa8ff_returns)
+    }
+}
+
+/// Calls of the `Router` interface, so logic can be generic over `Contract` and `MockRouter`.
+pub trait RouterApi {
+    // Original: hookOf(address)
+    fn hook_of__0xc8d5d871(&self, pool: Address) -> Result<ExternalFunction, CallError>;
+
+    // Original: multicall(function[],bytes[])
+    fn multicall__0x0d40e7c7(&self, calls: Vec<Function>, data: Vec<Bytes>) -> Result<(), CallError>;
+
+    // Original: swap(uint256,function)
+    fn swap__0x409fa8ff(&self, amountIn: U256, callback: ExternalFunction) -> Result<U256, CallError>;
+}
+
+impl<T: Transport> RouterApi for Contract<T> {
+    fn hook_of__0xc8d5d871(&self, pool: Address) -> Result<ExternalFunction, CallError> { Contract::hook_of__0xc8d5d871(self, pool) }
+
+    fn multicall__0x0d40e7c7(&self, calls: Vec<Function>, data: Vec<Bytes>) -> Result<(), CallError> { Contract::multicall__0x0d40e7c7(self, calls, data) }
+
+    fn swap__0x409fa8ff(&self, amountIn: U256, callback: ExternalFunction) -> Result<U256, CallError> { Contract::swap__0x409fa8ff(self, amountIn, callback) }
+}
+
+/// Function selectors, named like the methods that call them.
+#[allow(non_upper_case_globals)]
+pub mod selectors {
+    // Original: hookOf(address)
+    pub const hook_of__0xc8d5d871: [u8; 4] = [0xc8, 0xd5, 0xd8, 0x71];
+    // Original: multicall(function[],bytes[])
+    pub const multicall__0x0d40e7c7: [u8; 4] = [0x0d, 0x40, 0xe7, 0xc7];
+    // Original: swap(uint256,function)
+    pub const swap__0x409fa8ff: [u8; 4] = [0x40, 0x9f, 0xa8, 0xff];
+}
+
+// Original: hookOf(address)
+pub fn encode_hook_of__0xc8d5d871(pool: Address) -> [u8; 36] {
+    let mut input = [0u8; 36];
+    input[..4].copy_from_slice(&selectors::hook_of__0xc8d5d871);
+    input[4..36].copy_from_slice(<sol_data::Address as SolType>::tokenize(&pool).0.as_slice());
+    input
+}
+
+// Original: hookOf(address) returns (function)
+pub fn decode_hook_of__0xc8d5d871_returns(data: &[u8]) -> Result<ExternalFunction, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Function,)>::abi_decode_params(data, true).map(|(value,)| <ExternalFunction>::from(value))
+}
+
+// Original: multicall(function[],bytes[])
+pub fn encode_multicall__0x0d40e7c7(calls: Vec<Function>, data: Vec<Bytes>) -> Vec<u8> {
+    let mut input = selectors::multicall__0x0d40e7c7.to_vec();
+    input.extend(<(sol_data::Array<sol_data::Function>, sol_data::Array<sol_data::Bytes>)>::abi_encode_params(&(calls, data)));
+    input
+}
+
+// Original: multicall(function[],bytes[]) returns ()
+pub fn decode_multicall__0x0d40e7c7_returns(data: &[u8]) -> Result<(), stylus_sdk::alloy_sol_types::Error> {
+    <()>::abi_decode_params(data, true)
+}
+
+// Original: swap(uint256,function)
+pub fn encode_swap__0x409fa8ff(amountIn: U256, callback: ExternalFunction) -> [u8; 68] {
+    let mut input = [0u8; 68];
+    input[..4].copy_from_slice(&selectors::swap__0x409fa8ff);
+    input[4..36].copy_from_slice(<sol_data::Uint<256> as SolType>::tokenize(&amountIn).0.as_slice());
+    input[36..68].copy_from_slice(<sol_data::Function as SolType>::tokenize(&callback).0.as_slice());
+    input
+}
+
+// Original: swap(uint256,function) returns (uint256)
+pub fn decode_swap__0x409fa8ff_returns(data: &[u8]) -> Result<U256, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Uint<256>,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+#[allow(non_camel_case_types, non_snake_case)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Calls {
+    // Original: hookOf(address)
+    HookOf__0xc8d5d871 { pool: Address },
+    // Original: multicall(function[],bytes[])
+    Multicall__0x0d40e7c7 { calls: Vec<Function>, data: Vec<Bytes> },
+    // Original: swap(uint256,function)
+    Swap__0x409fa8ff { amountIn: U256, callback: ExternalFunction },
+}
+
+impl Calls {
+    pub fn abi_decode(data: &[u8]) -> Result<Self, stylus_sdk::alloy_sol_types::Error> {
+        let Some((selector, args)) = data.split_first_chunk::<4>() else {
+            return Err(stylus_sdk::alloy_sol_types::Error::Overrun);
+        };
+        match *selector {
+            selectors::hook_of__0xc8d5d871 => {
+                let (pool,) = <(sol_data::Address,)>::abi_decode_params(args, true)?;
+                Ok(Self::HookOf__0xc8d5d871 { pool })
+            }
+            selectors::multicall__0x0d40e7c7 => {
+                let (calls, data) = <(sol_data::Array<sol_data::Function>, sol_data::Array<sol_data::Bytes>)>::abi_decode_params(args, true)?;
+                Ok(Self::Multicall__0x0d40e7c7 { calls, data })
+            }
+            selectors::swap__0x409fa8ff => {
+                let (amountIn, callback) = <(sol_data::Uint<256>, sol_data::Function)>::abi_decode_params(args, true)?;
+                Ok(Self::Swap__0x409fa8ff { amountIn, callback: callback.into() })
+            }
+            _ => Err(stylus_sdk::alloy_sol_types::Error::unknown_selector("Calls", *selector)),
+        }
+    }
+
+    pub fn abi_encode(&self) -> Vec<u8> {
+        match self.clone() {
+            Self::HookOf__0xc8d5d871 { pool } => encode_hook_of__0xc8d5d871(pool).to_vec(),
+            Self::Multicall__0x0d40e7c7 { calls, data } => encode_multicall__0x0d40e7c7(calls, data),
+            Self::Swap__0x409fa8ff { amountIn, callback } => encode_swap__0x409fa8ff(amountIn, callback).to_vec(),
+        }
+    }
+
+    pub fn selector(&self) -> [u8; 4] {
+        match self {
+            Self::HookOf__0xc8d5d871 { .. } => selectors::hook_of__0xc8d5d871,
+            Self::Multicall__0x0d40e7c7 { .. } => selectors::multicall__0x0d40e7c7,
+            Self::Swap__0x409fa8ff { .. } => selectors::swap__0x409fa8ff,
+        }
+    }
+}
+
+impl core::fmt::Display for Calls {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::HookOf__0xc8d5d871 { pool } => write!(f, "hookOf(pool={})", pool),
+            Self::Multicall__0x0d40e7c7 { calls, data } => write!(f, "multicall(calls=[{}], data=[{}])", calls.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", "), data.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ")),
+            Self::Swap__0x409fa8ff { amountIn, callback } => write!(f, "swap(amountIn={}, callback={:?})", amountIn, callback),
+        }
+    }
+}
+
+/// In-memory `RouterApi` for native unit tests: configurable responses and a log of calls.
+///
+/// Responses are raw return data, decoded exactly like a real `Contract` call.
+#[derive(Default)]
+pub struct MockRouter {
+    pub state: MockState<Calls>,
+}
+
+impl MockRouter {
+    pub fn new() -> Self { Self::default() }
+
+    /// Every call made so far, oldest first.
+    pub fn calls(&self) -> Vec<Calls> { self.state.calls() }
+
+    /// Makes calls to `selector` fail with `error` until replaced.
+    pub fn fail(&self, selector: [u8; 4], error: CallError) { self.state.respond(selector, Err(error)); }
+
+    /// Makes `hookOf(address)` return `value` until replaced.
+    pub fn returns_hook_of__0xc8d5d871(&self, value: ExternalFunction) { self.state.respond(selectors::hook_of__0xc8d5d871, Ok(<(sol_data::Function,)>::abi_encode_params(&(value,)))); }
+
+    /// Makes `swap(uint256,function)` return `value` until replaced.
+    pub fn returns_swap__0x409fa8ff(&self, value: U256) { self.state.respond(selectors::swap__0x409fa8ff, Ok(<(sol_data::Uint<256>,)>::abi_encode_params(&(value,)))); }
+}
+
+impl RouterApi for MockRouter {
+    fn hook_of__0xc8d5d871(&self, pool: Address) -> Result<ExternalFunction, CallError> {
+        let output = self.state.record(selectors::hook_of__0xc8d5d871, Calls::HookOf__0xc8d5d871 { pool })?;
+        CallError::decode(output, decode_hook_of__0xc8d5d871_returns)
+    }
+
+    fn multicall__0x0d40e7c7(&self, calls: Vec<Function>, data: Vec<Bytes>) -> Result<(), CallError> {
+        let output = self.state.record(selectors::multicall__0x0d40e7c7, Calls::Multicall__0x0d40e7c7 { calls, data })?;
+        CallError::decode(output, decode_multicall__0x0d40e7c7_returns)
+    }
+
+    fn swap__0x409fa8ff(&self, amountIn: U256, callback: ExternalFunction) -> Result<U256, CallError> {
+        let output = self.state.record(selectors::swap__0x409fa8ff, Calls::Swap__0x409fa8ff { amountIn, callback })?;
+        CallError::decode(output, decode_swap__0x409fa8ff_returns)
+    }
+}
@@ -6,7 +6,9 @@ mod common;
 use std::collections::HashSet;
 use std::fs;
 
-use common::{abi_path, extract_selector_fns, read_expected, run_bindgen, STANDARDS};
+use common::{
+    abi_path, extract_selector_fns, read_expected, run_bindgen, run_bindgen_with, STANDARDS,
+};
 
 #[test]
 fn erc20_golden() {
@@ -52,6 +54,362 @@ fn ierc165_golden() {
     );
 }
 
+#[test]
+fn erc721_delegate_golden() {
+    let generated = run_bindgen_with(&abi_path("erc721"), &["--delegate"]);
+    let expected = read_expected("erc721_delegate");
+    assert_eq!(
+        generated.trim(),
+        expected.trim(),
+        "ERC721 --delegate bindings do not match expected golden output"
+    );
+}
+
+#[test]
+fn erc721_call_context_golden() {
+    let generated = run_bindgen_with(&abi_path("erc721"), &["--call-context", "--delegate"]);
+    let expected = read_expected("erc721_call_context");
+    assert_eq!(
+        generated.trim(),
+        expected.trim(),
+        "ERC721 --call-context bindings do not match expected golden output"
+    );
+}
+
+#[test]
+fn erc1155_lazy_golden() {
+    let generated = run_bindgen_with(&abi_path("erc1155"), &["--lazy", "balanceOfBatch"]);
+    let expected = read_expected("erc1155_lazy");
+    assert_eq!(
+        generated.trim(),
+        expected.trim(),
+        "ERC1155 --lazy bindings do not match expected golden output"
+    );
+}
+
+#[test]
+fn pairs_lazy_golden() {
+    // `uint256[2]` takes two head words, so `ids` and `total` start at words 2 and 3.
+    let pairs = format!("{}/tests/abis/pairs.json", env!("CARGO_MANIFEST_DIR"));
+    let generated = run_bindgen_with(&pairs, &["--lazy", "pairOf"]);
+    let expected = read_expected("pairs_lazy");
+    assert_eq!(
+        generated.trim(),
+        expected.trim(),
+        "pairs --lazy output does not match expected golden output"
+    );
+}
+
+#[test]
+fn erc721_implement_golden() {
+    let generated = run_bindgen_with(&abi_path("erc721"), &["--mode", "implement"]);
+    let expected = read_expected("erc721_implement");
+    assert_eq!(
+        generated.trim(),
+        expected.trim(),
+        "ERC721 --mode implement output does not match expected golden output"
+    );
+}
+
+#[test]
+fn erc721_overloads_naming_golden() {
+    let generated = run_bindgen_with(&abi_path("erc721"), &["--naming", "overloads"]);
+    let expected = read_expected("erc721_overloads");
+    assert_eq!(
+        generated.trim(),
+        expected.trim(),
+        "ERC721 --naming overloads output does not match expected golden output"
+    );
+}
+
+#[test]
+fn erc721_subset_golden() {
+    let generated = run_bindgen_with(&abi_path("erc721"), &["--only-view", "--subset"]);
+    let expected = read_expected("erc721_subset");
+    assert_eq!(
+        generated.trim(),
+        expected.trim(),
+        "ERC721 --only-view --subset output does not match expected golden output"
+    );
+}
+
+#[test]
+fn subset_of_the_whole_abi_is_the_standard_interface_id() {
+    let generated = run_bindgen_with(&abi_path("erc721"), &["--subset"]);
+    assert!(generated.contains("pub const INTERFACE_ID: [u8; 4] = [0x80, 0xac, 0x58, 0xcd];\n"));
+    // Filters pick the same functions whether given by name glob, signature or selector.
+    let by_glob = run_bindgen_with(&abi_path("erc721"), &["--include", "*pprov*", "--subset"]);
+    let by_selector = run_bindgen_with(
+        &abi_path("erc721"),
+        &[
+            "--include",
+            "0x095ea7b3",
+            "--include",
+            "0x081812fc",
+            "--include",
+            "0xe985e9c5",
+            "--include",
+            "setApprovalForAll(address,bool)",
+            "--subset",
+        ],
+    );
+    assert_eq!(by_glob, by_selector);
+    assert!(!by_glob.contains("transfer"));
+}
+
+#[test]
+fn filters_reject_unknown_functions_and_empty_output() {
+    for args in [
+        &["--include", "mint"][..],
+        &["--exclude", "burn*"],
+        &["--include", "approve", "--only-view"],
+    ] {
+        let status = std::process::Command::new(common::bindgen_binary())
+            .args(["--input", &abi_path("erc721"), "--output"])
+            .arg(common::unique_output_path())
+            .args(args)
+            .stderr(std::process::Stdio::null())
+            .status()
+            .expect("Failed to execute stylus-bindgen");
+        assert!(!status.success(), "{:?}", args);
+    }
+}
+
+#[test]
+fn erc721_types_golden() {
+    let generated = run_bindgen_with(&abi_path("erc721"), &["--type", "*.tokenId=crate::TokenId"]);
+    let expected = read_expected("erc721_types");
+    assert_eq!(
+        generated.trim(),
+        expected.trim(),
+        "ERC721 --type output does not match expected golden output"
+    );
+}
+
+#[test]
+fn type_overrides_reject_unused_keys_and_implement_mode() {
+    for args in [
+        &["--type", "*.amount=crate::Amount"][..],
+        &["--type", "uint256"],
+        &["--type", "uint256=crate::TokenId", "--mode", "implement"],
+    ] {
+        let status = std::process::Command::new(common::bindgen_binary())
+            .args(["--input", &abi_path("erc721"), "--output"])
+            .arg(common::unique_output_path())
+            .args(args)
+            .stderr(std::process::Stdio::null())
+            .status()
+            .expect("Failed to execute stylus-bindgen");
+        assert!(!status.success(), "{:?}", args);
+    }
+}
+
+/// ABI with enum, contract and user-defined value type parameters.
+fn exchange_abi() -> String {
+    format!("{}/tests/abis/exchange.json", env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn exchange_internal_types_golden() {
+    let generated = run_bindgen_with(&exchange_abi(), &["--enum", "Side=Buy,Sell"]);
+    let expected = read_expected("exchange");
+    assert_eq!(
+        generated.trim(),
+        expected.trim(),
+        "exchange --enum output does not match expected golden output"
+    );
+}
+
+#[test]
+fn enums_without_variants_stay_u8() {
+    let generated = run_bindgen(&exchange_abi());
+    assert!(
+        generated.contains("pub fn decode_side_of__0xa2e08620_returns(data: &[u8]) -> Result<u8, ")
+    );
+    assert!(!generated.contains("pub enum Side"));
+    // So does an overridden one.
+    let generated = run_bindgen_with(&exchange_abi(), &["--type", "enum Exchange.Side=u8"]);
+    assert!(
+        generated.contains("pub fn decode_side_of__0xa2e08620_returns(data: &[u8]) -> Result<u8, ")
+    );
+    for args in [
+        &["--enum", "Kind=A,B"][..],
+        &["--enum", "Side=Buy,Sell", "--mode", "implement"],
+        &["--pack-handles", "--mode", "implement"],
+    ] {
+        let status = std::process::Command::new(common::bindgen_binary())
+            .args(["--input", &exchange_abi(), "--output"])
+            .arg(common::unique_output_path())
+            .args(args)
+            .stderr(std::process::Stdio::null())
+            .status()
+            .expect("Failed to execute stylus-bindgen");
+        assert!(!status.success(), "{:?}", args);
+    }
+}
+
+#[test]
+fn pack_handles_are_opt_in() {
+    let generated = run_bindgen_with(&exchange_abi(), &["--enum", "Side=Buy,Sell"]);
+    assert!(generated.contains("pub struct IERC20(pub Address);"));
+    assert!(!generated.contains("stylus_interfaces::erc20::Contract"));
+    let generated = run_bindgen_with(
+        &exchange_abi(),
+        &["--enum", "Side=Buy,Sell", "--pack-handles"],
+    );
+    assert!(!generated.contains("pub struct IERC20"));
+    assert!(generated.contains("token: stylus_interfaces::erc20::Contract"));
+}
+
+#[test]
+fn internal_types_may_not_shadow_generated_names() {
+    // `CallError` is imported by every `Transport` binding.
+    let abi = common::unique_output_path().with_extension("json");
+    std::fs::write(
+        &abi,
+        r#"[{"type": "function", "name": "last", "stateMutability": "view", "inputs": [],
+             "outputs": [{"name": "", "type": "uint8", "internalType": "CallError"}]}]"#,
+    )
+    .unwrap();
+    let output = std::process::Command::new(common::bindgen_binary())
+        .args(["--input", abi.to_str().unwrap(), "--output"])
+        .arg(common::unique_output_path())
+        .output()
+        .expect("Failed to execute stylus-bindgen");
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("`CallError` of last() would shadow the generated `CallError`"));
+}
+
+#[test]
+fn keyword_parameters_golden() {
+    let keywords = format!("{}/tests/abis/keywords.json", env!("CARGO_MANIFEST_DIR"));
+    let generated = run_bindgen(&keywords);
+    let expected = read_expected("keywords");
+    assert_eq!(
+        generated.trim(),
+        expected.trim(),
+        "keywords output does not match expected golden output"
+    );
+    // Unnamed parameters are numbered and keywords become raw identifiers.
+    assert!(generated.contains("arg0: Address, r#type: U256, self_: bool, r#match: Vec<u8>"));
+}
+
+#[test]
+fn router_function_types_golden() {
+    let router = format!("{}/tests/abis/router.json", env!("CARGO_MANIFEST_DIR"));
+    let generated = run_bindgen(&router);
+    let expected = read_expected("router");
+    assert_eq!(
+        generated.trim(),
+        expected.trim(),
+        "router output does not match expected golden output"
+    );
+    // Function values are called or static-called; the kind is never up to the caller.
+    assert!(generated.contains("pub fn call<") && generated.contains("pub fn static_call<"));
+    assert!(!generated.contains("DelegateCall"));
+    assert!(!generated
+        .lines()
+        .any(|line| line.contains("pub fn") && line.contains("CallKind")));
+    // stylus-sdk can't export `function` parameters.
+    let status = std::process::Command::new(common::bindgen_binary())
+        .args(["--input", &router, "--mode", "implement", "--output"])
+        .arg(common::unique_output_path())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .expect("Failed to execute stylus-bindgen");
+    assert!(!status.success());
+}
+
+#[test]
+fn rates_fixed_point_golden() {
+    let rates = format!("{}/tests/abis/rates.json", env!("CARGO_MANIFEST_DIR"));
+    let generated = run_bindgen(&rates);
+    let expected = read_expected("rates");
+    assert_eq!(
+        generated.trim(),
+        expected.trim(),
+        "rates output does not match expected golden output"
+    );
+    let status = std::process::Command::new(common::bindgen_binary())
+        .args(["--input", &rates, "--mode", "implement", "--output"])
+        .arg(common::unique_output_path())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .expect("Failed to execute stylus-bindgen");
+    assert!(!status.success());
+}
+
+#[test]
+fn zero_decimal_fixed_point_is_rejected() {
+    let abi = common::unique_output_path().with_extension("json");
+    std::fs::write(
+        &abi,
+        r#"[{"type": "function", "name": "f", "stateMutability": "view", "inputs": [],
+            "outputs": [{"name": "", "type": "fixed128x0"}]}]"#,
+    )
+    .unwrap();
+    let output = std::process::Command::new(common::bindgen_binary())
+        .args(["--input", abi.to_str().unwrap(), "--output"])
+        .arg(common::unique_output_path())
+        .output()
+        .expect("Failed to execute stylus-bindgen");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("`fixed128x0` is not a valid"));
+}
+
+#[test]
+fn lazy_tuple_outputs_are_rejected() {
+    let abi = common::unique_output_path().with_extension("json");
+    std::fs::write(
+        &abi,
+        r#"[{"type": "function", "name": "position", "stateMutability": "view", "inputs": [],
+            "outputs": [{"name": "", "type": "tuple", "components": [
+                {"name": "size", "type": "uint256"}, {"name": "owner", "type": "address"}]}]}]"#,
+    )
+    .unwrap();
+    let output = std::process::Command::new(common::bindgen_binary())
+        .args([
+            "--input",
+            abi.to_str().unwrap(),
+            "--lazy",
+            "position",
+            "--output",
+        ])
+        .arg(common::unique_output_path())
+        .output()
+        .expect("Failed to execute stylus-bindgen");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--lazy position(): output `(uint256,address)` is a tuple"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn implement_mode_rejects_binding_flags() {
+    let status = std::process::Command::new(common::bindgen_binary())
+        .args(["--input", &abi_path("erc721"), "--output"])
+        .arg(common::unique_output_path())
+        .args(["--mode", "implement", "--delegate"])
+        .stderr(std::process::Stdio::null())
+        .status()
+        .expect("Failed to execute stylus-bindgen");
+    assert!(!status.success());
+}
+
+#[test]
+fn lazy_accepts_signature_and_selector() {
+    let expected = read_expected("erc1155_lazy");
+    for pattern in ["balanceOfBatch(address[],uint256[])", "0x4e1273f4"] {
+        let generated = run_bindgen_with(&abi_path("erc1155"), &["--lazy", pattern]);
+        assert_eq!(generated.trim(), expected.trim(), "--lazy {}", pattern);
+    }
+}
+
 #[test]
 fn deterministic_output() {
     let input = abi_path("erc20");
@@ -69,7 +427,7 @@ fn golden_matches_interfaces_crate() {
     for name in STANDARDS {
         let expected = read_expected(name);
         let interface_src = fs::read_to_string(format!("{}/{}.rs", interface_dir, name))
-            .expect(&format!("interface source {}.rs must exist", name));
+            .unwrap_or_else(|_| panic!("interface source {}.rs must exist", name));
 
         let gen_fns: HashSet<String> = extract_selector_fns(&expected).into_iter().collect();
         let iface_fns: HashSet<String> = extract_selector_fns(&interface_src).into_iter().collect();
@@ -0,0 +1,83 @@
+//! `stylus-bindgen lint` end to end: rule levels from `sipb.toml`, exit status and formats.
+
+mod common;
+
+use std::fs;
+use std::process::{Command, Output};
+
+use common::{abi_path, bindgen_binary, unique_output_path, workspace_root, STANDARDS};
+
+/// An unnamed parameter (warn by default) and a `uint` alias (deny by default).
+const SLOPPY: &str = r#"[
+  {"type":"function","name":"mint","inputs":[{"name":"","type":"address"},{"name":"amount","type":"uint"}],"outputs":[],"stateMutability":"nonpayable"}
+]"#;
+
+fn lint(args: &[&str]) -> Output {
+    Command::new(bindgen_binary())
+        .arg("lint")
+        .args(args)
+        .current_dir(workspace_root())
+        .output()
+        .expect("Failed to execute stylus-bindgen")
+}
+
+fn write_temp(extension: &str, content: &str) -> String {
+    let path = unique_output_path().with_extension(extension);
+    fs::write(&path, content).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+#[test]
+fn standards_lint_clean() {
+    let paths: Vec<String> = STANDARDS.iter().map(|name| abi_path(name)).collect();
+    let args: Vec<&str> = paths.iter().map(String::as_str).collect();
+    let output = lint(&args);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "0 error(s), 0 warning(s)\n"
+    );
+}
+
+#[test]
+fn default_levels_fail_on_deny() {
+    let abi = write_temp("json", SLOPPY);
+    let output = lint(&[&abi]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(&format!(
+        "warning[unnamed-param]: {}: mint(address,uint): parameter 0 is unnamed\n",
+        abi
+    )));
+    assert!(stdout.contains(&format!(
+        "error[non-canonical-type]: {}: mint: `uint` should be spelled `uint256`\n",
+        abi
+    )));
+    assert!(stdout.ends_with("1 error(s), 1 warning(s)\n"));
+}
+
+#[test]
+fn config_relaxes_rules_in_json() {
+    let abi = write_temp("json", SLOPPY);
+    let config = write_temp(
+        "toml",
+        "[lint]\nnon-canonical-type = \"warn\"\nunnamed-param = \"allow\"\n",
+    );
+    let output = lint(&[&abi, "--config", &config, "--format", "json"]);
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["errors"], 0);
+    assert_eq!(report["warnings"], 1);
+    assert_eq!(report["findings"][0]["rule"], "non-canonical-type");
+    assert_eq!(report["findings"][0]["level"], "warn");
+    assert_eq!(report["findings"][0]["file"], abi);
+}
+
+#[test]
+fn unknown_rule_in_config_is_an_error() {
+    let config = write_temp("toml", "[lint]\nselector-clash = \"deny\"\n");
+    let output = lint(&[&abi_path("erc20"), "--config", &config]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("unknown lint rule `selector-clash`"));
+}
@@ -5,7 +5,11 @@ mod common;
 
 use std::collections::HashSet;
 
-use common::{extract_selector_fns, is_valid_selector_name, read_expected, STANDARDS};
+use common::{
+    contract_impl, extract_calls_variants, extract_decoder_fns, extract_encoder_fns,
+    extract_selector_fns, impl_block, is_valid_selector_name, item_block, read_expected,
+    NON_SELECTOR_METHODS, STANDARDS,
+};
 
 // ── Naming convention ─────────────────────────────────────────────
 
@@ -29,7 +33,7 @@ fn naming_convention_name__0x_selector() {
 
 #[test]
 fn function_counts() {
-    let expected_counts = [("erc20", 3), ("erc721", 9), ("erc1155", 6), ("ierc165", 1)];
+    let expected_counts = [("erc20", 6), ("erc721", 9), ("erc1155", 6), ("ierc165", 1)];
     for (name, expected) in expected_counts {
         let src = read_expected(name);
         let count = extract_selector_fns(&src).len();
@@ -64,6 +68,12 @@ fn type_mapping() {
         erc721.contains("data: Vec<u8>"),
         "Solidity 'bytes' must map to Rust 'Vec<u8>'"
     );
+
+    let ierc165 = read_expected("ierc165");
+    assert!(
+        ierc165.contains("interfaceId: FixedBytes<4>"),
+        "Solidity 'bytes4' must map to Rust 'FixedBytes<4>'"
+    );
 }
 
 // ── Snake case conversion ─────────────────────────────────────────
@@ -128,15 +138,26 @@ fn contract_struct_and_constructor() {
 fn all_selector_functions_return_result() {
     for name in STANDARDS {
         let src = read_expected(name);
-        for line in src.lines() {
-            if line.contains("pub fn ") && !line.contains("fn new(") {
-                assert!(
-                    line.contains("Result<Vec<u8>, Vec<u8>>"),
-                    "{}: selector function must return Result<Vec<u8>, Vec<u8>>: {}",
-                    name,
-                    line.trim()
-                );
-            }
+        for fn_name in extract_selector_fns(&src) {
+            let line = contract_impl(&src)
+                .lines()
+                .find(|l| l.contains(&format!("pub fn {}(", fn_name)))
+                .unwrap();
+            let decoder = src
+                .lines()
+                .find(|l| l.starts_with(&format!("pub fn decode_{}_returns(", fn_name)))
+                .unwrap();
+            let output = decoder
+                .split("-> Result<")
+                .nth(1)
+                .and_then(|rest| rest.strip_suffix(", stylus_sdk::alloy_sol_types::Error> {"))
+                .unwrap();
+            assert!(
+                line.contains(&format!("-> Result<{}, CallError>", output)),
+                "{}: selector function must return its decoded outputs or a CallError: {}",
+                name,
+                line.trim()
+            );
         }
     }
 }
@@ -170,27 +191,176 @@ fn selector_in_body_matches_name() {
     for name in STANDARDS {
         let src = read_expected(name);
         let lines: Vec<&str> = src.lines().collect();
+        for (i, line) in lines.iter().enumerate() {
+            if let Some(pos) = line.find("pub fn encode_") {
+                let after = &line[pos + 7..];
+                let fn_name = after.split('(').next().unwrap();
+                let selector = fn_name.split("__0x").last().unwrap();
+                let body = lines[i..std::cmp::min(i + 6, lines.len())].join("\n");
+                let method = fn_name.strip_prefix("encode_").unwrap();
+                assert!(
+                    body.contains(&format!("selectors::{}", method)),
+                    "{}: function {} body must use selectors::{}",
+                    name,
+                    fn_name,
+                    method
+                );
+                let bytes: Vec<String> = (0..4)
+                    .map(|b| format!("0x{}", &selector[2 * b..2 * b + 2]))
+                    .collect();
+                assert!(
+                    src.contains(&format!(
+                        "pub const {}: [u8; 4] = [{}];",
+                        method,
+                        bytes.join(", ")
+                    )),
+                    "{}: selector const for {} must be 0x{}",
+                    name,
+                    method,
+                    selector
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn no_runtime_hex_decoding() {
+    for name in STANDARDS {
+        let src = read_expected(name);
+        assert!(
+            !src.contains("hex::decode"),
+            "{}: hex::decode in output",
+            name
+        );
+        assert!(src.contains("#[allow(non_upper_case_globals)]\npub mod selectors {"));
+    }
+}
+
+#[test]
+fn methods_call_matching_encoder() {
+    for name in STANDARDS {
+        let src = read_expected(name);
+        let lines: Vec<&str> = contract_impl(&src).lines().collect();
         for (i, line) in lines.iter().enumerate() {
             if let Some(pos) = line.find("pub fn ") {
                 let after = &line[pos + 7..];
                 let fn_name = after.split('(').next().unwrap();
-                if fn_name == "new" {
+                if NON_SELECTOR_METHODS.contains(&fn_name) {
                     continue;
                 }
-                let selector = fn_name.split("__0x").last().unwrap();
                 let body = lines[i..std::cmp::min(i + 6, lines.len())].join("\n");
                 assert!(
-                    body.contains(&format!("hex::decode(\"{}\")", selector)),
-                    "{}: function {} body must call hex::decode(\"{}\")",
+                    body.contains(&format!("encode_{}(", fn_name)),
+                    "{}: method {} must build its calldata with encode_{}",
                     name,
                     fn_name,
-                    selector
+                    fn_name
                 );
             }
         }
     }
 }
 
+// ── Calldata encoders and return decoders ─────────────────────────
+
+#[test]
+fn every_method_has_encoder_and_decoder() {
+    for name in STANDARDS {
+        let src = read_expected(name);
+        let methods = extract_selector_fns(&src);
+        let encoders: HashSet<String> = extract_encoder_fns(&src).into_iter().collect();
+        let decoders: HashSet<String> = extract_decoder_fns(&src).into_iter().collect();
+        assert_eq!(encoders.len(), methods.len(), "{}: encoder count", name);
+        assert_eq!(decoders.len(), methods.len(), "{}: decoder count", name);
+        for method in methods {
+            assert!(
+                encoders.contains(&format!("encode_{}", method)),
+                "{}: missing encode_{}",
+                name,
+                method
+            );
+            assert!(
+                decoders.contains(&format!("decode_{}_returns", method)),
+                "{}: missing decode_{}_returns",
+                name,
+                method
+            );
+        }
+    }
+}
+
+#[test]
+fn encoders_return_calldata() {
+    for name in STANDARDS {
+        let src = read_expected(name);
+        for line in src.lines().filter(|l| l.contains("pub fn encode_")) {
+            let params = line.split('(').nth(1).unwrap().split(')').next().unwrap();
+            let words = params.split(", ").filter(|p| !p.is_empty()).count();
+            let static_len = format!("-> [u8; {}] {{", 4 + 32 * words);
+            assert!(
+                line.trim_end().ends_with("-> Vec<u8> {") || line.trim_end().ends_with(&static_len),
+                "{}: encoder must return Vec<u8> or [u8; 4 + 32 * n]: {}",
+                name,
+                line.trim()
+            );
+        }
+    }
+}
+
+#[test]
+fn static_arguments_encode_into_stack_buffer() {
+    let erc20 = read_expected("erc20");
+    assert!(erc20.contains(
+        "pub fn encode_transfer__0xa9059cbb(to: Address, value: U256) -> [u8; 68] {\n    let mut input = [0u8; 68];"
+    ));
+
+    let erc721 = read_expected("erc721");
+    assert!(erc721.contains("encode_safe_transfer_from__0x42842e0e(from: Address, to: Address, tokenId: U256) -> [u8; 100]"));
+    assert!(erc721.contains("encode_safe_transfer_from__0xb88d4fde(from: Address, to: Address, tokenId: U256, data: Vec<u8>) -> Vec<u8>"));
+    assert!(erc721.contains("Self::SafeTransferFrom__0x42842e0e { from, to, tokenId } => encode_safe_transfer_from__0x42842e0e(from, to, tokenId).to_vec(),"));
+}
+
+#[test]
+fn decoder_return_types() {
+    let erc20 = read_expected("erc20");
+    assert!(erc20.contains(
+        "pub fn decode_transfer__0xa9059cbb_returns(data: &[u8]) -> Result<bool, stylus_sdk::alloy_sol_types::Error>"
+    ));
+    assert!(erc20.contains(
+        "pub fn decode_balance_of__0x70a08231_returns(data: &[u8]) -> Result<U256, stylus_sdk::alloy_sol_types::Error>"
+    ));
+
+    let erc721 = read_expected("erc721");
+    assert!(erc721.contains(
+        "pub fn decode_owner_of__0x6352211e_returns(data: &[u8]) -> Result<Address, stylus_sdk::alloy_sol_types::Error>"
+    ));
+    assert!(erc721.contains(
+        "pub fn decode_safe_transfer_from__0xb88d4fde_returns(data: &[u8]) -> Result<(), stylus_sdk::alloy_sol_types::Error>"
+    ));
+
+    let erc1155 = read_expected("erc1155");
+    assert!(erc1155.contains(
+        "pub fn decode_balance_of_batch__0x4e1273f4_returns(data: &[u8]) -> Result<Vec<U256>, stylus_sdk::alloy_sol_types::Error>"
+    ));
+}
+
+#[test]
+fn encoders_encode_all_params() {
+    let erc721 = read_expected("erc721");
+    assert!(erc721.contains(
+        "<(sol_data::Address, sol_data::Address, sol_data::Uint<256>, sol_data::Bytes)>::abi_encode_params(&(from, to, tokenId, data))"
+    ));
+    let ierc165 = read_expected("ierc165");
+    assert!(ierc165.contains(
+        "input[4..36].copy_from_slice(<sol_data::FixedBytes<4> as SolType>::tokenize(&interfaceId).0.as_slice());"
+    ));
+    let erc20 = read_expected("erc20");
+    assert!(erc20.contains(
+        "input[36..68].copy_from_slice(<sol_data::Uint<256> as SolType>::tokenize(&value).0.as_slice());"
+    ));
+}
+
 // ── Original Solidity signature preserved as comment ──────────────
 
 #[test]
@@ -259,12 +429,14 @@ fn erc721_overload_both_selectors_present() {
 fn erc721_overloads_defined_exactly_once() {
     let src = read_expected("erc721");
     assert_eq!(
-        src.matches("fn safe_transfer_from__0x42842e0e(").count(),
+        src.matches("pub fn safe_transfer_from__0x42842e0e(")
+            .count(),
         1,
         "3-arg safeTransferFrom must be defined exactly once"
     );
     assert_eq!(
-        src.matches("fn safe_transfer_from__0xb88d4fde(").count(),
+        src.matches("pub fn safe_transfer_from__0xb88d4fde(")
+            .count(),
         1,
         "4-arg safeTransferFrom must be defined exactly once"
     );
@@ -302,8 +474,466 @@ fn erc1155_no_overload_collision() {
         "ERC1155 must have safe_batch_transfer_from__0x2eb2c2d6"
     );
     assert_eq!(
-        src.matches("fn safe_transfer_from__").count(),
+        src.matches("pub fn safe_transfer_from__").count(),
         1,
         "ERC1155 must have exactly one safe_transfer_from function"
     );
 }
+
+// ── Calls enum ────────────────────────────────────────────────────
+
+#[test]
+fn calls_enum_has_variant_per_method() {
+    for name in STANDARDS {
+        let src = read_expected(name);
+        let methods = extract_selector_fns(&src);
+        let variants = extract_calls_variants(&src);
+        assert_eq!(
+            variants.len(),
+            methods.len(),
+            "{}: Calls must have one variant per function: {:?}",
+            name,
+            variants
+        );
+        for method in methods {
+            let selector = method.split("__0x").last().unwrap();
+            assert_eq!(
+                variants
+                    .iter()
+                    .filter(|v| v.ends_with(&format!("__0x{}", selector)))
+                    .count(),
+                1,
+                "{}: Calls must have exactly one variant for selector 0x{}",
+                name,
+                selector
+            );
+        }
+    }
+}
+
+#[test]
+fn calls_enum_overload_variants() {
+    let src = read_expected("erc721");
+    assert!(
+        src.contains("SafeTransferFrom__0x42842e0e { from: Address, to: Address, tokenId: U256 },")
+    );
+    assert!(src.contains(
+        "SafeTransferFrom__0xb88d4fde { from: Address, to: Address, tokenId: U256, data: Vec<u8> },"
+    ));
+}
+
+#[test]
+fn calls_enum_decode_matches_selectors() {
+    for name in STANDARDS {
+        let src = read_expected(name);
+        for method in extract_selector_fns(&src) {
+            let arm = format!("selectors::{} => {{", method);
+            assert!(
+                src.contains(&arm),
+                "{}: Calls::abi_decode must match {} with `{}`",
+                name,
+                method,
+                arm
+            );
+        }
+        assert!(src.contains(
+            "pub fn abi_decode(data: &[u8]) -> Result<Self, stylus_sdk::alloy_sol_types::Error>"
+        ));
+        assert!(src.contains("pub fn abi_encode(&self) -> Vec<u8>"));
+        assert!(src.contains("pub fn selector(&self) -> [u8; 4]"));
+        assert!(src.contains("impl core::fmt::Display for Calls"));
+    }
+}
+
+#[test]
+fn calls_display_uses_solidity_names() {
+    let src = read_expected("erc721");
+    assert!(src.contains("\"safeTransferFrom(from={}, to={}, tokenId={})\""));
+    assert!(src.contains("\"safeTransferFrom(from={}, to={}, tokenId={}, data=0x{})\""));
+}
+
+// ── Call options ──────────────────────────────────────────────────
+
+#[test]
+fn call_opts_struct_and_builder() {
+    for name in STANDARDS {
+        let src = read_expected(name);
+        assert!(src.contains("pub struct CallOpts {"), "{}: CallOpts", name);
+        assert!(src.contains("pub gas: Option<u64>,"), "{}: gas", name);
+        assert!(src.contains("pub value: U256,"), "{}: value", name);
+        assert!(
+            src.contains("pub max_return: Option<usize>,"),
+            "{}: max_return",
+            name
+        );
+        assert!(src.contains("pub opts: CallOpts,"), "{}: opts field", name);
+        assert!(
+            src.contains("pub fn with(&self, opts: CallOpts) -> Self"),
+            "{}: with()",
+            name
+        );
+        assert!(src.contains("gas: self.opts.gas,"), "{}: gas", name);
+        assert!(
+            src.contains("max_return: self.opts.max_return,"),
+            "{}: max_return",
+            name
+        );
+        assert!(
+            src.contains(
+                "value: if kind == CallKind::Call { self.opts.value } else { U256::ZERO },"
+            ),
+            "{}: value only with CALL",
+            name
+        );
+    }
+}
+
+#[test]
+fn call_kind_follows_state_mutability() {
+    let erc721 = read_expected("erc721");
+    let impl_src = contract_impl(&erc721);
+    let body_of = |fn_name: &str| {
+        let start = impl_src.find(&format!("pub fn {}(", fn_name)).unwrap();
+        impl_src[start..]
+            .lines()
+            .take(4)
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    assert!(body_of("owner_of__0x6352211e").contains("self.send(CallKind::StaticCall, &input)"));
+    assert!(body_of("balance_of__0x70a08231").contains("self.send(CallKind::StaticCall, &input)"));
+    assert!(body_of("safe_transfer_from__0xb88d4fde").contains("self.send(CallKind::Call, &input)"));
+    assert!(
+        body_of("set_approval_for_all__0xa22cb465").contains("self.send(CallKind::Call, &input)")
+    );
+
+    // Legacy `constant` flags (erc20.json) are honoured too.
+    let erc20 = read_expected("erc20");
+    let impl_src = contract_impl(&erc20);
+    let start = impl_src.find("pub fn balance_of__0x70a08231(").unwrap();
+    assert!(impl_src[start..]
+        .lines()
+        .nth(2)
+        .unwrap()
+        .contains("CallKind::StaticCall"));
+    let start = impl_src.find("pub fn transfer__0xa9059cbb(").unwrap();
+    assert!(impl_src[start..]
+        .lines()
+        .nth(2)
+        .unwrap()
+        .contains("CallKind::Call,"));
+}
+
+// ── Delegatecall mode ─────────────────────────────────────────────
+
+#[test]
+fn delegate_contract_is_opt_in() {
+    for name in STANDARDS {
+        let src = read_expected(name);
+        assert!(
+            !src.contains("DelegateContract") && !src.contains("CallKind::DelegateCall"),
+            "{}: DelegateContract must only be generated with --delegate",
+            name
+        );
+    }
+}
+
+#[test]
+fn delegate_contract_mirrors_contract() {
+    let src = read_expected("erc721_delegate");
+    let delegate_impl = impl_block(&src, "DelegateContract");
+    let delegate_fns: HashSet<String> = delegate_impl
+        .lines()
+        .filter_map(|l| l.split("pub unsafe fn ").nth(1))
+        .map(|after| after.split('(').next().unwrap().to_string())
+        .collect();
+    let contract_fns: HashSet<String> = extract_selector_fns(&src).into_iter().collect();
+    assert_eq!(delegate_fns, contract_fns);
+}
+
+#[test]
+fn delegate_methods_are_unsafe_delegatecalls() {
+    let src = read_expected("erc721_delegate");
+    let delegate_impl = impl_block(&src, "DelegateContract");
+    for line in delegate_impl
+        .lines()
+        .filter(|l| l.contains("fn ") && l.contains("__0x"))
+    {
+        assert!(
+            line.trim_start().starts_with("pub unsafe fn "),
+            "delegate method must be unsafe: {}",
+            line.trim()
+        );
+    }
+    assert_eq!(
+        delegate_impl
+            .matches("unsafe { self.send_delegate(&input) }")
+            .count(),
+        9,
+        "every delegate method must go through the unsafe send_delegate"
+    );
+    assert!(delegate_impl.contains("unsafe { self.transport.call_delegate(request) }"));
+    assert!(!delegate_impl.contains("self.transport.call("));
+    assert!(
+        !delegate_impl.contains("CallKind::StaticCall")
+            && !delegate_impl.contains("self.send(CallKind::Call,")
+    );
+    // Nine methods plus the `send_delegate` helper.
+    assert_eq!(delegate_impl.matches("/// # Safety").count(), 10);
+    assert!(!contract_impl(&src).contains("CallKind::DelegateCall"));
+}
+
+// ── Call-context mode ─────────────────────────────────────────────
+
+#[test]
+fn call_context_methods_are_safe() {
+    let src = read_expected("erc721_call_context");
+    let impl_src = contract_impl(&src);
+    assert!(
+        !impl_src.contains("unsafe"),
+        "Contract must not use unsafe in --call-context mode"
+    );
+    assert!(
+        !src.contains("RawCall"),
+        "--call-context must not use RawCall"
+    );
+    assert!(
+        !src.contains("CallOpts"),
+        "gas and value come from the call context"
+    );
+}
+
+#[test]
+fn call_context_delegatecalls_flush_then_clear_the_storage_cache() {
+    let src = read_expected("erc721_call_context");
+    let delegate_impl = impl_block(&src, "DelegateContract");
+    let lines: Vec<&str> = delegate_impl.lines().map(str::trim).collect();
+    let calls: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].contains("delegate_call(context,"))
+        .collect();
+    assert_eq!(calls.len(), 9);
+    for i in calls {
+        assert_eq!(lines[i - 1], "StorageCache::flush();");
+        assert_eq!(lines[i + 1], "StorageCache::clear();");
+    }
+}
+
+#[test]
+fn call_context_trait_follows_state_mutability() {
+    let src = read_expected("erc721_call_context");
+    let impl_src = contract_impl(&src);
+    let line_of = |fn_name: &str| {
+        impl_src
+            .lines()
+            .find(|l| l.contains(&format!("pub fn {}(", fn_name)))
+            .unwrap()
+    };
+    for view in ["balance_of__0x70a08231", "owner_of__0x6352211e"] {
+        assert!(line_of(view).contains("(&self, context: impl StaticCallContext, "));
+    }
+    for mutating in [
+        "safe_transfer_from__0xb88d4fde",
+        "set_approval_for_all__0xa22cb465",
+    ] {
+        assert!(line_of(mutating).contains("(&self, context: impl NonPayableCallContext, "));
+    }
+    assert_eq!(
+        impl_src
+            .matches("static_call(context, self.address, &input).map_err(Vec::from)")
+            .count(),
+        4
+    );
+    assert_eq!(
+        impl_src
+            .matches(" call(context, self.address, &input).map_err(Vec::from)")
+            .count(),
+        5
+    );
+}
+
+#[test]
+fn call_context_delegate_stays_unsafe() {
+    let src = read_expected("erc721_call_context");
+    let delegate_impl = impl_block(&src, "DelegateContract");
+    assert_eq!(delegate_impl.matches("pub unsafe fn ").count(), 9);
+    assert_eq!(
+        delegate_impl
+            .matches("context: impl MutatingCallContext, ")
+            .count(),
+        9
+    );
+    assert_eq!(
+        delegate_impl
+            .matches("unsafe { delegate_call(context, self.address, &input) }.map_err(Vec::from)")
+            .count(),
+        9
+    );
+}
+
+// ── Lazy decoding ─────────────────────────────────────────────────
+
+#[test]
+fn lazy_function_returns_view() {
+    let src = read_expected("erc1155_lazy");
+    assert!(src.contains(
+        "use stylus_interfaces::{CallError, mock::MockState, transport::CallKind, transport::Request, transport::StylusTransport, transport::Transport, lazy::ArrayView, lazy::ReturnData};"
+    ));
+    assert!(src.contains(
+        "pub fn balance_of_batch__0x4e1273f4(&self, accounts: Vec<Address>, ids: Vec<U256>) -> Result<BalanceOfBatch__0x4e1273f4Returns, CallError> {"
+    ));
+    assert!(
+        src.contains("CallError::decode_lazy(output, decode_balance_of_batch__0x4e1273f4_returns)")
+    );
+    assert!(src.contains(
+        "pub fn output_0(&self) -> Result<ArrayView<'_, sol_data::Uint<256>>, stylus_sdk::alloy_sol_types::Error> { self.0.array(0) }"
+    ));
+    assert!(src.contains(
+        "pub fn decode_balance_of_batch__0x4e1273f4_returns(data: Vec<u8>) -> Result<BalanceOfBatch__0x4e1273f4Returns, Vec<u8>> {"
+    ));
+}
+
+#[test]
+fn lazy_leaves_other_functions_eager() {
+    let src = read_expected("erc1155_lazy");
+    // Contract method and mock
+    assert_eq!(src.matches("CallError::decode_lazy(").count(), 2);
+    assert!(src.contains(
+        "pub fn decode_balance_of__0x00fdd58e_returns(data: &[u8]) -> Result<U256, stylus_sdk::alloy_sol_types::Error>"
+    ));
+    assert!(!read_expected("erc1155").contains("ReturnData"));
+}
+
+// ── API trait and mock ────────────────────────────────────────────
+
+#[test]
+fn api_trait_covers_every_method() {
+    for (name, api, mock) in [
+        ("erc20", "Erc20Api", "MockErc20"),
+        ("erc721", "Erc721Api", "MockErc721"),
+        ("erc1155", "Erc1155Api", "MockErc1155"),
+        ("ierc165", "Ierc165Api", "MockIerc165"),
+    ] {
+        let src = read_expected(name);
+        let trait_src = item_block(&src, &format!("pub trait {}", api));
+        let contract_impl =
+            item_block(&src, &format!("impl<T: Transport> {} for Contract<T>", api));
+        let mock_impl = item_block(&src, &format!("impl {} for {}", api, mock));
+        for method in extract_selector_fns(&src) {
+            let def = format!("fn {}(&self, ", method);
+            assert!(
+                trait_src.contains(&def),
+                "{}: {} missing {}",
+                name,
+                api,
+                method
+            );
+            assert!(
+                contract_impl.contains(&def),
+                "{}: Contract missing {}",
+                name,
+                method
+            );
+            assert!(
+                mock_impl.contains(&def),
+                "{}: {} missing {}",
+                name,
+                mock,
+                method
+            );
+            assert!(contract_impl.contains(&format!("Contract::{}(self, ", method)));
+            assert!(mock_impl.contains(&format!("self.state.record(selectors::{}, ", method)));
+        }
+    }
+}
+
+#[test]
+fn mock_has_setters_for_functions_with_outputs() {
+    let src = read_expected("erc721");
+    let mock = impl_block(&src, "MockErc721");
+    assert!(mock.contains("pub fn returns_owner_of__0x6352211e(&self, value: Address)"));
+    assert!(mock.contains("pub fn fail(&self, selector: [u8; 4], error: CallError)"));
+    assert!(mock.contains("pub fn calls(&self) -> Vec<Calls>"));
+    assert!(!mock.contains("returns_approve__0x095ea7b3"));
+}
+
+#[test]
+fn call_context_api_takes_context() {
+    let src = read_expected("erc721_call_context");
+    assert!(src.contains(
+        "fn balance_of__0x70a08231(&self, context: impl StaticCallContext, owner: Address) -> Result<U256, CallError>;"
+    ));
+    assert!(src.contains("Contract::balance_of__0x70a08231(self, context, owner)"));
+    assert!(src.contains(
+        "fn balance_of__0x70a08231(&self, _context: impl StaticCallContext, owner: Address) -> Result<U256, CallError> {"
+    ));
+}
+
+// ── Transports ────────────────────────────────────────────────────
+
+#[test]
+fn contracts_are_generic_over_transport() {
+    for name in ["erc20", "erc721", "erc1155", "ierc165"] {
+        let src = read_expected(name);
+        assert!(
+            src.contains("pub struct Contract<T = StylusTransport> {"),
+            "{}: Contract must default to StylusTransport",
+            name
+        );
+        assert!(
+            src.contains("Self::with_transport(address, StylusTransport)"),
+            "{}: new() must use StylusTransport",
+            name
+        );
+        let body = contract_impl(&src);
+        assert!(body.contains("self.transport.call(Request {"), "{}", name);
+        assert!(
+            !body.contains("unsafe"),
+            "{}: Contract must not need unsafe",
+            name
+        );
+        assert!(
+            !src.contains("RawCall"),
+            "{}: calls must go through Transport",
+            name
+        );
+    }
+}
+
+#[test]
+fn call_context_mode_keeps_call_contexts() {
+    let src = read_expected("erc721_call_context");
+    assert!(!src.contains("Transport"));
+    assert!(src.contains("pub struct Contract {"));
+}
+
+// ── Implement mode ────────────────────────────────────────────────
+
+#[test]
+fn implement_overloads_keep_solidity_name() {
+    let src = read_expected("erc721_implement");
+    assert_eq!(
+        src.matches("#[selector(name = \"safeTransferFrom\")]")
+            .count(),
+        2
+    );
+    assert!(src.contains("pub fn safe_transfer_from__0x42842e0e(&mut self, from: Address, to: Address, tokenId: U256) -> Result<(), Vec<u8>> {"));
+    assert!(src.contains("pub fn safe_transfer_from__0xb88d4fde(&mut self, from: Address, to: Address, tokenId: U256, data: Bytes) -> Result<(), Vec<u8>> {"));
+    assert!(src.contains("use stylus_sdk::{abi::Bytes,"));
+}
+
+#[test]
+fn implement_router_delegates_to_trait() {
+    let src = read_expected("erc721_implement");
+    let interface = item_block(&src, "pub trait Erc721Interface");
+    let router = item_block(&src, "impl Erc721");
+    assert!(src.contains("#[public]\nimpl Erc721 {"));
+    assert!(src.contains("#[storage]\n#[entrypoint]\npub struct Erc721 {}"));
+    assert!(interface
+        .contains("fn owner_of__0x6352211e(&self, tokenId: U256) -> Result<Address, Vec<u8>>;"));
+    assert!(router.contains("<Self as Erc721Interface>::owner_of__0x6352211e(self, tokenId)"));
+    assert_eq!(
+        interface.matches("fn ").count(),
+        router.matches("pub fn ").count()
+    );
+    assert!(!src.contains("CallError"));
+}
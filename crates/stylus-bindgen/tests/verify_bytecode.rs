@@ -0,0 +1,126 @@
+//! `stylus-bindgen verify-bytecode` end to end, on hand-assembled dispatchers.
+
+mod common;
+
+use std::fs;
+use std::process::{Command, Output};
+
+use common::{abi_path, bindgen_binary, unique_output_path, workspace_root};
+
+/// Solidity-style dispatcher: `DUP1 PUSH4 <selector> EQ PUSH2 <dest> JUMPI` per selector, after
+/// `PUSH1 0 CALLDATALOAD PUSH1 0xe0 SHR`, ending in CBOR-like metadata.
+fn solidity_dispatcher(selectors: &[&str]) -> String {
+    let mut code = String::from("0x60003560e01c");
+    for selector in selectors {
+        code.push_str(&format!("8063{}1461004257", selector));
+    }
+    code.push_str("5f80fd5b00a264697066735822");
+    code
+}
+
+/// Runtime bytecode compiled by `tests/bytecode/build.sh`.
+fn compiled(name: &str) -> String {
+    let path = format!("{}/tests/bytecode/{}.hex", env!("CARGO_MANIFEST_DIR"), name);
+    fs::read_to_string(path).unwrap_or_else(|_| {
+        panic!(
+            "missing fixture {}.hex; run tests/bytecode/build.sh and commit it",
+            name
+        )
+    })
+}
+
+fn statuses(output: &Output) -> Vec<String> {
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    report["functions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["status"].as_str().unwrap().to_string())
+        .collect()
+}
+
+fn verify(code: &str, extra_args: &[&str]) -> Output {
+    let path = unique_output_path().with_extension("hex");
+    fs::write(&path, code).unwrap();
+    Command::new(bindgen_binary())
+        .args(["verify-bytecode", "--abi", &abi_path("erc20"), "--code"])
+        .arg(&path)
+        .args(extra_args)
+        .current_dir(workspace_root())
+        .output()
+        .expect("Failed to execute stylus-bindgen")
+}
+
+#[test]
+fn all_functions_dispatched() {
+    let code = solidity_dispatcher(&[
+        "095ea7b3", "18160ddd", "23b872dd", "70a08231", "a9059cbb", "dd62ed3e",
+    ]);
+    let output = verify(&code, &[]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("implements 6 of 6 functions"));
+    assert!(stdout.contains("  dispatched 0xa9059cbb  transfer(address,uint256)\n"));
+}
+
+#[test]
+fn missing_and_unknown_selectors_are_reported() {
+    // approve is missing; decimals is not in erc20.json.
+    let code = solidity_dispatcher(&[
+        "18160ddd", "23b872dd", "313ce567", "70a08231", "a9059cbb", "dd62ed3e",
+    ]);
+    let output = verify(&code, &["--format", "json"]);
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let statuses: Vec<(&str, &str)> = report["functions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| {
+            (
+                f["selector"].as_str().unwrap(),
+                f["status"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            ("0xdd62ed3e", "dispatched"),
+            ("0x095ea7b3", "absent"),
+            ("0x70a08231", "dispatched"),
+            ("0x18160ddd", "dispatched"),
+            ("0xa9059cbb", "dispatched"),
+            ("0x23b872dd", "dispatched"),
+        ]
+    );
+    assert_eq!(
+        report["unknown_selectors"],
+        serde_json::json!(["0x313ce567"])
+    );
+}
+
+#[test]
+fn solc_dispatches_every_function() {
+    let output = verify(&compiled("solc"), &["--format", "json"]);
+    assert!(output.status.success());
+    assert_eq!(statuses(&output), ["dispatched"; 6]);
+}
+
+#[test]
+fn vyper_selector_table_has_every_function() {
+    let output = verify(&compiled("vyper"), &["--format", "json"]);
+    assert!(output.status.success());
+    let statuses = statuses(&output);
+    assert_eq!(statuses.len(), 6);
+    assert!(!statuses.iter().any(|status| status == "absent"));
+}
+
+#[test]
+fn invalid_hex_is_an_error() {
+    let output = verify("0x60zz", &[]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("bytecode is not valid hex"));
+}
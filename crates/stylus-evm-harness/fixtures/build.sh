@@ -0,0 +1,38 @@
+#!/usr/bin/env bash
+# Rebuilds the *.hex fixtures from contracts/Fixtures.sol.
+# Needs exactly solc 0.8.24 and network access once to fetch OpenZeppelin Contracts v5.0.2,
+# checked out at the tag's commit; other versions produce different bytecode, so they are refused.
+# The tests only read the committed .hex files, so they run offline.
+set -euo pipefail
+
+SOLC_VERSION=0.8.24
+OZ_VERSION=5.0.2
+OZ_COMMIT=dbb6104ce834628e473d2173bbc9d47f81a9eec3
+cd "$(dirname "$0")"
+
+if ! solc --version | grep -q "Version: $SOLC_VERSION+"; then
+    echo "build.sh needs solc $SOLC_VERSION, found: $(solc --version | tail -n 1)" >&2
+    exit 1
+fi
+
+if [ ! -d lib/openzeppelin-contracts ]; then
+    git clone --depth 1 --branch "v$OZ_VERSION" \
+        https://github.com/OpenZeppelin/openzeppelin-contracts lib/openzeppelin-contracts
+fi
+# A moved tag or a local edit would change the bytecode without changing the version.
+if [ "$(git -C lib/openzeppelin-contracts rev-parse HEAD)" != "$OZ_COMMIT" ] \
+    || [ -n "$(git -C lib/openzeppelin-contracts status --porcelain)" ]; then
+    echo "lib/openzeppelin-contracts is not a clean v$OZ_VERSION ($OZ_COMMIT); delete it and rerun" >&2
+    exit 1
+fi
+
+out=$(mktemp -d)
+trap 'rm -rf "$out"' EXIT
+solc --optimize --optimize-runs 200 --evm-version cancun --metadata-hash none --bin \
+    @openzeppelin/contracts/=lib/openzeppelin-contracts/contracts/ \
+    -o "$out" contracts/Fixtures.sol
+
+for contract in TestERC20 TestERC721 TestERC1155 TestERC721Receiver TestERC1155Receiver; do
+    name=$(echo "$contract" | sed 's/^Test//' | tr '[:upper:]' '[:lower:]')
+    cp "$out/$contract.bin" "$name.hex"
+done
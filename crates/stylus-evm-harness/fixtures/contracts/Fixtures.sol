@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+// Concrete OpenZeppelin contracts deployed by the stylus-evm-harness tests.
+pragma solidity ^0.8.20;
+
+import {ERC20} from "@openzeppelin/contracts/token/ERC20/ERC20.sol";
+import {ERC721} from "@openzeppelin/contracts/token/ERC721/ERC721.sol";
+import {ERC721Holder} from "@openzeppelin/contracts/token/ERC721/utils/ERC721Holder.sol";
+import {ERC1155} from "@openzeppelin/contracts/token/ERC1155/ERC1155.sol";
+import {ERC1155Holder} from "@openzeppelin/contracts/token/ERC1155/utils/ERC1155Holder.sol";
+
+/// Mints 1000 tokens to the deployer.
+contract TestERC20 is ERC20 {
+    constructor() ERC20("Test", "TST") {
+        _mint(msg.sender, 1000);
+    }
+}
+
+contract TestERC721 is ERC721 {
+    constructor() ERC721("Test", "TST") {}
+
+    function mint(address to, uint256 tokenId) external {
+        _mint(to, tokenId);
+    }
+}
+
+contract TestERC1155 is ERC1155 {
+    constructor() ERC1155("") {}
+
+    function mint(address to, uint256 id, uint256 value) external {
+        _mint(to, id, value, "");
+    }
+}
+
+contract TestERC721Receiver is ERC721Holder {}
+
+contract TestERC1155Receiver is ERC1155Holder {}
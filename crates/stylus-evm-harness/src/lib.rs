@@ -0,0 +1,213 @@
+//! In-process EVM for testing generated bindings against real bytecode.
+//!
+//! [`EvmHarness`] wraps a `revm` instance with an in-memory state and implements
+//! [`Transport`], so any generated `Contract` can be pointed at it with `with_transport`. Calls
+//! are sent from [`EvmHarness::caller`], which stands in for the Stylus contract using the
+//! bindings. Compiled OpenZeppelin contracts are loaded with [`fixture`]; see `fixtures/README.md`.
+//!
+//! Dev-only: this crate is not published and nothing in `stylus-interfaces` depends on it.
+
+use alloy_primitives::{address, Address, Bytes, Log, TxKind, U256};
+use core::cell::RefCell;
+use revm::{
+    db::{AccountState, InMemoryDB},
+    primitives::{AccountInfo, Bytecode, ExecutionResult, Output},
+    DatabaseRef, Evm,
+};
+use std::path::PathBuf;
+use stylus_interfaces::transport::{CallKind, Request, Transport};
+
+/// Default `msg.sender` of every call and deployment.
+pub const DEFAULT_CALLER: Address = address!("00000000000000000000000000000000000c0de5");
+
+/// Gas limit of calls that don't set `CallOpts::gas`.
+const DEFAULT_GAS: u64 = 30_000_000;
+
+/// An in-memory chain that generated bindings can call into.
+pub struct EvmHarness {
+    db: RefCell<InMemoryDB>,
+    logs: RefCell<Vec<Log>>,
+    caller: Address,
+}
+
+impl Default for EvmHarness {
+    fn default() -> Self {
+        Self {
+            db: RefCell::new(InMemoryDB::default()),
+            logs: RefCell::new(Vec::new()),
+            caller: DEFAULT_CALLER,
+        }
+    }
+}
+
+impl EvmHarness {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn caller(&self) -> Address {
+        self.caller
+    }
+
+    /// Sends later calls and deployments from `caller`.
+    pub fn set_caller(&mut self, caller: Address) {
+        self.caller = caller;
+    }
+
+    /// Sets the ether balance of `address`.
+    pub fn fund(&mut self, address: Address, balance: U256) {
+        self.update_account(address, |info| info.balance = balance);
+    }
+
+    pub fn balance(&self, address: Address) -> U256 {
+        let info = self.db.borrow().basic_ref(address).ok().flatten();
+        info.map_or(U256::ZERO, |info| info.balance)
+    }
+
+    /// Places `runtime` code at `address` without running a constructor.
+    pub fn install(&mut self, address: Address, runtime: impl Into<Bytes>) {
+        let code = Bytecode::new_raw(runtime.into());
+        self.update_account(address, |info| {
+            info.nonce = info.nonce.max(1);
+            info.code_hash = code.hash_slow();
+            info.code = Some(code);
+        });
+    }
+
+    /// Runs `init_code` (creation bytecode plus ABI-encoded constructor arguments) from the
+    /// caller, returning the new contract's address or the constructor's revert data.
+    pub fn deploy(&mut self, init_code: impl Into<Bytes>) -> Result<Address, Vec<u8>> {
+        match self.execute(
+            TxKind::Create,
+            init_code.into(),
+            U256::ZERO,
+            DEFAULT_GAS,
+            true,
+        ) {
+            ExecutionResult::Success {
+                output: Output::Create(_, Some(address)),
+                ..
+            } => Ok(address),
+            ExecutionResult::Revert { output, .. } => Err(output.to_vec()),
+            _ => Err(Vec::new()),
+        }
+    }
+
+    /// Writes a raw storage slot, e.g. to seed balances.
+    pub fn set_storage(&mut self, address: Address, slot: U256, value: U256) {
+        self.db
+            .get_mut()
+            .insert_account_storage(address, slot, value)
+            .expect("in-memory database is infallible");
+    }
+
+    /// Reads a raw storage slot.
+    pub fn storage(&self, address: Address, slot: U256) -> U256 {
+        self.db
+            .borrow()
+            .storage_ref(address, slot)
+            .unwrap_or_default()
+    }
+
+    fn update_account(&mut self, address: Address, update: impl FnOnce(&mut AccountInfo)) {
+        let db = self.db.get_mut();
+        let mut info = db
+            .accounts
+            .get(&address)
+            .map(|account| account.info.clone())
+            .unwrap_or_default();
+        update(&mut info);
+        db.insert_account_info(address, info);
+        // A lookup may have cached the account as non-existent, which would hide the update.
+        if let Some(account) = db.accounts.get_mut(&address) {
+            account.account_state = AccountState::None;
+        }
+    }
+
+    /// Logs emitted by successful calls since the last `take_logs`, oldest first.
+    pub fn take_logs(&self) -> Vec<Log> {
+        self.logs.take()
+    }
+
+    /// Runs one transaction, committing its state changes only if `commit` is set.
+    fn execute(
+        &self,
+        to: TxKind,
+        data: Bytes,
+        value: U256,
+        gas: u64,
+        commit: bool,
+    ) -> ExecutionResult {
+        let db = self.db.take();
+        let mut evm = Evm::builder()
+            .with_db(db)
+            .modify_tx_env(|tx| {
+                tx.caller = self.caller;
+                tx.transact_to = to;
+                tx.data = data;
+                tx.value = value;
+                tx.gas_limit = gas;
+                tx.gas_price = U256::ZERO;
+            })
+            .build();
+        let result = if commit {
+            evm.transact_commit()
+        } else {
+            evm.transact().map(|outcome| outcome.result)
+        };
+        self.db.replace(evm.into_context().evm.inner.db);
+        let result = result.expect("transaction failed validation");
+        if let (true, ExecutionResult::Success { logs, .. }) = (commit, &result) {
+            self.logs.borrow_mut().extend(logs.iter().cloned());
+        }
+        result
+    }
+}
+
+impl Transport for EvmHarness {
+    /// Runs `request` as a transaction from the caller.
+    ///
+    /// Static calls are executed and rolled back. Delegatecalls panic: there is no calling
+    /// contract whose storage the callee could run against.
+    fn send(&self, request: Request<'_>) -> Result<Vec<u8>, Vec<u8>> {
+        let commit = match request.kind {
+            CallKind::Call => true,
+            CallKind::StaticCall => false,
+            CallKind::DelegateCall => panic!("EvmHarness cannot issue a top-level delegatecall"),
+        };
+        let result = self.execute(
+            TxKind::Call(request.address),
+            Bytes::copy_from_slice(request.calldata),
+            request.value,
+            request.gas.unwrap_or(DEFAULT_GAS),
+            commit,
+        );
+        match result {
+            ExecutionResult::Success { output, .. } => {
+                let mut data = output.into_data().to_vec();
+                if let Some(max_return) = request.max_return {
+                    data.truncate(max_return);
+                }
+                Ok(data)
+            }
+            ExecutionResult::Revert { output, .. } => Err(output.to_vec()),
+            ExecutionResult::Halt { .. } => Err(Vec::new()),
+        }
+    }
+
+    fn has_code(&self, address: Address) -> bool {
+        let info = self.db.borrow().basic_ref(address).ok().flatten();
+        info.is_some_and(|info| !info.is_empty_code_hash())
+    }
+}
+
+/// Creation bytecode of the compiled fixture `name`, read from `fixtures/<name>.hex`.
+///
+/// Returns `None` when the fixture has not been built; see `fixtures/README.md`.
+pub fn fixture(name: &str) -> Option<Bytes> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join(format!("{}.hex", name));
+    let hex = std::fs::read_to_string(path).ok()?;
+    Some(hex.trim().parse().expect("fixture must be hex encoded"))
+}
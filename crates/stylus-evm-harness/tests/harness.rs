@@ -0,0 +1,254 @@
+//! Generated bindings against a hand-assembled token, which needs no compiled fixtures.
+
+use alloy_primitives::{address, keccak256, Address, U256};
+use stylus_evm_harness::EvmHarness;
+use stylus_interfaces::{
+    erc20::{self, CallOpts},
+    transport::Transport,
+    CallError,
+};
+
+const TOKEN: Address = address!("0000000000000000000000000000000000007041");
+const ALICE: Address = address!("00000000000000000000000000000000000000a1");
+
+enum Asm {
+    Op(u8),
+    Push(Vec<u8>),
+    Label(&'static str),
+    PushLabel(&'static str),
+}
+
+use Asm::*;
+
+const ADD: u8 = 0x01;
+const SUB: u8 = 0x03;
+const LT: u8 = 0x10;
+const EQ: u8 = 0x14;
+const SHR: u8 = 0x1c;
+const CALLER: u8 = 0x33;
+const CALLDATALOAD: u8 = 0x35;
+const MSTORE: u8 = 0x52;
+const SLOAD: u8 = 0x54;
+const SSTORE: u8 = 0x55;
+const JUMP: u8 = 0x56;
+const JUMPI: u8 = 0x57;
+const JUMPDEST: u8 = 0x5b;
+const DUP1: u8 = 0x80;
+const DUP2: u8 = 0x81;
+const SWAP1: u8 = 0x90;
+const LOG3: u8 = 0xa3;
+const RETURN: u8 = 0xf3;
+const REVERT: u8 = 0xfd;
+
+fn push(value: u64) -> Asm {
+    let bytes = value.to_be_bytes();
+    let skip = bytes.iter().take_while(|b| **b == 0).count().min(7);
+    Push(bytes[skip..].to_vec())
+}
+
+/// Assembles `program`; label pushes are always PUSH1, which is enough for tiny contracts.
+fn assemble(program: &[Asm]) -> Vec<u8> {
+    let mut labels = Vec::new();
+    let mut offset = 0;
+    for item in program {
+        match item {
+            Op(_) => offset += 1,
+            Push(bytes) => offset += 1 + bytes.len(),
+            Label(name) => {
+                labels.push((*name, offset));
+                offset += 1;
+            }
+            PushLabel(_) => offset += 2,
+        }
+    }
+    let mut code = Vec::new();
+    for item in program {
+        match item {
+            Op(op) => code.push(*op),
+            Push(bytes) => {
+                code.push(0x5f + bytes.len() as u8);
+                code.extend_from_slice(bytes);
+            }
+            Label(_) => code.push(JUMPDEST),
+            PushLabel(name) => {
+                let (_, target) = labels.iter().find(|(label, _)| label == name).unwrap();
+                code.extend_from_slice(&[0x60, u8::try_from(*target).unwrap()]);
+            }
+        }
+    }
+    code
+}
+
+/// `balanceOf` and `transfer` only; balances live in the slot named by the holder's address.
+/// Transfers exceeding the balance revert without data.
+fn token_runtime() -> Vec<u8> {
+    let transfer_topic = keccak256("Transfer(address,address,uint256)").to_vec();
+    assemble(&[
+        push(0),
+        Op(CALLDATALOAD),
+        push(0xe0),
+        Op(SHR),
+        Op(DUP1),
+        Push(erc20::selectors::balance_of__0x70a08231.to_vec()),
+        Op(EQ),
+        PushLabel("balance_of"),
+        Op(JUMPI),
+        Push(erc20::selectors::transfer__0xa9059cbb.to_vec()),
+        Op(EQ),
+        PushLabel("transfer"),
+        Op(JUMPI),
+        PushLabel("fail"),
+        Op(JUMP),
+        Label("balance_of"),
+        push(4),
+        Op(CALLDATALOAD),
+        Op(SLOAD),
+        push(0),
+        Op(MSTORE),
+        push(32),
+        push(0),
+        Op(RETURN),
+        Label("transfer"),
+        push(0x24),
+        Op(CALLDATALOAD),
+        Op(CALLER),
+        Op(SLOAD),
+        Op(DUP2),
+        Op(DUP2),
+        Op(LT),
+        PushLabel("fail"),
+        Op(JUMPI),
+        Op(DUP2),
+        Op(SWAP1),
+        Op(SUB),
+        Op(CALLER),
+        Op(SSTORE),
+        Op(DUP1),
+        push(4),
+        Op(CALLDATALOAD),
+        Op(SLOAD),
+        Op(ADD),
+        push(4),
+        Op(CALLDATALOAD),
+        Op(SSTORE),
+        push(0),
+        Op(MSTORE),
+        push(4),
+        Op(CALLDATALOAD),
+        Op(CALLER),
+        Push(transfer_topic),
+        push(32),
+        push(0),
+        Op(LOG3),
+        push(1),
+        push(0),
+        Op(MSTORE),
+        push(32),
+        push(0),
+        Op(RETURN),
+        Label("fail"),
+        push(0),
+        Op(DUP1),
+        Op(REVERT),
+    ])
+}
+
+fn harness_with_balance(balance: u64) -> EvmHarness {
+    let mut evm = EvmHarness::new();
+    evm.install(TOKEN, token_runtime());
+    let holder = U256::from_be_slice(evm.caller().as_slice());
+    evm.set_storage(TOKEN, holder, U256::from(balance));
+    evm
+}
+
+#[test]
+fn transfer_moves_balance() {
+    let evm = harness_with_balance(100);
+    let token = erc20::Contract::with_transport(TOKEN, &evm);
+    assert_eq!(token.transfer__0xa9059cbb(ALICE, U256::from(30)), Ok(true));
+    assert_eq!(token.balance_of__0x70a08231(ALICE), Ok(U256::from(30)));
+    assert_eq!(
+        token.balance_of__0x70a08231(evm.caller()),
+        Ok(U256::from(70))
+    );
+}
+
+#[test]
+fn transfer_emits_event() {
+    let evm = harness_with_balance(100);
+    let token = erc20::Contract::with_transport(TOKEN, &evm);
+    token.transfer__0xa9059cbb(ALICE, U256::from(5)).unwrap();
+    let logs = evm.take_logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].address, TOKEN);
+    assert_eq!(logs[0].topics()[2], ALICE.into_word());
+    assert_eq!(
+        logs[0].data.data.as_ref(),
+        U256::from(5).to_be_bytes::<32>()
+    );
+    assert!(evm.take_logs().is_empty());
+}
+
+#[test]
+fn failed_transfer_reverts_state() {
+    let evm = harness_with_balance(10);
+    let token = erc20::Contract::with_transport(TOKEN, &evm);
+    assert_eq!(
+        token.transfer__0xa9059cbb(ALICE, U256::from(11)),
+        Err(CallError::EmptyRevert)
+    );
+    assert_eq!(token.balance_of__0x70a08231(ALICE), Ok(U256::ZERO));
+    assert!(evm.take_logs().is_empty());
+}
+
+#[test]
+fn unknown_selector_reverts() {
+    let evm = harness_with_balance(10);
+    let token = erc20::Contract::with_transport(TOKEN, &evm);
+    assert_eq!(
+        token.approve__0x095ea7b3(ALICE, U256::from(1)),
+        Err(CallError::EmptyRevert)
+    );
+}
+
+#[test]
+fn missing_contract_has_no_code() {
+    let evm = EvmHarness::new();
+    let token = erc20::Contract::with_transport(TOKEN, &evm);
+    assert!(!evm.has_code(TOKEN));
+    assert_eq!(
+        token.balance_of__0x70a08231(ALICE),
+        Err(CallError::NoCode(TOKEN))
+    );
+}
+
+#[test]
+fn call_opts_are_applied() {
+    let mut evm = harness_with_balance(100);
+    evm.fund(evm.caller(), U256::from(1_000));
+    let token = erc20::Contract::with_transport(TOKEN, &evm);
+    let paying = token.with(CallOpts {
+        value: U256::from(7),
+        ..CallOpts::default()
+    });
+    assert_eq!(paying.transfer__0xa9059cbb(ALICE, U256::from(1)), Ok(true));
+    assert_eq!(evm.balance(TOKEN), U256::from(7));
+
+    let starved = token.with(CallOpts {
+        gas: Some(30_000),
+        ..CallOpts::default()
+    });
+    assert_eq!(
+        starved.transfer__0xa9059cbb(ALICE, U256::from(1)),
+        Err(CallError::EmptyRevert)
+    );
+
+    let truncated = token.with(CallOpts {
+        max_return: Some(16),
+        ..CallOpts::default()
+    });
+    assert_eq!(
+        truncated.balance_of__0x70a08231(ALICE),
+        Err(CallError::MalformedReturn(vec![0; 16]))
+    );
+}
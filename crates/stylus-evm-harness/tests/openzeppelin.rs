@@ -0,0 +1,179 @@
+//! Generated bindings against compiled OpenZeppelin contracts; see `fixtures/README.md`.
+
+use alloy_primitives::{address, keccak256, Address, FixedBytes, U256};
+use alloy_sol_types::SolValue;
+use stylus_evm_harness::{fixture, EvmHarness};
+use stylus_interfaces::{
+    erc1155, erc20, erc721, ierc165,
+    transport::{CallKind, Request, Transport},
+    CallError,
+};
+
+const ALICE: Address = address!("00000000000000000000000000000000000000a1");
+
+fn deploy(evm: &mut EvmHarness, name: &str) -> Address {
+    let code = fixture(name).unwrap_or_else(|| panic!("missing fixture {}.hex", name));
+    evm.deploy(code)
+        .unwrap_or_else(|_| panic!("{} constructor reverted", name))
+}
+
+/// Calls a fixture-only function such as `mint`, which the interface packs don't cover.
+fn call(evm: &EvmHarness, to: Address, signature: &str, args: &[u8]) {
+    let mut calldata = keccak256(signature)[..4].to_vec();
+    calldata.extend_from_slice(args);
+    evm.call(Request {
+        address: to,
+        calldata: &calldata,
+        kind: CallKind::Call,
+        value: U256::ZERO,
+        gas: None,
+        max_return: None,
+    })
+    .unwrap_or_else(|err| panic!("{} failed: {}", signature, err));
+}
+
+fn custom_error(err: CallError, signature: &str) {
+    match err {
+        CallError::Revert { data, reason: None } => {
+            assert_eq!(
+                data[..4],
+                keccak256(signature)[..4],
+                "expected {}",
+                signature
+            )
+        }
+        other => panic!("expected {}, got {:?}", signature, other),
+    }
+}
+
+#[test]
+fn erc20_transfer() {
+    let mut evm = EvmHarness::new();
+    let address = deploy(&mut evm, "erc20");
+    let token = erc20::Contract::with_transport(address, &evm);
+
+    assert_eq!(token.transfer__0xa9059cbb(ALICE, U256::from(250)), Ok(true));
+    assert_eq!(token.balance_of__0x70a08231(ALICE), Ok(U256::from(250)));
+    assert_eq!(
+        token.balance_of__0x70a08231(evm.caller()),
+        Ok(U256::from(750))
+    );
+    assert_eq!(evm.take_logs().len(), 2, "mint and transfer events");
+
+    let err = token
+        .transfer__0xa9059cbb(ALICE, U256::from(751))
+        .unwrap_err();
+    custom_error(err, "ERC20InsufficientBalance(address,uint256,uint256)");
+}
+
+#[test]
+fn erc721_safe_transfer_to_receiver() {
+    let mut evm = EvmHarness::new();
+    let address = deploy(&mut evm, "erc721");
+    let receiver = deploy(&mut evm, "erc721receiver");
+    let caller = evm.caller();
+    call(
+        &evm,
+        address,
+        "mint(address,uint256)",
+        &(caller, U256::from(1)).abi_encode_params(),
+    );
+    let nft = erc721::Contract::with_transport(address, &evm);
+
+    nft.safe_transfer_from__0xb88d4fde(caller, receiver, U256::from(1), b"hi".to_vec())
+        .unwrap();
+    assert_eq!(nft.owner_of__0x6352211e(U256::from(1)), Ok(receiver));
+    assert_eq!(nft.balance_of__0x70a08231(receiver), Ok(U256::from(1)));
+}
+
+#[test]
+fn erc721_safe_transfer_to_non_receiver_reverts() {
+    let mut evm = EvmHarness::new();
+    let address = deploy(&mut evm, "erc721");
+    let not_a_receiver = deploy(&mut evm, "erc20");
+    let caller = evm.caller();
+    call(
+        &evm,
+        address,
+        "mint(address,uint256)",
+        &(caller, U256::from(1)).abi_encode_params(),
+    );
+    let nft = erc721::Contract::with_transport(address, &evm);
+
+    let err = nft
+        .safe_transfer_from__0x42842e0e(caller, not_a_receiver, U256::from(1))
+        .unwrap_err();
+    custom_error(err, "ERC721InvalidReceiver(address)");
+    assert_eq!(nft.owner_of__0x6352211e(U256::from(1)), Ok(caller));
+}
+
+#[test]
+fn erc1155_transfers_and_batch_balances() {
+    let mut evm = EvmHarness::new();
+    let address = deploy(&mut evm, "erc1155");
+    let receiver = deploy(&mut evm, "erc1155receiver");
+    let caller = evm.caller();
+    let signature = "mint(address,uint256,uint256)";
+    call(
+        &evm,
+        address,
+        signature,
+        &(caller, U256::from(1), U256::from(10)).abi_encode_params(),
+    );
+    call(
+        &evm,
+        address,
+        signature,
+        &(caller, U256::from(2), U256::from(20)).abi_encode_params(),
+    );
+    let token = erc1155::Contract::with_transport(address, &evm);
+
+    token
+        .safe_transfer_from__0xf242432a(caller, receiver, U256::from(1), U256::from(4), vec![])
+        .unwrap();
+    token
+        .safe_batch_transfer_from__0x2eb2c2d6(
+            caller,
+            receiver,
+            vec![U256::from(1), U256::from(2)],
+            vec![U256::from(1), U256::from(5)],
+            vec![],
+        )
+        .unwrap();
+    assert_eq!(
+        token.balance_of_batch__0x4e1273f4(
+            vec![caller, receiver, caller, receiver],
+            vec![U256::from(1), U256::from(1), U256::from(2), U256::from(2)],
+        ),
+        Ok(vec![
+            U256::from(5),
+            U256::from(5),
+            U256::from(15),
+            U256::from(5)
+        ])
+    );
+}
+
+#[test]
+fn erc165_reports_token_interfaces() {
+    let mut evm = EvmHarness::new();
+    for (name, interface_id) in [
+        ("erc721", [0x80, 0xac, 0x58, 0xcd]),
+        ("erc1155", [0xd9, 0xb6, 0x7a, 0x26]),
+    ] {
+        let address = deploy(&mut evm, name);
+        let contract = ierc165::Contract::with_transport(address, &evm);
+        assert_eq!(
+            contract.supports_interface__0x01ffc9a7(FixedBytes(interface_id)),
+            Ok(true),
+            "{}",
+            name
+        );
+        assert_eq!(
+            contract.supports_interface__0x01ffc9a7(FixedBytes([0xff; 4])),
+            Ok(false),
+            "{}",
+            name
+        );
+    }
+}
@@ -0,0 +1,116 @@
+//! Spec-compliant ERC-165 interface detection.
+//!
+//! Calling `supportsInterface` on an arbitrary address is not enough: contracts that do not
+//! implement ERC-165 may revert, return nothing, or answer `true` to every query. The EIP's
+//! detection procedure guards against all three, and is what these helpers implement.
+//! They are re-exported from [`crate::ierc165`]; the `_with` variants take any [`Transport`], so
+//! detection can run against simulated contracts.
+
+use crate::transport::{CallKind, Request, StylusTransport, Transport};
+use stylus_sdk::alloy_primitives::{Address, FixedBytes, U256};
+
+/// Interface ID of ERC-165 itself (the `supportsInterface(bytes4)` selector).
+pub const INTERFACE_ID_ERC165: FixedBytes<4> = FixedBytes([0x01, 0xff, 0xc9, 0xa7]);
+
+/// Interface ID that every ERC-165 contract must report as unsupported.
+pub const INTERFACE_ID_INVALID: FixedBytes<4> = FixedBytes([0xff, 0xff, 0xff, 0xff]);
+
+/// Gas stipend for each `supportsInterface` probe, as mandated by the EIP.
+pub const PROBE_GAS: u64 = 30_000;
+
+/// Returns true if `address` implements ERC-165: it answers `true` for `0x01ffc9a7` and
+/// `false` for `0xffffffff`.
+pub fn supports_erc165(address: Address) -> bool {
+    supports_erc165_with(&StylusTransport, address)
+}
+
+/// Returns true if `address` implements ERC-165 and reports support for `interface_id`.
+///
+/// Reverts, short returns and calls to addresses without code all count as unsupported.
+pub fn detect(address: Address, interface_id: FixedBytes<4>) -> bool {
+    detect_with(&StylusTransport, address, interface_id)
+}
+
+/// Returns true if `address` implements ERC-165 and supports every interface in `interface_ids`.
+///
+/// The ERC-165 check runs once for the whole batch.
+pub fn supports_all(address: Address, interface_ids: &[FixedBytes<4>]) -> bool {
+    supports_all_with(&StylusTransport, address, interface_ids)
+}
+
+/// Returns true if `address` implements ERC-165 and supports at least one interface in
+/// `interface_ids`.
+///
+/// Probing stops at the first supported interface, so list the most likely one first.
+pub fn supports_any(address: Address, interface_ids: &[FixedBytes<4>]) -> bool {
+    supports_any_with(&StylusTransport, address, interface_ids)
+}
+
+/// [`supports_erc165`] through `transport`.
+pub fn supports_erc165_with(transport: &impl Transport, address: Address) -> bool {
+    probe(transport, address, INTERFACE_ID_ERC165)
+        && !probe(transport, address, INTERFACE_ID_INVALID)
+}
+
+/// [`detect`] through `transport`.
+pub fn detect_with(
+    transport: &impl Transport,
+    address: Address,
+    interface_id: FixedBytes<4>,
+) -> bool {
+    interface_id != INTERFACE_ID_INVALID
+        && supports_erc165_with(transport, address)
+        && probe(transport, address, interface_id)
+}
+
+/// [`supports_all`] through `transport`.
+pub fn supports_all_with(
+    transport: &impl Transport,
+    address: Address,
+    interface_ids: &[FixedBytes<4>],
+) -> bool {
+    supports_erc165_with(transport, address)
+        && interface_ids
+            .iter()
+            .all(|id| *id != INTERFACE_ID_INVALID && probe(transport, address, *id))
+}
+
+/// [`supports_any`] through `transport`.
+pub fn supports_any_with(
+    transport: &impl Transport,
+    address: Address,
+    interface_ids: &[FixedBytes<4>],
+) -> bool {
+    supports_erc165_with(transport, address)
+        && interface_ids
+            .iter()
+            .any(|id| *id != INTERFACE_ID_INVALID && probe(transport, address, *id))
+}
+
+/// Single `supportsInterface` staticcall with the EIP gas stipend and a 32-byte return cap.
+fn probe(transport: &impl Transport, address: Address, interface_id: FixedBytes<4>) -> bool {
+    returned_true(transport.send(Request {
+        address,
+        calldata: &probe_calldata(interface_id),
+        kind: CallKind::StaticCall,
+        value: U256::ZERO,
+        gas: Some(PROBE_GAS),
+        max_return: Some(32),
+    }))
+}
+
+/// `supportsInterface(interface_id)` calldata: selector followed by the right-padded `bytes4`.
+pub(crate) fn probe_calldata(interface_id: FixedBytes<4>) -> [u8; 36] {
+    let mut calldata = [0u8; 36];
+    calldata[..4].copy_from_slice(INTERFACE_ID_ERC165.as_slice());
+    calldata[4..8].copy_from_slice(interface_id.as_slice());
+    calldata
+}
+
+/// A probe succeeds only if the call did not revert and returned a full, non-zero word.
+pub(crate) fn returned_true(result: Result<Vec<u8>, Vec<u8>>) -> bool {
+    match result {
+        Ok(data) => data.len() >= 32 && data[..32].iter().any(|b| *b != 0),
+        Err(_) => false,
+    }
+}
@@ -1,25 +1,77 @@
 // Generated by stylus-bindgen
 #![allow(clippy::all)]
+use stylus_interfaces::{
+    mock::MockState, transport::CallKind, transport::Request, transport::StylusTransport,
+    transport::Transport, CallError,
+};
 use stylus_sdk::{
     alloy_primitives::{Address, U256},
-    call::RawCall,
+    alloy_sol_types::{sol_data, SolType},
 };
 
-pub struct Contract {
+/// Per-call overrides applied to every call made through a `Contract`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CallOpts {
+    /// Gas forwarded to the callee; all remaining gas when `None`.
+    pub gas: Option<u64>,
+    /// Wei sent with non-view calls. View and pure functions always use STATICCALL.
+    pub value: U256,
+    /// Maximum bytes of return data copied back, guarding against return bombs.
+    pub max_return: Option<usize>,
+}
+
+pub struct Contract<T = StylusTransport> {
     pub address: Address,
+    pub opts: CallOpts,
+    pub transport: T,
 }
 
 impl Contract {
     pub fn new(address: Address) -> Self {
-        Self { address }
+        Self::with_transport(address, StylusTransport)
+    }
+}
+
+impl<T: Transport> Contract<T> {
+    pub fn with_transport(address: Address, transport: T) -> Self {
+        Self {
+            address,
+            opts: CallOpts::default(),
+            transport,
+        }
+    }
+
+    pub fn with(&self, opts: CallOpts) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            address: self.address,
+            opts,
+            transport: self.transport.clone(),
+        }
+    }
+
+    fn send(&self, kind: CallKind, input: &[u8]) -> Result<Vec<u8>, CallError> {
+        self.transport.call(Request {
+            address: self.address,
+            calldata: input,
+            kind,
+            value: if kind == CallKind::Call {
+                self.opts.value
+            } else {
+                U256::ZERO
+            },
+            gas: self.opts.gas,
+            max_return: self.opts.max_return,
+        })
     }
 
     // Original: balanceOf(address,uint256)
-    pub fn balance_of__0x00fdd58e(&self, account: Address, id: U256) -> Result<Vec<u8>, Vec<u8>> {
-        let input = hex::decode("00fdd58e").unwrap();
-        // note: real encoding requires SolType definitions (Milestone 2)
-        let result = unsafe { RawCall::new_static().call(self.address, &input)? };
-        Ok(result)
+    pub fn balance_of__0x00fdd58e(&self, account: Address, id: U256) -> Result<U256, CallError> {
+        let input = encode_balance_of__0x00fdd58e(account, id);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_balance_of__0x00fdd58e_returns)
     }
 
     // Original: balanceOfBatch(address[],uint256[])
@@ -27,11 +79,10 @@ impl Contract {
         &self,
         accounts: Vec<Address>,
         ids: Vec<U256>,
-    ) -> Result<Vec<u8>, Vec<u8>> {
-        let input = hex::decode("4e1273f4").unwrap();
-        // note: real encoding requires SolType definitions (Milestone 2)
-        let result = unsafe { RawCall::new_static().call(self.address, &input)? };
-        Ok(result)
+    ) -> Result<Vec<U256>, CallError> {
+        let input = encode_balance_of_batch__0x4e1273f4(accounts, ids);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_balance_of_batch__0x4e1273f4_returns)
     }
 
     // Original: isApprovedForAll(address,address)
@@ -39,11 +90,10 @@ impl Contract {
         &self,
         account: Address,
         operator: Address,
-    ) -> Result<Vec<u8>, Vec<u8>> {
-        let input = hex::decode("e985e9c5").unwrap();
-        // note: real encoding requires SolType definitions (Milestone 2)
-        let result = unsafe { RawCall::new_static().call(self.address, &input)? };
-        Ok(result)
+    ) -> Result<bool, CallError> {
+        let input = encode_is_approved_for_all__0xe985e9c5(account, operator);
+        let output = self.send(CallKind::StaticCall, &input)?;
+        CallError::decode(output, decode_is_approved_for_all__0xe985e9c5_returns)
     }
 
     // Original: safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)
@@ -54,11 +104,10 @@ impl Contract {
         ids: Vec<U256>,
         values: Vec<U256>,
         data: Vec<u8>,
-    ) -> Result<Vec<u8>, Vec<u8>> {
-        let input = hex::decode("2eb2c2d6").unwrap();
-        // note: real encoding requires SolType definitions (Milestone 2)
-        let result = unsafe { RawCall::new_static().call(self.address, &input)? };
-        Ok(result)
+    ) -> Result<(), CallError> {
+        let input = encode_safe_batch_transfer_from__0x2eb2c2d6(from, to, ids, values, data);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_safe_batch_transfer_from__0x2eb2c2d6_returns)
     }
 
     // Original: safeTransferFrom(address,address,uint256,uint256,bytes)
@@ -69,11 +118,10 @@ impl Contract {
         id: U256,
         value: U256,
         data: Vec<u8>,
-    ) -> Result<Vec<u8>, Vec<u8>> {
-        let input = hex::decode("f242432a").unwrap();
-        // note: real encoding requires SolType definitions (Milestone 2)
-        let result = unsafe { RawCall::new_static().call(self.address, &input)? };
-        Ok(result)
+    ) -> Result<(), CallError> {
+        let input = encode_safe_transfer_from__0xf242432a(from, to, id, value, data);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_safe_transfer_from__0xf242432a_returns)
     }
 
     // Original: setApprovalForAll(address,bool)
@@ -81,10 +129,623 @@ impl Contract {
         &self,
         operator: Address,
         approved: bool,
-    ) -> Result<Vec<u8>, Vec<u8>> {
-        let input = hex::decode("a22cb465").unwrap();
-        // note: real encoding requires SolType definitions (Milestone 2)
-        let result = unsafe { RawCall::new_static().call(self.address, &input)? };
-        Ok(result)
+    ) -> Result<(), CallError> {
+        let input = encode_set_approval_for_all__0xa22cb465(operator, approved);
+        let output = self.send(CallKind::Call, &input)?;
+        CallError::decode(output, decode_set_approval_for_all__0xa22cb465_returns)
+    }
+}
+
+/// Calls of the `Erc1155` interface, so logic can be generic over `Contract` and `MockErc1155`.
+pub trait Erc1155Api {
+    // Original: balanceOf(address,uint256)
+    fn balance_of__0x00fdd58e(&self, account: Address, id: U256) -> Result<U256, CallError>;
+
+    // Original: balanceOfBatch(address[],uint256[])
+    fn balance_of_batch__0x4e1273f4(
+        &self,
+        accounts: Vec<Address>,
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, CallError>;
+
+    // Original: isApprovedForAll(address,address)
+    fn is_approved_for_all__0xe985e9c5(
+        &self,
+        account: Address,
+        operator: Address,
+    ) -> Result<bool, CallError>;
+
+    // Original: safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)
+    fn safe_batch_transfer_from__0x2eb2c2d6(
+        &self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: Vec<u8>,
+    ) -> Result<(), CallError>;
+
+    // Original: safeTransferFrom(address,address,uint256,uint256,bytes)
+    fn safe_transfer_from__0xf242432a(
+        &self,
+        from: Address,
+        to: Address,
+        id: U256,
+        value: U256,
+        data: Vec<u8>,
+    ) -> Result<(), CallError>;
+
+    // Original: setApprovalForAll(address,bool)
+    fn set_approval_for_all__0xa22cb465(
+        &self,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), CallError>;
+}
+
+impl<T: Transport> Erc1155Api for Contract<T> {
+    fn balance_of__0x00fdd58e(&self, account: Address, id: U256) -> Result<U256, CallError> {
+        Contract::balance_of__0x00fdd58e(self, account, id)
+    }
+
+    fn balance_of_batch__0x4e1273f4(
+        &self,
+        accounts: Vec<Address>,
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, CallError> {
+        Contract::balance_of_batch__0x4e1273f4(self, accounts, ids)
+    }
+
+    fn is_approved_for_all__0xe985e9c5(
+        &self,
+        account: Address,
+        operator: Address,
+    ) -> Result<bool, CallError> {
+        Contract::is_approved_for_all__0xe985e9c5(self, account, operator)
+    }
+
+    fn safe_batch_transfer_from__0x2eb2c2d6(
+        &self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: Vec<u8>,
+    ) -> Result<(), CallError> {
+        Contract::safe_batch_transfer_from__0x2eb2c2d6(self, from, to, ids, values, data)
+    }
+
+    fn safe_transfer_from__0xf242432a(
+        &self,
+        from: Address,
+        to: Address,
+        id: U256,
+        value: U256,
+        data: Vec<u8>,
+    ) -> Result<(), CallError> {
+        Contract::safe_transfer_from__0xf242432a(self, from, to, id, value, data)
+    }
+
+    fn set_approval_for_all__0xa22cb465(
+        &self,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), CallError> {
+        Contract::set_approval_for_all__0xa22cb465(self, operator, approved)
+    }
+}
+
+/// Function selectors, named like the methods that call them.
+#[allow(non_upper_case_globals)]
+pub mod selectors {
+    // Original: balanceOf(address,uint256)
+    pub const balance_of__0x00fdd58e: [u8; 4] = [0x00, 0xfd, 0xd5, 0x8e];
+    // Original: balanceOfBatch(address[],uint256[])
+    pub const balance_of_batch__0x4e1273f4: [u8; 4] = [0x4e, 0x12, 0x73, 0xf4];
+    // Original: isApprovedForAll(address,address)
+    pub const is_approved_for_all__0xe985e9c5: [u8; 4] = [0xe9, 0x85, 0xe9, 0xc5];
+    // Original: safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)
+    pub const safe_batch_transfer_from__0x2eb2c2d6: [u8; 4] = [0x2e, 0xb2, 0xc2, 0xd6];
+    // Original: safeTransferFrom(address,address,uint256,uint256,bytes)
+    pub const safe_transfer_from__0xf242432a: [u8; 4] = [0xf2, 0x42, 0x43, 0x2a];
+    // Original: setApprovalForAll(address,bool)
+    pub const set_approval_for_all__0xa22cb465: [u8; 4] = [0xa2, 0x2c, 0xb4, 0x65];
+}
+
+// Original: balanceOf(address,uint256)
+pub fn encode_balance_of__0x00fdd58e(account: Address, id: U256) -> [u8; 68] {
+    let mut input = [0u8; 68];
+    input[..4].copy_from_slice(&selectors::balance_of__0x00fdd58e);
+    input[4..36].copy_from_slice(
+        <sol_data::Address as SolType>::tokenize(&account)
+            .0
+            .as_slice(),
+    );
+    input[36..68].copy_from_slice(<sol_data::Uint<256> as SolType>::tokenize(&id).0.as_slice());
+    input
+}
+
+// Original: balanceOf(address,uint256) returns (uint256)
+pub fn decode_balance_of__0x00fdd58e_returns(
+    data: &[u8],
+) -> Result<U256, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Uint<256>,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+// Original: balanceOfBatch(address[],uint256[])
+pub fn encode_balance_of_batch__0x4e1273f4(accounts: Vec<Address>, ids: Vec<U256>) -> Vec<u8> {
+    let mut input = selectors::balance_of_batch__0x4e1273f4.to_vec();
+    input.extend(<(
+        sol_data::Array<sol_data::Address>,
+        sol_data::Array<sol_data::Uint<256>>,
+    )>::abi_encode_params(&(accounts, ids)));
+    input
+}
+
+// Original: balanceOfBatch(address[],uint256[]) returns (uint256[])
+pub fn decode_balance_of_batch__0x4e1273f4_returns(
+    data: &[u8],
+) -> Result<Vec<U256>, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Array<sol_data::Uint<256>>,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+// Original: isApprovedForAll(address,address)
+pub fn encode_is_approved_for_all__0xe985e9c5(account: Address, operator: Address) -> [u8; 68] {
+    let mut input = [0u8; 68];
+    input[..4].copy_from_slice(&selectors::is_approved_for_all__0xe985e9c5);
+    input[4..36].copy_from_slice(
+        <sol_data::Address as SolType>::tokenize(&account)
+            .0
+            .as_slice(),
+    );
+    input[36..68].copy_from_slice(
+        <sol_data::Address as SolType>::tokenize(&operator)
+            .0
+            .as_slice(),
+    );
+    input
+}
+
+// Original: isApprovedForAll(address,address) returns (bool)
+pub fn decode_is_approved_for_all__0xe985e9c5_returns(
+    data: &[u8],
+) -> Result<bool, stylus_sdk::alloy_sol_types::Error> {
+    <(sol_data::Bool,)>::abi_decode_params(data, true).map(|(value,)| value)
+}
+
+// Original: safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)
+pub fn encode_safe_batch_transfer_from__0x2eb2c2d6(
+    from: Address,
+    to: Address,
+    ids: Vec<U256>,
+    values: Vec<U256>,
+    data: Vec<u8>,
+) -> Vec<u8> {
+    let mut input = selectors::safe_batch_transfer_from__0x2eb2c2d6.to_vec();
+    input.extend(<(
+        sol_data::Address,
+        sol_data::Address,
+        sol_data::Array<sol_data::Uint<256>>,
+        sol_data::Array<sol_data::Uint<256>>,
+        sol_data::Bytes,
+    )>::abi_encode_params(&(from, to, ids, values, data)));
+    input
+}
+
+// Original: safeBatchTransferFrom(address,address,uint256[],uint256[],bytes) returns ()
+pub fn decode_safe_batch_transfer_from__0x2eb2c2d6_returns(
+    data: &[u8],
+) -> Result<(), stylus_sdk::alloy_sol_types::Error> {
+    <()>::abi_decode_params(data, true)
+}
+
+// Original: safeTransferFrom(address,address,uint256,uint256,bytes)
+pub fn encode_safe_transfer_from__0xf242432a(
+    from: Address,
+    to: Address,
+    id: U256,
+    value: U256,
+    data: Vec<u8>,
+) -> Vec<u8> {
+    let mut input = selectors::safe_transfer_from__0xf242432a.to_vec();
+    input.extend(<(
+        sol_data::Address,
+        sol_data::Address,
+        sol_data::Uint<256>,
+        sol_data::Uint<256>,
+        sol_data::Bytes,
+    )>::abi_encode_params(&(from, to, id, value, data)));
+    input
+}
+
+// Original: safeTransferFrom(address,address,uint256,uint256,bytes) returns ()
+pub fn decode_safe_transfer_from__0xf242432a_returns(
+    data: &[u8],
+) -> Result<(), stylus_sdk::alloy_sol_types::Error> {
+    <()>::abi_decode_params(data, true)
+}
+
+// Original: setApprovalForAll(address,bool)
+pub fn encode_set_approval_for_all__0xa22cb465(operator: Address, approved: bool) -> [u8; 68] {
+    let mut input = [0u8; 68];
+    input[..4].copy_from_slice(&selectors::set_approval_for_all__0xa22cb465);
+    input[4..36].copy_from_slice(
+        <sol_data::Address as SolType>::tokenize(&operator)
+            .0
+            .as_slice(),
+    );
+    input[36..68].copy_from_slice(
+        <sol_data::Bool as SolType>::tokenize(&approved)
+            .0
+            .as_slice(),
+    );
+    input
+}
+
+// Original: setApprovalForAll(address,bool) returns ()
+pub fn decode_set_approval_for_all__0xa22cb465_returns(
+    data: &[u8],
+) -> Result<(), stylus_sdk::alloy_sol_types::Error> {
+    <()>::abi_decode_params(data, true)
+}
+
+#[allow(non_camel_case_types, non_snake_case)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Calls {
+    // Original: balanceOf(address,uint256)
+    BalanceOf__0x00fdd58e {
+        account: Address,
+        id: U256,
+    },
+    // Original: balanceOfBatch(address[],uint256[])
+    BalanceOfBatch__0x4e1273f4 {
+        accounts: Vec<Address>,
+        ids: Vec<U256>,
+    },
+    // Original: isApprovedForAll(address,address)
+    IsApprovedForAll__0xe985e9c5 {
+        account: Address,
+        operator: Address,
+    },
+    // Original: safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)
+    SafeBatchTransferFrom__0x2eb2c2d6 {
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: Vec<u8>,
+    },
+    // Original: safeTransferFrom(address,address,uint256,uint256,bytes)
+    SafeTransferFrom__0xf242432a {
+        from: Address,
+        to: Address,
+        id: U256,
+        value: U256,
+        data: Vec<u8>,
+    },
+    // Original: setApprovalForAll(address,bool)
+    SetApprovalForAll__0xa22cb465 {
+        operator: Address,
+        approved: bool,
+    },
+}
+
+impl Calls {
+    pub fn abi_decode(data: &[u8]) -> Result<Self, stylus_sdk::alloy_sol_types::Error> {
+        let Some((selector, args)) = data.split_first_chunk::<4>() else {
+            return Err(stylus_sdk::alloy_sol_types::Error::Overrun);
+        };
+        match *selector {
+            selectors::balance_of__0x00fdd58e => {
+                let (account, id) =
+                    <(sol_data::Address, sol_data::Uint<256>)>::abi_decode_params(args, true)?;
+                Ok(Self::BalanceOf__0x00fdd58e { account, id })
+            }
+            selectors::balance_of_batch__0x4e1273f4 => {
+                let (accounts, ids) = <(
+                    sol_data::Array<sol_data::Address>,
+                    sol_data::Array<sol_data::Uint<256>>,
+                )>::abi_decode_params(args, true)?;
+                Ok(Self::BalanceOfBatch__0x4e1273f4 { accounts, ids })
+            }
+            selectors::is_approved_for_all__0xe985e9c5 => {
+                let (account, operator) =
+                    <(sol_data::Address, sol_data::Address)>::abi_decode_params(args, true)?;
+                Ok(Self::IsApprovedForAll__0xe985e9c5 { account, operator })
+            }
+            selectors::safe_batch_transfer_from__0x2eb2c2d6 => {
+                let (from, to, ids, values, data) =
+                    <(
+                        sol_data::Address,
+                        sol_data::Address,
+                        sol_data::Array<sol_data::Uint<256>>,
+                        sol_data::Array<sol_data::Uint<256>>,
+                        sol_data::Bytes,
+                    )>::abi_decode_params(args, true)?;
+                Ok(Self::SafeBatchTransferFrom__0x2eb2c2d6 {
+                    from,
+                    to,
+                    ids,
+                    values,
+                    data: data.into(),
+                })
+            }
+            selectors::safe_transfer_from__0xf242432a => {
+                let (from, to, id, value, data) = <(
+                    sol_data::Address,
+                    sol_data::Address,
+                    sol_data::Uint<256>,
+                    sol_data::Uint<256>,
+                    sol_data::Bytes,
+                )>::abi_decode_params(args, true)?;
+                Ok(Self::SafeTransferFrom__0xf242432a {
+                    from,
+                    to,
+                    id,
+                    value,
+                    data: data.into(),
+                })
+            }
+            selectors::set_approval_for_all__0xa22cb465 => {
+                let (operator, approved) =
+                    <(sol_data::Address, sol_data::Bool)>::abi_decode_params(args, true)?;
+                Ok(Self::SetApprovalForAll__0xa22cb465 { operator, approved })
+            }
+            _ => Err(stylus_sdk::alloy_sol_types::Error::unknown_selector(
+                "Calls", *selector,
+            )),
+        }
+    }
+
+    pub fn abi_encode(&self) -> Vec<u8> {
+        match self.clone() {
+            Self::BalanceOf__0x00fdd58e { account, id } => {
+                encode_balance_of__0x00fdd58e(account, id).to_vec()
+            }
+            Self::BalanceOfBatch__0x4e1273f4 { accounts, ids } => {
+                encode_balance_of_batch__0x4e1273f4(accounts, ids)
+            }
+            Self::IsApprovedForAll__0xe985e9c5 { account, operator } => {
+                encode_is_approved_for_all__0xe985e9c5(account, operator).to_vec()
+            }
+            Self::SafeBatchTransferFrom__0x2eb2c2d6 {
+                from,
+                to,
+                ids,
+                values,
+                data,
+            } => encode_safe_batch_transfer_from__0x2eb2c2d6(from, to, ids, values, data),
+            Self::SafeTransferFrom__0xf242432a {
+                from,
+                to,
+                id,
+                value,
+                data,
+            } => encode_safe_transfer_from__0xf242432a(from, to, id, value, data),
+            Self::SetApprovalForAll__0xa22cb465 { operator, approved } => {
+                encode_set_approval_for_all__0xa22cb465(operator, approved).to_vec()
+            }
+        }
+    }
+
+    pub fn selector(&self) -> [u8; 4] {
+        match self {
+            Self::BalanceOf__0x00fdd58e { .. } => selectors::balance_of__0x00fdd58e,
+            Self::BalanceOfBatch__0x4e1273f4 { .. } => selectors::balance_of_batch__0x4e1273f4,
+            Self::IsApprovedForAll__0xe985e9c5 { .. } => selectors::is_approved_for_all__0xe985e9c5,
+            Self::SafeBatchTransferFrom__0x2eb2c2d6 { .. } => {
+                selectors::safe_batch_transfer_from__0x2eb2c2d6
+            }
+            Self::SafeTransferFrom__0xf242432a { .. } => selectors::safe_transfer_from__0xf242432a,
+            Self::SetApprovalForAll__0xa22cb465 { .. } => {
+                selectors::set_approval_for_all__0xa22cb465
+            }
+        }
+    }
+}
+
+impl core::fmt::Display for Calls {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BalanceOf__0x00fdd58e { account, id } => {
+                write!(f, "balanceOf(account={}, id={})", account, id)
+            }
+            Self::BalanceOfBatch__0x4e1273f4 { accounts, ids } => write!(
+                f,
+                "balanceOfBatch(accounts=[{}], ids=[{}])",
+                accounts
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                ids.iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::IsApprovedForAll__0xe985e9c5 { account, operator } => write!(
+                f,
+                "isApprovedForAll(account={}, operator={})",
+                account, operator
+            ),
+            Self::SafeBatchTransferFrom__0x2eb2c2d6 {
+                from,
+                to,
+                ids,
+                values,
+                data,
+            } => write!(
+                f,
+                "safeBatchTransferFrom(from={}, to={}, ids=[{}], values=[{}], data=0x{})",
+                from,
+                to,
+                ids.iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                values
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                stylus_sdk::alloy_primitives::hex::encode(data)
+            ),
+            Self::SafeTransferFrom__0xf242432a {
+                from,
+                to,
+                id,
+                value,
+                data,
+            } => write!(
+                f,
+                "safeTransferFrom(from={}, to={}, id={}, value={}, data=0x{})",
+                from,
+                to,
+                id,
+                value,
+                stylus_sdk::alloy_primitives::hex::encode(data)
+            ),
+            Self::SetApprovalForAll__0xa22cb465 { operator, approved } => write!(
+                f,
+                "setApprovalForAll(operator={}, approved={})",
+                operator, approved
+            ),
+        }
+    }
+}
+
+/// In-memory `Erc1155Api` for native unit tests: configurable responses and a log of calls.
+///
+/// Responses are raw return data, decoded exactly like a real `Contract` call.
+#[derive(Default)]
+pub struct MockErc1155 {
+    pub state: MockState<Calls>,
+}
+
+impl MockErc1155 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every call made so far, oldest first.
+    pub fn calls(&self) -> Vec<Calls> {
+        self.state.calls()
+    }
+
+    /// Makes calls to `selector` fail with `error` until replaced.
+    pub fn fail(&self, selector: [u8; 4], error: CallError) {
+        self.state.respond(selector, Err(error));
+    }
+
+    /// Makes `balanceOf(address,uint256)` return `value` until replaced.
+    pub fn returns_balance_of__0x00fdd58e(&self, value: U256) {
+        self.state.respond(
+            selectors::balance_of__0x00fdd58e,
+            Ok(<(sol_data::Uint<256>,)>::abi_encode_params(&(value,))),
+        );
+    }
+
+    /// Makes `balanceOfBatch(address[],uint256[])` return `value` until replaced.
+    pub fn returns_balance_of_batch__0x4e1273f4(&self, value: Vec<U256>) {
+        self.state.respond(
+            selectors::balance_of_batch__0x4e1273f4,
+            Ok(<(sol_data::Array<sol_data::Uint<256>>,)>::abi_encode_params(&(value,))),
+        );
+    }
+
+    /// Makes `isApprovedForAll(address,address)` return `value` until replaced.
+    pub fn returns_is_approved_for_all__0xe985e9c5(&self, value: bool) {
+        self.state.respond(
+            selectors::is_approved_for_all__0xe985e9c5,
+            Ok(<(sol_data::Bool,)>::abi_encode_params(&(value,))),
+        );
+    }
+}
+
+impl Erc1155Api for MockErc1155 {
+    fn balance_of__0x00fdd58e(&self, account: Address, id: U256) -> Result<U256, CallError> {
+        let output = self.state.record(
+            selectors::balance_of__0x00fdd58e,
+            Calls::BalanceOf__0x00fdd58e { account, id },
+        )?;
+        CallError::decode(output, decode_balance_of__0x00fdd58e_returns)
+    }
+
+    fn balance_of_batch__0x4e1273f4(
+        &self,
+        accounts: Vec<Address>,
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, CallError> {
+        let output = self.state.record(
+            selectors::balance_of_batch__0x4e1273f4,
+            Calls::BalanceOfBatch__0x4e1273f4 { accounts, ids },
+        )?;
+        CallError::decode(output, decode_balance_of_batch__0x4e1273f4_returns)
+    }
+
+    fn is_approved_for_all__0xe985e9c5(
+        &self,
+        account: Address,
+        operator: Address,
+    ) -> Result<bool, CallError> {
+        let output = self.state.record(
+            selectors::is_approved_for_all__0xe985e9c5,
+            Calls::IsApprovedForAll__0xe985e9c5 { account, operator },
+        )?;
+        CallError::decode(output, decode_is_approved_for_all__0xe985e9c5_returns)
+    }
+
+    fn safe_batch_transfer_from__0x2eb2c2d6(
+        &self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: Vec<u8>,
+    ) -> Result<(), CallError> {
+        let output = self.state.record(
+            selectors::safe_batch_transfer_from__0x2eb2c2d6,
+            Calls::SafeBatchTransferFrom__0x2eb2c2d6 {
+                from,
+                to,
+                ids,
+                values,
+                data,
+            },
+        )?;
+        CallError::decode(output, decode_safe_batch_transfer_from__0x2eb2c2d6_returns)
+    }
+
+    fn safe_transfer_from__0xf242432a(
+        &self,
+        from: Address,
+        to: Address,
+        id: U256,
+        value: U256,
+        data: Vec<u8>,
+    ) -> Result<(), CallError> {
+        let output = self.state.record(
+            selectors::safe_transfer_from__0xf242432a,
+            Calls::SafeTransferFrom__0xf242432a {
+                from,
+                to,
+                id,
+                value,
+                data,
+            },
+        )?;
+        CallError::decode(output, decode_safe_transfer_from__0xf242432a_returns)
+    }
+
+    fn set_approval_for_all__0xa22cb465(
+        &self,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), CallError> {
+        let output = self.state.record(
+            selectors::set_approval_for_all__0xa22cb465,
+            Calls::SetApprovalForAll__0xa22cb465 { operator, approved },
+        )?;
+        CallError::decode(output, decode_set_approval_for_all__0xa22cb465_returns)
     }
 }
@@ -0,0 +1,114 @@
+//! Error type returned by every generated binding call.
+
+use core::fmt;
+use stylus_sdk::{
+    alloy_primitives::{hex, Address},
+    alloy_sol_types::{self, decode_revert_reason, Revert, SolError},
+    types::AddressVM,
+};
+
+/// Why a call made through a generated binding failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CallError {
+    /// The callee reverted with data. `reason` holds the decoded message for `Error(string)`,
+    /// `Panic(uint256)` and plain UTF-8 reverts; custom errors leave it `None`.
+    Revert {
+        data: Vec<u8>,
+        reason: Option<String>,
+    },
+    /// The callee reverted without data, e.g. `revert()`, `require(cond)` or out of gas.
+    EmptyRevert,
+    /// The call succeeded but the return data is too short or does not decode as the outputs.
+    MalformedReturn(Vec<u8>),
+    /// The target has no code, so the call "succeeded" without executing anything.
+    NoCode(Address),
+}
+
+impl CallError {
+    /// Classifies a raw call result, returning the return data on success.
+    ///
+    /// The target's code size is only queried when the call returned no data.
+    pub fn check(address: Address, result: Result<Vec<u8>, Vec<u8>>) -> Result<Vec<u8>, Self> {
+        Self::check_with(address, result, || address.has_code())
+    }
+
+    /// Like [`check`](Self::check), but asks `has_code` whether the target has code.
+    pub fn check_with(
+        address: Address,
+        result: Result<Vec<u8>, Vec<u8>>,
+        has_code: impl FnOnce() -> bool,
+    ) -> Result<Vec<u8>, Self> {
+        match result {
+            Ok(data) if data.is_empty() && !has_code() => Err(Self::NoCode(address)),
+            Ok(data) => Ok(data),
+            Err(data) if data.is_empty() => Err(Self::EmptyRevert),
+            Err(data) => Err(Self::Revert {
+                reason: revert_reason(&data),
+                data,
+            }),
+        }
+    }
+
+    /// Decodes return data with a generated `decode_*_returns` function.
+    pub fn decode<T>(
+        data: Vec<u8>,
+        decode: impl FnOnce(&[u8]) -> Result<T, alloy_sol_types::Error>,
+    ) -> Result<T, Self> {
+        decode(&data).map_err(|_| Self::MalformedReturn(data))
+    }
+
+    /// Wraps return data with a generated lazy `decode_*_returns` function.
+    pub fn decode_lazy<T>(
+        data: Vec<u8>,
+        decode: impl FnOnce(Vec<u8>) -> Result<T, Vec<u8>>,
+    ) -> Result<T, Self> {
+        decode(data).map_err(Self::MalformedReturn)
+    }
+
+    /// Decoded revert reason, if the callee reverted with a recognizable one.
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Revert { reason, .. } => reason.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// `Error(string)` messages verbatim; `Panic(uint256)` and UTF-8 data as alloy describes them.
+fn revert_reason(data: &[u8]) -> Option<String> {
+    match Revert::abi_decode(data, true) {
+        Ok(revert) => Some(revert.reason),
+        Err(_) => decode_revert_reason(data),
+    }
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Revert {
+                reason: Some(reason),
+                ..
+            } => write!(f, "call reverted: {}", reason),
+            Self::Revert { data, .. } => write!(f, "call reverted: 0x{}", hex::encode(data)),
+            Self::EmptyRevert => f.write_str("call reverted without data"),
+            Self::MalformedReturn(data) => {
+                write!(f, "malformed return data ({} bytes)", data.len())
+            }
+            Self::NoCode(address) => write!(f, "call to {} which has no code", address),
+        }
+    }
+}
+
+/// Revert data for `#[public]` methods bubbling a `CallError` up with `?`.
+///
+/// Callee reverts propagate unchanged; local failures become `Error(string)` reverts.
+impl From<CallError> for Vec<u8> {
+    fn from(err: CallError) -> Vec<u8> {
+        match err {
+            CallError::Revert { data, .. } => data,
+            CallError::EmptyRevert => Vec::new(),
+            CallError::MalformedReturn(_) => Revert::from("malformed return data").abi_encode(),
+            CallError::NoCode(_) => Revert::from("call to address without code").abi_encode(),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! The packs' `Contract`s as parameter types: stylus-bindgen uses them for parameters whose
+//! `internalType` is `contract IERC20` and the like. They encode as their address, like the
+//! `address` they replace, and decode into a handle on the default transport.
+
+use core::borrow::Borrow;
+use core::fmt;
+use stylus_sdk::alloy_primitives::Address;
+
+macro_rules! contract_param {
+    ($($module:ident),*) => {$(
+        impl<T> Borrow<[u8; 20]> for crate::$module::Contract<T> {
+            fn borrow(&self) -> &[u8; 20] {
+                self.address.borrow()
+            }
+        }
+
+        impl From<Address> for crate::$module::Contract {
+            fn from(address: Address) -> Self {
+                Self::new(address)
+            }
+        }
+
+        impl<T: Clone> Clone for crate::$module::Contract<T> {
+            fn clone(&self) -> Self {
+                Self {
+                    address: self.address,
+                    opts: self.opts,
+                    transport: self.transport.clone(),
+                }
+            }
+        }
+
+        impl<T: fmt::Debug> fmt::Debug for crate::$module::Contract<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct("Contract")
+                    .field("address", &self.address)
+                    .field("opts", &self.opts)
+                    .field("transport", &self.transport)
+                    .finish()
+            }
+        }
+
+        impl<T: PartialEq> PartialEq for crate::$module::Contract<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.address == other.address
+                    && self.opts == other.opts
+                    && self.transport == other.transport
+            }
+        }
+
+        impl<T: Eq> Eq for crate::$module::Contract<T> {}
+    )*};
+}
+
+contract_param!(erc20, erc721, erc1155, ierc165);
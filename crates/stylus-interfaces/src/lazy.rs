@@ -0,0 +1,101 @@
+//! Zero-copy views over return data, used by functions generated with `--lazy`.
+//!
+//! Eagerly decoding a `uint256[]` of hundreds of words allocates a `Vec<U256>` and touches every
+//! word up front. [`ReturnData`] keeps the raw return buffer instead and decodes outputs on
+//! access; [`ArrayView`] offers indexed access and iteration over a dynamic array in place.
+
+use core::marker::PhantomData;
+use stylus_sdk::alloy_sol_types::{abi::Decoder, Error, SolType};
+
+/// Raw return data of a call, decoded one output at a time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReturnData {
+    data: Vec<u8>,
+}
+
+impl ReturnData {
+    /// Wraps `data` if it is long enough to hold `head_words` output head words.
+    ///
+    /// Gives the buffer back when it is too short.
+    pub fn new(data: Vec<u8>, head_words: usize) -> Result<Self, Vec<u8>> {
+        if data.len() < 32 * head_words {
+            return Err(data);
+        }
+        Ok(Self { data })
+    }
+
+    /// Decodes the output whose head is word `slot`.
+    pub fn value<T: SolType>(&self, slot: usize) -> Result<T::RustType, Error> {
+        decode_at::<T>(&self.data, 32 * slot)
+    }
+
+    /// View over the dynamic array output whose head is word `slot`.
+    pub fn array<T: SolType>(&self, slot: usize) -> Result<ArrayView<'_, T>, Error> {
+        let mut decoder = Decoder::new(&self.data, true);
+        decoder.set_offset(32 * slot);
+        let mut array = decoder.take_indirection()?;
+        let len = array.take_offset()?;
+        let elements = array.remaining_buf().ok_or(Error::Overrun)?;
+        let head_size = T::ENCODED_SIZE.unwrap_or(32);
+        if !matches!(len.checked_mul(head_size), Some(size) if size <= elements.len()) {
+            return Err(Error::Overrun);
+        }
+        Ok(ArrayView {
+            elements,
+            len,
+            _element: PhantomData,
+        })
+    }
+
+    /// The undecoded return data.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+/// Dynamic array borrowed from [`ReturnData`]; elements are decoded on access.
+pub struct ArrayView<'a, T: SolType> {
+    elements: &'a [u8],
+    len: usize,
+    _element: PhantomData<T>,
+}
+
+impl<'a, T: SolType> ArrayView<'a, T> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Decodes element `index`, or fails with `Overrun` past the end.
+    pub fn get(&self, index: usize) -> Result<T::RustType, Error> {
+        if index >= self.len {
+            return Err(Error::Overrun);
+        }
+        decode_at::<T>(self.elements, T::ENCODED_SIZE.unwrap_or(32) * index)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Result<T::RustType, Error>> + '_ {
+        (0..self.len).map(move |index| self.get(index))
+    }
+
+    /// Decodes every element, like the eager decoder would.
+    pub fn to_vec(&self) -> Result<Vec<T::RustType>, Error> {
+        self.iter().collect()
+    }
+}
+
+/// Decodes a `T` whose head starts `head` bytes into `buf`; offsets are relative to `buf`.
+fn decode_at<T: SolType>(buf: &[u8], head: usize) -> Result<T::RustType, Error> {
+    let mut decoder = Decoder::new(buf, true);
+    decoder.set_offset(head);
+    let token = decoder.decode::<T::Token<'_>>()?;
+    T::type_check(&token)?;
+    Ok(T::detokenize(token))
+}
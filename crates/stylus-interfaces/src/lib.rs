@@ -7,15 +7,47 @@
 //! - IERC165 (interface detection)
 
 #![allow(non_snake_case)] // selector-suffixed names e.g. approve__0x095ea7b3 are intentional
-#![allow(unused_variables)] // params not yet encoded (Milestone 2); kept for API clarity
 
+// Generated packs name `stylus_interfaces::CallError`, like downstream generated code does.
+extern crate self as stylus_interfaces;
+
+mod detection;
 pub mod erc1155;
 pub mod erc20;
 pub mod erc721;
-pub mod ierc165;
+mod error;
+mod handles;
+#[path = "ierc165.rs"]
+mod ierc165_bindings;
+pub mod lazy;
+pub mod mock;
+pub mod transport;
+
+pub use error::CallError;
+
+/// IERC165 bindings and spec-compliant interface detection.
+pub mod ierc165 {
+    pub use crate::detection::{
+        detect, detect_with, supports_all, supports_all_with, supports_any, supports_any_with,
+        supports_erc165, supports_erc165_with,
+    };
+    pub use crate::ierc165_bindings::*;
+}
 
 #[cfg(test)]
 mod tests {
+    /// Generated functions without a selector suffix: constructors, options, `Calls` and mock helpers.
+    const UNSUFFIXED_FNS: &[&str] = &[
+        "new",
+        "with_transport",
+        "with",
+        "abi_decode",
+        "abi_encode",
+        "selector",
+        "calls",
+        "fail",
+    ];
+
     fn sources() -> &'static [(&'static str, &'static str)] {
         &[
             ("erc20", include_str!("erc20.rs")),
@@ -49,14 +81,64 @@ mod tests {
         }
     }
 
+    mod contract_params {
+        use stylus_sdk::alloy_primitives::Address;
+        use stylus_sdk::alloy_sol_types::{sol_data, SolType};
+
+        #[test]
+        fn contracts_encode_as_their_address() {
+            let address = Address::repeat_byte(0x11);
+            let token = crate::erc20::Contract::from(address);
+            assert_eq!(
+                <sol_data::Address as SolType>::abi_encode(&token),
+                <sol_data::Address as SolType>::abi_encode(&address)
+            );
+            assert_eq!(token.clone(), crate::erc20::Contract::new(address));
+        }
+    }
+
+    mod call_opts {
+        use stylus_sdk::alloy_primitives::{Address, U256};
+
+        #[test]
+        fn default_opts_are_unrestricted() {
+            let contract = crate::erc721::Contract::new(Address::ZERO);
+            assert_eq!(contract.opts, crate::erc721::CallOpts::default());
+            assert_eq!(contract.opts.gas, None);
+            assert_eq!(contract.opts.value, U256::ZERO);
+            assert_eq!(contract.opts.max_return, None);
+        }
+
+        #[test]
+        fn with_keeps_address_and_sets_opts() {
+            let address = Address::repeat_byte(0x11);
+            let opts = crate::erc721::CallOpts {
+                gas: Some(50_000),
+                value: U256::from(1),
+                max_return: Some(64),
+            };
+            let base = crate::erc721::Contract::new(address);
+            let configured = base.with(opts);
+            assert_eq!(configured.address, address);
+            assert_eq!(configured.opts, opts);
+            assert_eq!(base.opts, crate::erc721::CallOpts::default());
+        }
+    }
+
     mod erc20 {
         #[test]
         fn has_all_expected_functions() {
             let src = include_str!("erc20.rs");
             let expected = [
+                ("allowance__0xdd62ed3e", "allowance(address,address)"),
                 ("approve__0x095ea7b3", "approve(address,uint256)"),
                 ("balance_of__0x70a08231", "balanceOf(address)"),
+                ("total_supply__0x18160ddd", "totalSupply()"),
                 ("transfer__0xa9059cbb", "transfer(address,uint256)"),
+                (
+                    "transfer_from__0x23b872dd",
+                    "transferFrom(address,address,uint256)",
+                ),
             ];
             for (fn_name, sig) in expected {
                 assert!(
@@ -69,32 +151,31 @@ mod tests {
         }
 
         #[test]
-        fn has_exactly_3_functions() {
+        fn has_exactly_6_functions() {
             let src = include_str!("erc20.rs");
             let fn_count = src.matches("pub fn ").count();
-            // 3 ERC20 functions + 1 new() constructor = 4
+            // 6 ERC20 functions x (method, encoder, decoder) + new() + with() + 3 Calls methods
+            // + with_transport() + mock new(), calls(), fail() + 6 returns_* setters = 33
             assert_eq!(
-                fn_count, 4,
-                "ERC20 must have exactly 3 selector-suffixed functions plus new()"
+                fn_count, 33,
+                "ERC20 must have exactly 6 selector-suffixed functions with encoders/decoders plus new(), with(), Calls and the mock"
             );
         }
 
         #[test]
         fn selectors_are_correct() {
-            let src = include_str!("erc20.rs");
-            assert!(
-                src.contains("fn approve__0x095ea7b3") && src.contains("hex::decode(\"095ea7b3\")"),
-                "approve selector mismatch"
-            );
-            assert!(
-                src.contains("fn balance_of__0x70a08231")
-                    && src.contains("hex::decode(\"70a08231\")"),
-                "balanceOf selector mismatch"
+            use crate::erc20::selectors;
+            assert_eq!(selectors::approve__0x095ea7b3, [0x09, 0x5e, 0xa7, 0xb3]);
+            assert_eq!(selectors::balance_of__0x70a08231, [0x70, 0xa0, 0x82, 0x31]);
+            assert_eq!(selectors::transfer__0xa9059cbb, [0xa9, 0x05, 0x9c, 0xbb]);
+            assert_eq!(
+                selectors::transfer_from__0x23b872dd,
+                [0x23, 0xb8, 0x72, 0xdd]
             );
-            assert!(
-                src.contains("fn transfer__0xa9059cbb")
-                    && src.contains("hex::decode(\"a9059cbb\")"),
-                "transfer selector mismatch"
+            assert_eq!(selectors::allowance__0xdd62ed3e, [0xdd, 0x62, 0xed, 0x3e]);
+            assert_eq!(
+                selectors::total_supply__0x18160ddd,
+                [0x18, 0x16, 0x0d, 0xdd]
             );
         }
     }
@@ -143,10 +224,11 @@ mod tests {
         fn has_exactly_9_functions() {
             let src = include_str!("erc721.rs");
             let fn_count = src.matches("pub fn ").count();
-            // 9 ERC721 functions + 1 new() = 10
+            // 9 ERC721 functions x (method, encoder, decoder) + new() + with() + 3 Calls methods
+            // + with_transport() + mock new(), calls(), fail() + 4 returns_* setters = 40
             assert_eq!(
-                fn_count, 10,
-                "ERC721 must have exactly 9 selector-suffixed functions plus new()"
+                fn_count, 40,
+                "ERC721 must have exactly 9 selector-suffixed functions with encoders/decoders plus new(), with(), Calls and the mock"
             );
         }
 
@@ -167,8 +249,12 @@ mod tests {
         fn overloads_have_distinct_selectors() {
             assert_ne!("0x42842e0e", "0xb88d4fde", "sanity: selectors must differ");
             let src = include_str!("erc721.rs");
-            let count_3arg = src.matches("fn safe_transfer_from__0x42842e0e(").count();
-            let count_4arg = src.matches("fn safe_transfer_from__0xb88d4fde(").count();
+            let count_3arg = src
+                .matches("pub fn safe_transfer_from__0x42842e0e(")
+                .count();
+            let count_4arg = src
+                .matches("pub fn safe_transfer_from__0xb88d4fde(")
+                .count();
             assert_eq!(
                 count_3arg, 1,
                 "safeTransferFrom(3-arg) must be defined exactly once"
@@ -266,10 +352,11 @@ mod tests {
         fn has_exactly_6_functions() {
             let src = include_str!("erc1155.rs");
             let fn_count = src.matches("pub fn ").count();
-            // 6 ERC1155 functions + 1 new() = 7
+            // 6 ERC1155 functions x (method, encoder, decoder) + new() + with() + 3 Calls methods
+            // + with_transport() + mock new(), calls(), fail() + 3 returns_* setters = 30
             assert_eq!(
-                fn_count, 7,
-                "ERC1155 must have exactly 6 selector-suffixed functions plus new()"
+                fn_count, 30,
+                "ERC1155 must have exactly 6 selector-suffixed functions with encoders/decoders plus new(), with(), Calls and the mock"
             );
         }
     }
@@ -288,16 +375,588 @@ mod tests {
         fn has_exactly_1_function() {
             let src = include_str!("ierc165.rs");
             let fn_count = src.matches("pub fn ").count();
-            // 1 IERC165 function + 1 new() = 2
+            // 1 IERC165 function x (method, encoder, decoder) + new() + with() + 3 Calls methods
+            // + with_transport() + mock new(), calls(), fail() + 1 returns_* setter = 13
+            assert_eq!(
+                fn_count, 13,
+                "IERC165 must have exactly 1 selector-suffixed function with encoder/decoder plus new(), with(), Calls and the mock"
+            );
+        }
+    }
+
+    mod detection {
+        use crate::detection::{probe_calldata, returned_true, INTERFACE_ID_INVALID};
+        use stylus_sdk::alloy_primitives::FixedBytes;
+
+        #[test]
+        fn probe_calldata_is_selector_plus_padded_id() {
+            let calldata = probe_calldata(FixedBytes([0x80, 0xac, 0x58, 0xcd]));
+            assert_eq!(&calldata[..4], &[0x01, 0xff, 0xc9, 0xa7]);
+            assert_eq!(&calldata[4..8], &[0x80, 0xac, 0x58, 0xcd]);
+            assert!(calldata[8..].iter().all(|b| *b == 0));
+        }
+
+        #[test]
+        fn probe_calldata_for_invalid_id() {
+            let calldata = probe_calldata(INTERFACE_ID_INVALID);
+            assert_eq!(&calldata[4..8], &[0xff; 4]);
+        }
+
+        #[test]
+        fn true_word_is_supported() {
+            let mut word = vec![0u8; 32];
+            word[31] = 1;
+            assert!(returned_true(Ok(word)));
+        }
+
+        #[test]
+        fn false_word_is_unsupported() {
+            assert!(!returned_true(Ok(vec![0u8; 32])));
+        }
+
+        #[test]
+        fn short_return_is_unsupported() {
+            assert!(!returned_true(Ok(vec![])));
+            assert!(!returned_true(Ok(vec![1u8; 31])));
+        }
+
+        #[test]
+        fn revert_is_unsupported() {
+            let mut word = vec![0u8; 32];
+            word[31] = 1;
+            assert!(!returned_true(Err(word)));
+            assert!(!returned_true(Err(vec![])));
+        }
+
+        mod end_to_end {
+            use crate::detection::PROBE_GAS;
+            use crate::ierc165::{
+                detect_with, supports_all_with, supports_any_with, supports_erc165_with,
+            };
+            use crate::transport::{CallKind, InProcessTransport, Request};
+            use stylus_sdk::alloy_primitives::{address, Address, FixedBytes};
+            use stylus_sdk::alloy_sol_types::SolValue;
+
+            const TOKEN: Address = address!("00000000000000000000000000000000000000d1");
+            const ERC721: FixedBytes<4> = FixedBytes([0x80, 0xac, 0x58, 0xcd]);
+            const ERC1155: FixedBytes<4> = FixedBytes([0xd9, 0xb6, 0x7a, 0x26]);
+
+            /// Transport with `answer` deployed at `TOKEN`, called with the queried interface ID.
+            fn deploy(
+                answer: impl Fn(FixedBytes<4>) -> Result<Vec<u8>, Vec<u8>> + 'static,
+            ) -> InProcessTransport {
+                let mut transport = InProcessTransport::new();
+                transport.register(TOKEN, move |_, request: Request<'_>| {
+                    assert_eq!(request.kind, CallKind::StaticCall);
+                    assert_eq!(request.gas, Some(PROBE_GAS));
+                    assert_eq!(request.max_return, Some(32));
+                    answer(FixedBytes::from_slice(&request.calldata[4..8]))
+                });
+                transport
+            }
+
+            #[test]
+            fn erc165_contract_reports_its_interfaces() {
+                let transport = deploy(|id| {
+                    Ok((id == FixedBytes([0x01, 0xff, 0xc9, 0xa7]) || id == ERC721).abi_encode())
+                });
+                assert!(supports_erc165_with(&transport, TOKEN));
+                assert!(detect_with(&transport, TOKEN, ERC721));
+                assert!(!detect_with(&transport, TOKEN, ERC1155));
+                assert!(!detect_with(&transport, TOKEN, FixedBytes([0xff; 4])));
+                assert!(supports_all_with(&transport, TOKEN, &[ERC721]));
+                assert!(!supports_all_with(&transport, TOKEN, &[ERC721, ERC1155]));
+                assert!(supports_any_with(&transport, TOKEN, &[ERC1155, ERC721]));
+            }
+
+            #[test]
+            fn reverting_contract_supports_nothing() {
+                let transport = deploy(|_| Err(Vec::new()));
+                assert!(!supports_erc165_with(&transport, TOKEN));
+                assert!(!detect_with(&transport, TOKEN, ERC721));
+            }
+
+            #[test]
+            fn short_return_supports_nothing() {
+                let transport = deploy(|_| Ok(vec![1]));
+                assert!(!supports_erc165_with(&transport, TOKEN));
+                assert!(!supports_any_with(&transport, TOKEN, &[ERC721]));
+            }
+
+            #[test]
+            fn always_true_contract_supports_nothing() {
+                let transport = deploy(|_| Ok(true.abi_encode()));
+                assert!(!supports_erc165_with(&transport, TOKEN));
+                assert!(!detect_with(&transport, TOKEN, ERC721));
+                assert!(!supports_all_with(&transport, TOKEN, &[ERC721]));
+            }
+
+            #[test]
+            fn address_without_code_supports_nothing() {
+                let transport = InProcessTransport::new();
+                assert!(!detect_with(&transport, TOKEN, ERC721));
+            }
+        }
+    }
+
+    mod call_error {
+        use crate::erc20;
+        use crate::CallError;
+        use stylus_sdk::alloy_primitives::{address, Address, U256};
+        use stylus_sdk::alloy_sol_types::{Revert, SolError};
+
+        const TOKEN: Address = address!("00000000000000000000000000000000000000aa");
+
+        fn classify(
+            result: Result<Vec<u8>, Vec<u8>>,
+            has_code: bool,
+        ) -> Result<Vec<u8>, CallError> {
+            CallError::check_with(TOKEN, result, || has_code)
+        }
+
+        #[test]
+        fn return_data_passes_through() {
+            assert_eq!(classify(Ok(vec![1, 2, 3]), false), Ok(vec![1, 2, 3]));
+        }
+
+        #[test]
+        fn empty_return_from_contract_is_ok() {
+            assert_eq!(classify(Ok(vec![]), true), Ok(vec![]));
+        }
+
+        #[test]
+        fn empty_return_without_code_is_no_code() {
+            assert_eq!(classify(Ok(vec![]), false), Err(CallError::NoCode(TOKEN)));
+        }
+
+        #[test]
+        fn empty_revert() {
+            assert_eq!(classify(Err(vec![]), true), Err(CallError::EmptyRevert));
+        }
+
+        #[test]
+        fn error_string_revert_has_reason() {
+            let data = Revert::from("insufficient balance").abi_encode();
+            let err = classify(Err(data.clone()), true).unwrap_err();
+            assert_eq!(err.reason(), Some("insufficient balance"));
+            assert_eq!(
+                err,
+                CallError::Revert {
+                    data,
+                    reason: Some("insufficient balance".into())
+                }
+            );
+        }
+
+        #[test]
+        fn custom_error_revert_has_no_reason() {
+            let data = vec![0xde, 0xad, 0xbe, 0xef, 0xff];
+            let err = classify(Err(data.clone()), true).unwrap_err();
+            assert_eq!(err.reason(), None);
+            assert_eq!(err, CallError::Revert { data, reason: None });
+        }
+
+        #[test]
+        fn short_return_is_malformed() {
+            let err =
+                CallError::decode(vec![0u8; 31], erc20::decode_balance_of__0x70a08231_returns)
+                    .unwrap_err();
+            assert_eq!(err, CallError::MalformedReturn(vec![0u8; 31]));
+        }
+
+        #[test]
+        fn full_word_decodes() {
+            let mut word = vec![0u8; 32];
+            word[31] = 7;
+            let balance =
+                CallError::decode(word, erc20::decode_balance_of__0x70a08231_returns).unwrap();
+            assert_eq!(balance, U256::from(7));
+        }
+
+        #[test]
+        fn revert_data_bubbles_up_unchanged() {
+            let data = vec![0xde, 0xad, 0xbe, 0xef];
+            let err = CallError::Revert {
+                data: data.clone(),
+                reason: None,
+            };
+            assert_eq!(Vec::<u8>::from(err), data);
+            assert_eq!(Vec::<u8>::from(CallError::EmptyRevert), Vec::<u8>::new());
+        }
+
+        #[test]
+        fn local_failures_become_error_strings() {
+            let data = Vec::<u8>::from(CallError::NoCode(TOKEN));
+            assert_eq!(
+                Revert::abi_decode(&data, true).unwrap().reason,
+                "call to address without code"
+            );
+            let data = Vec::<u8>::from(CallError::MalformedReturn(vec![]));
+            assert_eq!(
+                Revert::abi_decode(&data, true).unwrap().reason,
+                "malformed return data"
+            );
+        }
+    }
+
+    mod lazy {
+        use crate::lazy::ReturnData;
+        use stylus_sdk::alloy_primitives::U256;
+        use stylus_sdk::alloy_sol_types::{sol_data, Error, SolType};
+
+        fn balances(values: &[u64]) -> ReturnData {
+            let values: Vec<U256> = values.iter().map(|v| U256::from(*v)).collect();
+            let data = <(sol_data::Array<sol_data::Uint<256>>,)>::abi_encode_params(&(values,));
+            ReturnData::new(data, 1).unwrap()
+        }
+
+        #[test]
+        fn short_head_gives_data_back() {
+            assert_eq!(ReturnData::new(vec![0u8; 31], 1), Err(vec![0u8; 31]));
+            assert!(ReturnData::new(vec![], 0).is_ok());
+        }
+
+        #[test]
+        fn array_view_indexes_in_place() {
+            let data = balances(&[10, 20, 30]);
+            let view = data.array::<sol_data::Uint<256>>(0).unwrap();
+            assert_eq!(view.len(), 3);
+            assert_eq!(view.get(1).unwrap(), U256::from(20));
+            assert!(matches!(view.get(3), Err(Error::Overrun)));
+        }
+
+        #[test]
+        fn array_view_matches_eager_decoder() {
+            let data = balances(&[1, 2, 3, 4]);
+            let eager =
+                crate::erc1155::decode_balance_of_batch__0x4e1273f4_returns(data.as_slice())
+                    .unwrap();
+            let view = data.array::<sol_data::Uint<256>>(0).unwrap();
+            assert_eq!(view.to_vec().unwrap(), eager);
+            assert_eq!(view.iter().count(), 4);
+        }
+
+        #[test]
+        fn empty_array() {
+            let data = balances(&[]);
+            let view = data.array::<sol_data::Uint<256>>(0).unwrap();
+            assert!(view.is_empty());
+            assert_eq!(view.iter().count(), 0);
+        }
+
+        #[test]
+        fn dynamic_elements() {
+            let names = vec!["a".to_string(), "longer name".to_string()];
+            let data = <(sol_data::Array<sol_data::String>,)>::abi_encode_params(&(names,));
+            let data = ReturnData::new(data, 1).unwrap();
+            let view = data.array::<sol_data::String>(0).unwrap();
+            assert_eq!(view.get(1).unwrap(), "longer name");
+            assert_eq!(view.get(0).unwrap(), "a");
+        }
+
+        #[test]
+        fn static_and_array_outputs_together() {
+            let data = <(sol_data::Bool, sol_data::Array<sol_data::Uint<256>>)>::abi_encode_params(
+                &(true, vec![U256::from(5)]),
+            );
+            let data = ReturnData::new(data, 2).unwrap();
+            assert!(data.value::<sol_data::Bool>(0).unwrap());
+            let view = data.array::<sol_data::Uint<256>>(1).unwrap();
+            assert_eq!(view.get(0).unwrap(), U256::from(5));
+        }
+
+        #[test]
+        fn static_arrays_take_several_head_words() {
+            type Outputs = (
+                sol_data::FixedArray<sol_data::Uint<256>, 2>,
+                sol_data::Array<sol_data::Uint<256>>,
+                sol_data::Uint<256>,
+            );
+            let pair = [U256::from(1), U256::from(2)];
+            let data = Outputs::abi_encode_params(&(pair, vec![U256::from(7)], U256::from(9)));
+            let data = ReturnData::new(data, 4).unwrap();
+            assert_eq!(
+                data.value::<sol_data::FixedArray<sol_data::Uint<256>, 2>>(0)
+                    .unwrap(),
+                pair
+            );
+            let view = data.array::<sol_data::Uint<256>>(2).unwrap();
+            assert_eq!(view.to_vec().unwrap(), [U256::from(7)]);
+            assert_eq!(data.value::<sol_data::Uint<256>>(3).unwrap(), U256::from(9));
+        }
+
+        #[test]
+        fn truncated_array_is_rejected() {
+            let mut data = balances(&[1, 2]).into_inner();
+            data.truncate(data.len() - 1);
+            let data = ReturnData::new(data, 1).unwrap();
+            assert!(matches!(
+                data.array::<sol_data::Uint<256>>(0),
+                Err(Error::Overrun)
+            ));
+        }
+    }
+
+    mod mock {
+        use crate::erc20::{selectors, Calls, Erc20Api, MockErc20};
+        use crate::erc721::{Erc721Api, MockErc721};
+        use crate::CallError;
+        use stylus_sdk::alloy_primitives::{address, Address, U256};
+
+        const ALICE: Address = address!("00000000000000000000000000000000000000a1");
+
+        /// Business logic generic over the interface, as a contract would write it.
+        fn pay_if_funded(
+            token: &impl Erc20Api,
+            to: Address,
+            amount: U256,
+        ) -> Result<bool, CallError> {
+            if token.balance_of__0x70a08231(to)? >= amount {
+                return Ok(false);
+            }
+            token.transfer__0xa9059cbb(to, amount)
+        }
+
+        #[test]
+        fn configured_responses_are_decoded() {
+            let token = MockErc20::new();
+            token.returns_balance_of__0x70a08231(U256::from(5));
+            token.returns_transfer__0xa9059cbb(true);
+            assert_eq!(pay_if_funded(&token, ALICE, U256::from(10)), Ok(true));
+        }
+
+        #[test]
+        fn calls_are_logged_in_order() {
+            let token = MockErc20::new();
+            token.returns_balance_of__0x70a08231(U256::from(50));
+            assert_eq!(pay_if_funded(&token, ALICE, U256::from(10)), Ok(false));
+            assert_eq!(
+                token.calls(),
+                vec![Calls::BalanceOf__0x70a08231 { owner: ALICE }]
+            );
+            token.state.clear_calls();
+            assert!(token.calls().is_empty());
+        }
+
+        #[test]
+        fn failures_propagate() {
+            let token = MockErc20::new();
+            token.returns_balance_of__0x70a08231(U256::ZERO);
+            token.fail(selectors::transfer__0xa9059cbb, CallError::EmptyRevert);
             assert_eq!(
-                fn_count, 2,
-                "IERC165 must have exactly 1 selector-suffixed function plus new()"
+                pay_if_funded(&token, ALICE, U256::from(1)),
+                Err(CallError::EmptyRevert)
             );
+            assert_eq!(token.calls().len(), 2);
+        }
+
+        #[test]
+        fn unconfigured_function_with_outputs_is_malformed() {
+            let token = MockErc20::new();
+            assert_eq!(
+                token.balance_of__0x70a08231(ALICE),
+                Err(CallError::MalformedReturn(vec![]))
+            );
+        }
+
+        #[test]
+        fn unconfigured_function_without_outputs_succeeds() {
+            let nft = MockErc721::new();
+            assert_eq!(nft.approve__0x095ea7b3(ALICE, U256::from(1)), Ok(()));
+        }
+    }
+
+    mod transport {
+        use crate::erc20::{self, CallOpts, Calls};
+        use crate::transport::{
+            around_storage_cache, CallKind, InProcessTransport, Request, StylusTransport, Transport,
+        };
+        use crate::CallError;
+        use core::cell::RefCell;
+        use std::rc::Rc;
+        use stylus_sdk::alloy_primitives::{address, Address, U256};
+        use stylus_sdk::alloy_sol_types::{Revert, SolError, SolValue};
+
+        const TOKEN: Address = address!("00000000000000000000000000000000000000c1");
+        const ROUTER: Address = address!("00000000000000000000000000000000000000c2");
+        const ALICE: Address = address!("00000000000000000000000000000000000000a1");
+
+        /// Token holding 100 units for everyone that reverts on larger transfers.
+        fn token(seen: Rc<RefCell<Vec<(CallKind, U256)>>>) -> InProcessTransport {
+            let mut transport = InProcessTransport::new();
+            transport.register(TOKEN, move |_, request: Request<'_>| {
+                seen.borrow_mut().push((request.kind, request.value));
+                match Calls::abi_decode(request.calldata).map_err(|_| Vec::new())? {
+                    Calls::BalanceOf__0x70a08231 { .. } => Ok(U256::from(100).abi_encode()),
+                    Calls::Transfer__0xa9059cbb { value, .. } if value > U256::from(100) => {
+                        Err(Revert::from("insufficient balance").abi_encode())
+                    }
+                    Calls::Transfer__0xa9059cbb { .. } => Ok(true.abi_encode()),
+                    // `approve` and the rest revert without data.
+                    _ => Err(Vec::new()),
+                }
+            });
+            transport
+        }
+
+        #[test]
+        fn calls_reach_the_registered_handler() {
+            let transport = token(Rc::default());
+            let contract = erc20::Contract::with_transport(TOKEN, &transport);
+            assert_eq!(contract.balance_of__0x70a08231(ALICE), Ok(U256::from(100)));
+            assert_eq!(
+                contract.transfer__0xa9059cbb(ALICE, U256::from(1)),
+                Ok(true)
+            );
+        }
+
+        #[test]
+        fn reverts_are_classified() {
+            let transport = token(Rc::default());
+            let contract = erc20::Contract::with_transport(TOKEN, &transport);
+            let err = contract
+                .transfer__0xa9059cbb(ALICE, U256::from(101))
+                .unwrap_err();
+            assert_eq!(err.reason(), Some("insufficient balance"));
+            assert_eq!(
+                contract.approve__0x095ea7b3(ALICE, U256::from(1)),
+                Err(CallError::EmptyRevert)
+            );
+        }
+
+        #[test]
+        fn unregistered_address_has_no_code() {
+            let transport = InProcessTransport::new();
+            let contract = erc20::Contract::with_transport(ALICE, &transport);
+            assert_eq!(
+                contract.balance_of__0x70a08231(ALICE),
+                Err(CallError::NoCode(ALICE))
+            );
+            assert!(!transport.has_code(ALICE));
+        }
+
+        #[test]
+        fn value_is_only_sent_with_call() {
+            let seen = Rc::default();
+            let transport = token(Rc::clone(&seen));
+            let contract = erc20::Contract::with_transport(TOKEN, &transport).with(CallOpts {
+                value: U256::from(7),
+                ..CallOpts::default()
+            });
+            contract.balance_of__0x70a08231(ALICE).unwrap();
+            contract.transfer__0xa9059cbb(ALICE, U256::from(1)).unwrap();
+            assert_eq!(
+                *seen.borrow(),
+                vec![
+                    (CallKind::StaticCall, U256::ZERO),
+                    (CallKind::Call, U256::from(7)),
+                ]
+            );
+        }
+
+        #[test]
+        fn handlers_can_call_other_contracts() {
+            let mut transport = token(Rc::default());
+            // Forwards its calldata's amount to the token, like a contract using the bindings.
+            transport.register(ROUTER, |transport, request| {
+                let amount = U256::abi_decode(request.calldata, true).map_err(|_| Vec::new())?;
+                let token = erc20::Contract::with_transport(TOKEN, transport);
+                let ok = token.transfer__0xa9059cbb(ALICE, amount)?;
+                Ok(ok.abi_encode())
+            });
+            let call = |amount: u64| {
+                let calldata = U256::from(amount).abi_encode();
+                transport.call(Request {
+                    address: ROUTER,
+                    calldata: &calldata,
+                    kind: CallKind::Call,
+                    value: U256::ZERO,
+                    gas: None,
+                    max_return: None,
+                })
+            };
+            assert_eq!(call(1), Ok(true.abi_encode()));
+            assert_eq!(
+                call(1_000).unwrap_err().reason(),
+                Some("insufficient balance")
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "send_delegate")]
+        fn safe_send_refuses_delegatecalls() {
+            // `send`'s `RawCall`, without the hostio calls that only link on chain.
+            let _ = StylusTransport::raw_call(Request {
+                address: TOKEN,
+                calldata: &[],
+                kind: CallKind::DelegateCall,
+                value: U256::ZERO,
+                gas: None,
+                max_return: None,
+            });
+        }
+
+        #[test]
+        fn delegatecalls_reach_simulated_handlers() {
+            let seen = Rc::default();
+            let transport = token(Rc::clone(&seen));
+            let calldata = erc20::encode_balance_of__0x70a08231(ALICE);
+            // SAFETY: the simulated token has no access to any caller storage.
+            let result = unsafe {
+                transport.call_delegate(Request {
+                    address: TOKEN,
+                    calldata: &calldata,
+                    kind: CallKind::DelegateCall,
+                    value: U256::ZERO,
+                    gas: None,
+                    max_return: None,
+                })
+            };
+            assert_eq!(result, Ok(U256::from(100).abi_encode()));
+            assert_eq!(*seen.borrow(), vec![(CallKind::DelegateCall, U256::ZERO)]);
+        }
+
+        #[test]
+        fn delegatecalls_flush_then_clear_the_storage_cache() {
+            let events = RefCell::new(Vec::new());
+            let result = around_storage_cache(
+                || events.borrow_mut().push("flush"),
+                || events.borrow_mut().push("clear"),
+                || {
+                    events.borrow_mut().push("delegatecall");
+                    7
+                },
+            );
+            assert_eq!(result, 7);
+            assert_eq!(*events.borrow(), ["flush", "delegatecall", "clear"]);
+        }
+
+        #[test]
+        fn sender_is_the_calling_contract() {
+            let mut transport = InProcessTransport::new();
+            transport.register(TOKEN, |transport, _| Ok(transport.sender().abi_encode()));
+            transport.register(ROUTER, |transport, request| {
+                let mut nested = request;
+                nested.address = TOKEN;
+                let sender = transport.call(nested).map_err(Vec::from)?;
+                Ok([transport.sender().abi_encode(), sender].concat())
+            });
+            transport.set_caller(ALICE);
+            let result = transport.call(Request {
+                address: ROUTER,
+                calldata: &[],
+                kind: CallKind::Call,
+                value: U256::ZERO,
+                gas: None,
+                max_return: None,
+            });
+            assert_eq!(result, Ok((ALICE, ROUTER).abi_encode_params()));
+            assert_eq!(transport.sender(), ALICE);
         }
     }
 
     mod cross_interface {
-        use super::sources;
+        use super::{sources, UNSUFFIXED_FNS};
 
         #[test]
         fn all_use_selector_suffixed_naming() {
@@ -306,9 +965,10 @@ mod tests {
                     if let Some(fn_start) = line.find("pub fn ") {
                         let after = &line[fn_start + 7..];
                         let fn_name = after.split('(').next().unwrap();
-                        if fn_name == "new" {
+                        if UNSUFFIXED_FNS.contains(&fn_name) {
                             continue;
                         }
+                        let fn_name = fn_name.strip_suffix("_returns").unwrap_or(fn_name);
                         assert!(
                             fn_name.contains("__0x"),
                             "In {}: function '{}' must use __0x<selector> naming",
@@ -365,7 +1025,11 @@ mod tests {
                     if let Some(fn_start) = line.find("pub fn ") {
                         let after = &line[fn_start + 7..];
                         let fn_name = after.split('(').next().unwrap();
-                        if fn_name == "new" {
+                        if UNSUFFIXED_FNS.contains(&fn_name)
+                            || fn_name.starts_with("encode_")
+                            || fn_name.starts_with("decode_")
+                            || fn_name.starts_with("returns_")
+                        {
                             continue;
                         }
                         let selector = fn_name.split("__0x").last().unwrap();
@@ -382,29 +1046,195 @@ mod tests {
         }
 
         #[test]
-        fn selector_in_hex_decode_matches_function_name() {
+        fn encoders_use_matching_selector_const() {
+            for (name, src) in sources() {
+                let lines: Vec<&str> = src.lines().collect();
+                for (i, line) in lines.iter().enumerate() {
+                    if let Some(fn_start) = line.find("pub fn encode_") {
+                        let after = &line[fn_start + 7..];
+                        let fn_name = after.split('(').next().unwrap();
+                        let method = fn_name.strip_prefix("encode_").unwrap();
+                        let body_window = &lines[i..std::cmp::min(i + 12, lines.len())];
+                        let body = body_window.join("\n");
+                        assert!(
+                            body.contains(&format!("selectors::{}", method)),
+                            "In {}: function {} must use selectors::{} in its body",
+                            name,
+                            fn_name,
+                            method
+                        );
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn methods_use_matching_encoder() {
             for (name, src) in sources() {
                 let lines: Vec<&str> = src.lines().collect();
                 for (i, line) in lines.iter().enumerate() {
                     if let Some(fn_start) = line.find("pub fn ") {
                         let after = &line[fn_start + 7..];
                         let fn_name = after.split('(').next().unwrap();
-                        if fn_name == "new" {
+                        if UNSUFFIXED_FNS.contains(&fn_name)
+                            || fn_name.starts_with("encode_")
+                            || fn_name.starts_with("decode_")
+                            || fn_name.starts_with("returns_")
+                        {
                             continue;
                         }
-                        let selector = fn_name.split("__0x").last().unwrap();
                         let body_window = &lines[i..std::cmp::min(i + 12, lines.len())];
                         let body = body_window.join("\n");
                         assert!(
-                            body.contains(&format!("hex::decode(\"{}\")", selector)),
-                            "In {}: function {} must call hex::decode(\"{}\") in its body",
+                            body.contains(&format!("encode_{}(", fn_name)),
+                            "In {}: method {} must build calldata with encode_{}",
                             name,
                             fn_name,
-                            selector
+                            fn_name
                         );
                     }
                 }
             }
         }
     }
+
+    mod encoding {
+        use stylus_sdk::alloy_primitives::{address, Address, FixedBytes, U256};
+
+        #[test]
+        fn erc20_transfer_calldata() {
+            let to = address!("00000000000000000000000000000000000000aa");
+            let calldata = crate::erc20::encode_transfer__0xa9059cbb(to, U256::from(1000));
+            assert_eq!(calldata.len(), 4 + 32 * 2);
+            assert_eq!(&calldata[..4], &[0xa9, 0x05, 0x9c, 0xbb]);
+            assert_eq!(&calldata[16..36], to.as_slice());
+            assert_eq!(U256::from_be_slice(&calldata[36..68]), U256::from(1000));
+        }
+
+        #[test]
+        fn stack_encoding_matches_abi_encode_params() {
+            use stylus_sdk::alloy_sol_types::{sol_data, SolType};
+            let operator = address!("00000000000000000000000000000000000000bb");
+            let calldata = crate::erc721::encode_set_approval_for_all__0xa22cb465(operator, true);
+            let mut expected = crate::erc721::selectors::set_approval_for_all__0xa22cb465.to_vec();
+            expected.extend(<(sol_data::Address, sol_data::Bool)>::abi_encode_params(&(
+                operator, true,
+            )));
+            assert_eq!(calldata.as_slice(), expected.as_slice());
+        }
+
+        #[test]
+        fn erc721_safe_transfer_with_data_calldata() {
+            let calldata = crate::erc721::encode_safe_transfer_from__0xb88d4fde(
+                Address::ZERO,
+                Address::ZERO,
+                U256::from(7),
+                vec![0xde, 0xad],
+            );
+            assert_eq!(&calldata[..4], &[0xb8, 0x8d, 0x4f, 0xde]);
+            // head: from, to, tokenId, offset(data); tail: length, padded bytes
+            assert_eq!(calldata.len(), 4 + 32 * 6);
+            assert_eq!(U256::from_be_slice(&calldata[100..132]), U256::from(128));
+            assert_eq!(U256::from_be_slice(&calldata[132..164]), U256::from(2));
+            assert_eq!(&calldata[164..166], &[0xde, 0xad]);
+        }
+
+        #[test]
+        fn ierc165_supports_interface_calldata() {
+            let calldata = crate::ierc165::encode_supports_interface__0x01ffc9a7(FixedBytes([
+                0x80, 0xac, 0x58, 0xcd,
+            ]));
+            assert_eq!(
+                calldata,
+                crate::detection::probe_calldata(FixedBytes([0x80, 0xac, 0x58, 0xcd]))
+            );
+        }
+
+        #[test]
+        fn decode_bool_and_uint_returns() {
+            let mut word = [0u8; 32];
+            word[31] = 1;
+            assert!(crate::erc20::decode_transfer__0xa9059cbb_returns(&word).unwrap());
+            assert_eq!(
+                crate::erc20::decode_balance_of__0x70a08231_returns(&word).unwrap(),
+                U256::from(1)
+            );
+        }
+
+        #[test]
+        fn decode_rejects_short_returns() {
+            assert!(crate::erc20::decode_balance_of__0x70a08231_returns(&[0u8; 31]).is_err());
+            assert!(crate::erc721::decode_owner_of__0x6352211e_returns(&[]).is_err());
+        }
+
+        #[test]
+        fn calls_round_trip() {
+            let call = crate::erc721::Calls::SafeTransferFrom__0xb88d4fde {
+                from: Address::ZERO,
+                to: address!("00000000000000000000000000000000000000aa"),
+                tokenId: U256::from(1),
+                data: vec![0x01, 0x02],
+            };
+            let calldata = call.abi_encode();
+            assert_eq!(call.selector(), [0xb8, 0x8d, 0x4f, 0xde]);
+            assert_eq!(crate::erc721::Calls::abi_decode(&calldata).unwrap(), call);
+        }
+
+        #[test]
+        fn calls_decode_distinguishes_overloads() {
+            let calldata = crate::erc721::encode_safe_transfer_from__0x42842e0e(
+                Address::ZERO,
+                Address::ZERO,
+                U256::from(3),
+            );
+            assert_eq!(
+                crate::erc721::Calls::abi_decode(&calldata).unwrap(),
+                crate::erc721::Calls::SafeTransferFrom__0x42842e0e {
+                    from: Address::ZERO,
+                    to: Address::ZERO,
+                    tokenId: U256::from(3),
+                }
+            );
+        }
+
+        #[test]
+        fn calls_decode_rejects_unknown_and_short_calldata() {
+            assert!(crate::erc20::Calls::abi_decode(&[0xde, 0xad, 0xbe, 0xef]).is_err());
+            assert!(crate::erc20::Calls::abi_decode(&[0xa9, 0x05, 0x9c]).is_err());
+            assert!(crate::erc20::Calls::abi_decode(&[0xa9, 0x05, 0x9c, 0xbb]).is_err());
+        }
+
+        #[test]
+        fn calls_display() {
+            let call = crate::erc721::Calls::SafeTransferFrom__0xb88d4fde {
+                from: Address::ZERO,
+                to: Address::ZERO,
+                tokenId: U256::from(1),
+                data: vec![0xab],
+            };
+            assert_eq!(
+                call.to_string(),
+                "safeTransferFrom(from=0x0000000000000000000000000000000000000000, \
+                 to=0x0000000000000000000000000000000000000000, tokenId=1, data=0xab)"
+            );
+            let batch = crate::erc1155::Calls::BalanceOfBatch__0x4e1273f4 {
+                accounts: vec![],
+                ids: vec![U256::from(1), U256::from(2)],
+            };
+            assert_eq!(batch.to_string(), "balanceOfBatch(accounts=[], ids=[1, 2])");
+        }
+
+        #[test]
+        fn decode_dynamic_array_returns() {
+            let mut data = vec![0u8; 32 * 4];
+            data[31] = 0x20; // offset
+            data[63] = 2; // length
+            data[95] = 5;
+            data[127] = 9;
+            assert_eq!(
+                crate::erc1155::decode_balance_of_batch__0x4e1273f4_returns(&data).unwrap(),
+                vec![U256::from(5), U256::from(9)]
+            );
+        }
+    }
 }
@@ -0,0 +1,55 @@
+//! Shared state behind the generated `Mock*` test doubles.
+//!
+//! Each generated mock logs calls as its interface's `Calls` enum and answers with raw return
+//! data, which then goes through the same decoders as a real `Contract` call.
+
+use crate::CallError;
+use core::cell::RefCell;
+use std::collections::BTreeMap;
+
+/// Raw return data, or the error a mocked call fails with.
+pub type Response = Result<Vec<u8>, CallError>;
+
+/// Responses keyed by selector plus a log of every call, in order.
+pub struct MockState<C> {
+    responses: RefCell<BTreeMap<[u8; 4], Response>>,
+    calls: RefCell<Vec<C>>,
+}
+
+impl<C> Default for MockState<C> {
+    fn default() -> Self {
+        Self {
+            responses: RefCell::new(BTreeMap::new()),
+            calls: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl<C: Clone> MockState<C> {
+    /// Answers every later call to `selector` with `response`, until replaced.
+    pub fn respond(&self, selector: [u8; 4], response: Response) {
+        self.responses.borrow_mut().insert(selector, response);
+    }
+
+    /// Logs `call` and returns the response configured for `selector`.
+    ///
+    /// Unconfigured selectors return empty data: fine for functions without outputs, and a
+    /// `MalformedReturn` for everything else.
+    pub fn record(&self, selector: [u8; 4], call: C) -> Response {
+        self.calls.borrow_mut().push(call);
+        self.responses
+            .borrow()
+            .get(&selector)
+            .cloned()
+            .unwrap_or(Ok(Vec::new()))
+    }
+
+    /// Every call made so far, oldest first.
+    pub fn calls(&self) -> Vec<C> {
+        self.calls.borrow().clone()
+    }
+
+    pub fn clear_calls(&self) {
+        self.calls.borrow_mut().clear();
+    }
+}
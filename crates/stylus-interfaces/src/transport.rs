@@ -0,0 +1,231 @@
+//! How generated `Contract`s reach the chain.
+//!
+//! [`StylusTransport`] sends calls through the `RawCall` hostio and is the default. For native
+//! integration tests, [`InProcessTransport`] routes calls to Rust closures registered per
+//! address, so several interacting contracts can be simulated with the same binding code.
+
+use crate::CallError;
+use core::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use stylus_sdk::{
+    alloy_primitives::{Address, U256},
+    call::RawCall,
+    storage::StorageCache,
+    types::AddressVM,
+};
+
+/// Opcode used for a call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallKind {
+    /// CALL; the only kind that sends value.
+    Call,
+    /// STATICCALL, used for view and pure functions.
+    StaticCall,
+    /// DELEGATECALL, used by `DelegateContract` through [`Transport::send_delegate`].
+    DelegateCall,
+}
+
+/// A single outgoing call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Request<'a> {
+    pub address: Address,
+    pub calldata: &'a [u8],
+    pub kind: CallKind,
+    /// Wei sent along; always zero unless `kind` is `Call`.
+    pub value: U256,
+    /// Gas forwarded to the callee; all remaining gas when `None`.
+    pub gas: Option<u64>,
+    /// Maximum bytes of return data copied back.
+    pub max_return: Option<usize>,
+}
+
+/// Sends calls on behalf of generated contracts.
+pub trait Transport {
+    /// Performs `request`, returning the return data or the revert data.
+    ///
+    /// Delegatecalls go through [`send_delegate`](Self::send_delegate) instead; transports that
+    /// run on chain panic on them here.
+    fn send(&self, request: Request<'_>) -> Result<Vec<u8>, Vec<u8>>;
+
+    /// Performs `request` as a DELEGATECALL, running the target's code against the calling
+    /// contract's storage and balance.
+    ///
+    /// Simulated transports have no caller storage to lend, so by default this is [`send`](Self::send).
+    ///
+    /// # Safety
+    ///
+    /// The target code can read and overwrite any storage slot of the calling contract. It must
+    /// be trusted and built against the caller's storage layout.
+    unsafe fn send_delegate(&self, request: Request<'_>) -> Result<Vec<u8>, Vec<u8>> {
+        self.send(request)
+    }
+
+    /// Whether `address` has code, used to tell a missing contract from an empty return.
+    fn has_code(&self, address: Address) -> bool;
+
+    /// Performs `request` and classifies the outcome as a [`CallError`].
+    fn call(&self, request: Request<'_>) -> Result<Vec<u8>, CallError> {
+        let address = request.address;
+        CallError::check_with(address, self.send(request), || self.has_code(address))
+    }
+
+    /// Performs `request` as a DELEGATECALL and classifies the outcome as a [`CallError`].
+    ///
+    /// # Safety
+    ///
+    /// As for [`send_delegate`](Self::send_delegate).
+    unsafe fn call_delegate(&self, request: Request<'_>) -> Result<Vec<u8>, CallError> {
+        let address = request.address;
+        // SAFETY: the caller upholds `send_delegate`'s contract.
+        let result = unsafe { self.send_delegate(request) };
+        CallError::check_with(address, result, || self.has_code(address))
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for &T {
+    fn send(&self, request: Request<'_>) -> Result<Vec<u8>, Vec<u8>> {
+        (**self).send(request)
+    }
+
+    unsafe fn send_delegate(&self, request: Request<'_>) -> Result<Vec<u8>, Vec<u8>> {
+        // SAFETY: forwarded unchanged; the caller upholds the contract.
+        unsafe { (**self).send_delegate(request) }
+    }
+
+    fn has_code(&self, address: Address) -> bool {
+        (**self).has_code(address)
+    }
+}
+
+/// The Stylus `RawCall` hostio; the transport used by `Contract::new`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StylusTransport;
+
+impl StylusTransport {
+    /// The CALL or STATICCALL that performs `request`.
+    ///
+    /// # Panics
+    ///
+    /// On a `CallKind::DelegateCall` request, which has to go through
+    /// [`send_delegate`](Transport::send_delegate).
+    pub(crate) fn raw_call(request: Request<'_>) -> RawCall {
+        let call = match request.kind {
+            CallKind::Call => RawCall::new_with_value(request.value),
+            CallKind::StaticCall => RawCall::new_static(),
+            CallKind::DelegateCall => panic!("delegatecalls must go through `send_delegate`"),
+        };
+        Self::with_limits(call, request)
+    }
+
+    fn with_limits(mut call: RawCall, request: Request<'_>) -> RawCall {
+        if let Some(gas) = request.gas {
+            call = call.gas(gas);
+        }
+        if let Some(max_return) = request.max_return {
+            call = call.limit_return_data(0, max_return);
+        }
+        call
+    }
+}
+
+impl Transport for StylusTransport {
+    /// Performs a CALL or STATICCALL.
+    ///
+    /// # Panics
+    ///
+    /// On a `CallKind::DelegateCall` request, which has to go through
+    /// [`send_delegate`](Transport::send_delegate).
+    fn send(&self, request: Request<'_>) -> Result<Vec<u8>, Vec<u8>> {
+        // SAFETY: CALL and STATICCALL run the target against its own storage. Contracts that can
+        // be reentered flush their storage cache before calling out.
+        unsafe { Self::raw_call(request).call(request.address, request.calldata) }
+    }
+
+    unsafe fn send_delegate(&self, request: Request<'_>) -> Result<Vec<u8>, Vec<u8>> {
+        let call = Self::with_limits(RawCall::new_delegate(), request);
+        around_storage_cache(StorageCache::flush, StorageCache::clear, || {
+            // SAFETY: the caller vouches for the target, as `send_delegate` requires.
+            unsafe { call.call(request.address, request.calldata) }
+        })
+    }
+
+    fn has_code(&self, address: Address) -> bool {
+        address.has_code()
+    }
+}
+
+/// Runs `delegatecall` between a flush of the storage cache, so the target sees this contract's
+/// pending writes, and a clear, so cached slots the target overwrote aren't read or written back.
+pub(crate) fn around_storage_cache<R>(
+    flush: impl FnOnce(),
+    clear: impl FnOnce(),
+    delegatecall: impl FnOnce() -> R,
+) -> R {
+    flush();
+    let result = delegatecall();
+    clear();
+    result
+}
+
+/// Handler simulating the contract deployed at one address.
+///
+/// It receives the transport itself, so it can call other simulated contracts.
+pub type Handler = Box<dyn Fn(&InProcessTransport, Request<'_>) -> Result<Vec<u8>, Vec<u8>>>;
+
+/// In-process transport for native tests: calls go to the handler registered for the address.
+///
+/// Addresses without a handler behave like accounts without code. The transport tracks the
+/// simulated call stack, so handlers can ask for their `msg.sender` with [`sender`](Self::sender).
+#[derive(Default)]
+pub struct InProcessTransport {
+    handlers: BTreeMap<Address, Handler>,
+    caller: Cell<Address>,
+    frames: RefCell<Vec<Address>>,
+}
+
+impl InProcessTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the account that outermost calls come from; `Address::ZERO` by default.
+    pub fn set_caller(&self, caller: Address) {
+        self.caller.set(caller);
+    }
+
+    /// `msg.sender` of the handler currently running: the contract that called it, or the
+    /// caller set with [`set_caller`](Self::set_caller) for outermost calls.
+    pub fn sender(&self) -> Address {
+        let frames = self.frames.borrow();
+        match frames.len() {
+            0 | 1 => self.caller.get(),
+            len => frames[len - 2],
+        }
+    }
+
+    /// Deploys `handler` at `address`, replacing any previous one.
+    pub fn register(
+        &mut self,
+        address: Address,
+        handler: impl Fn(&InProcessTransport, Request<'_>) -> Result<Vec<u8>, Vec<u8>> + 'static,
+    ) -> &mut Self {
+        self.handlers.insert(address, Box::new(handler));
+        self
+    }
+}
+
+impl Transport for InProcessTransport {
+    fn send(&self, request: Request<'_>) -> Result<Vec<u8>, Vec<u8>> {
+        let Some(handler) = self.handlers.get(&request.address) else {
+            return Ok(Vec::new());
+        };
+        self.frames.borrow_mut().push(request.address);
+        let result = handler(self, request);
+        self.frames.borrow_mut().pop();
+        result
+    }
+
+    fn has_code(&self, address: Address) -> bool {
+        self.handlers.contains_key(&address)
+    }
+}
@@ -0,0 +1,71 @@
+//! [`Erc1155Interface`] on [`stylus_reference::erc1155`].
+
+use crate::erc1155_interface::{Erc1155, Erc1155Interface};
+use stylus_reference::{erc1155, erc165, StylusHost};
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, FixedBytes, U256},
+};
+
+impl Erc1155Interface for Erc1155 {
+    fn balance_of__0x00fdd58e(&self, account: Address, id: U256) -> Result<U256, Vec<u8>> {
+        Ok(erc1155::balance_of(&StylusHost, account, id))
+    }
+
+    fn balance_of_batch__0x4e1273f4(
+        &self,
+        accounts: Vec<Address>,
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, Vec<u8>> {
+        erc1155::balance_of_batch(&StylusHost, &accounts, &ids)
+    }
+
+    fn is_approved_for_all__0xe985e9c5(
+        &self,
+        account: Address,
+        operator: Address,
+    ) -> Result<bool, Vec<u8>> {
+        Ok(erc1155::is_approved_for_all(&StylusHost, account, operator))
+    }
+
+    fn mint__0x156e29f6(&mut self, to: Address, id: U256, value: U256) -> Result<(), Vec<u8>> {
+        erc1155::mint(&mut StylusHost, to, id, value, &[])
+    }
+
+    fn safe_batch_transfer_from__0x2eb2c2d6(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        erc1155::safe_batch_transfer_from(&mut StylusHost, from, to, &ids, &values, &data)
+    }
+
+    fn safe_transfer_from__0xf242432a(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        value: U256,
+        data: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        erc1155::safe_transfer_from(&mut StylusHost, from, to, id, value, &data)
+    }
+
+    fn set_approval_for_all__0xa22cb465(
+        &mut self,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), Vec<u8>> {
+        erc1155::set_approval_for_all(&mut StylusHost, operator, approved)
+    }
+
+    fn supports_interface__0x01ffc9a7(&self, interface_id: FixedBytes<4>) -> Result<bool, Vec<u8>> {
+        Ok(erc165::supports_interface(
+            interface_id.0,
+            &[erc1155::INTERFACE_ID],
+        ))
+    }
+}
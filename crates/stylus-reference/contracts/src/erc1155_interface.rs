@@ -0,0 +1,149 @@
+// Generated by stylus-bindgen
+#![allow(clippy::all)]
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, FixedBytes, U256},
+    prelude::*,
+};
+
+/// Functions of the `Erc1155` interface, as a Stylus contract implements them.
+///
+/// Implement this for `Erc1155`; its `#[public]` block routes every selector of the ABI here.
+pub trait Erc1155Interface {
+    // Original: balanceOf(address,uint256)
+    fn balance_of__0x00fdd58e(&self, account: Address, id: U256) -> Result<U256, Vec<u8>>;
+
+    // Original: balanceOfBatch(address[],uint256[])
+    fn balance_of_batch__0x4e1273f4(
+        &self,
+        accounts: Vec<Address>,
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, Vec<u8>>;
+
+    // Original: isApprovedForAll(address,address)
+    fn is_approved_for_all__0xe985e9c5(
+        &self,
+        account: Address,
+        operator: Address,
+    ) -> Result<bool, Vec<u8>>;
+
+    // Original: mint(address,uint256,uint256)
+    fn mint__0x156e29f6(&mut self, to: Address, id: U256, value: U256) -> Result<(), Vec<u8>>;
+
+    // Original: safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)
+    fn safe_batch_transfer_from__0x2eb2c2d6(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: Bytes,
+    ) -> Result<(), Vec<u8>>;
+
+    // Original: safeTransferFrom(address,address,uint256,uint256,bytes)
+    fn safe_transfer_from__0xf242432a(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        value: U256,
+        data: Bytes,
+    ) -> Result<(), Vec<u8>>;
+
+    // Original: setApprovalForAll(address,bool)
+    fn set_approval_for_all__0xa22cb465(
+        &mut self,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), Vec<u8>>;
+
+    // Original: supportsInterface(bytes4)
+    fn supports_interface__0x01ffc9a7(&self, interfaceId: FixedBytes<4>) -> Result<bool, Vec<u8>>;
+}
+
+/// Contract storage; add fields as needed.
+#[storage]
+#[entrypoint]
+pub struct Erc1155 {}
+
+#[public]
+impl Erc1155 {
+    // Original: balanceOf(address,uint256)
+    #[selector(name = "balanceOf")]
+    pub fn balance_of__0x00fdd58e(&self, account: Address, id: U256) -> Result<U256, Vec<u8>> {
+        <Self as Erc1155Interface>::balance_of__0x00fdd58e(self, account, id)
+    }
+
+    // Original: balanceOfBatch(address[],uint256[])
+    #[selector(name = "balanceOfBatch")]
+    pub fn balance_of_batch__0x4e1273f4(
+        &self,
+        accounts: Vec<Address>,
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, Vec<u8>> {
+        <Self as Erc1155Interface>::balance_of_batch__0x4e1273f4(self, accounts, ids)
+    }
+
+    // Original: isApprovedForAll(address,address)
+    #[selector(name = "isApprovedForAll")]
+    pub fn is_approved_for_all__0xe985e9c5(
+        &self,
+        account: Address,
+        operator: Address,
+    ) -> Result<bool, Vec<u8>> {
+        <Self as Erc1155Interface>::is_approved_for_all__0xe985e9c5(self, account, operator)
+    }
+
+    // Original: mint(address,uint256,uint256)
+    #[selector(name = "mint")]
+    pub fn mint__0x156e29f6(&mut self, to: Address, id: U256, value: U256) -> Result<(), Vec<u8>> {
+        <Self as Erc1155Interface>::mint__0x156e29f6(self, to, id, value)
+    }
+
+    // Original: safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)
+    #[selector(name = "safeBatchTransferFrom")]
+    pub fn safe_batch_transfer_from__0x2eb2c2d6(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        <Self as Erc1155Interface>::safe_batch_transfer_from__0x2eb2c2d6(
+            self, from, to, ids, values, data,
+        )
+    }
+
+    // Original: safeTransferFrom(address,address,uint256,uint256,bytes)
+    #[selector(name = "safeTransferFrom")]
+    pub fn safe_transfer_from__0xf242432a(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        value: U256,
+        data: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        <Self as Erc1155Interface>::safe_transfer_from__0xf242432a(self, from, to, id, value, data)
+    }
+
+    // Original: setApprovalForAll(address,bool)
+    #[selector(name = "setApprovalForAll")]
+    pub fn set_approval_for_all__0xa22cb465(
+        &mut self,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), Vec<u8>> {
+        <Self as Erc1155Interface>::set_approval_for_all__0xa22cb465(self, operator, approved)
+    }
+
+    // Original: supportsInterface(bytes4)
+    #[selector(name = "supportsInterface")]
+    pub fn supports_interface__0x01ffc9a7(
+        &self,
+        interfaceId: FixedBytes<4>,
+    ) -> Result<bool, Vec<u8>> {
+        <Self as Erc1155Interface>::supports_interface__0x01ffc9a7(self, interfaceId)
+    }
+}
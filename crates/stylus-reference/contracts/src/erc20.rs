@@ -0,0 +1,40 @@
+//! [`Erc20Interface`] on [`stylus_reference::erc20`].
+
+use crate::erc20_interface::{Erc20, Erc20Interface};
+use stylus_reference::{erc20, StylusHost};
+use stylus_sdk::alloy_primitives::{Address, U256};
+
+impl Erc20Interface for Erc20 {
+    fn allowance__0xdd62ed3e(&self, owner: Address, spender: Address) -> Result<U256, Vec<u8>> {
+        Ok(erc20::allowance(&StylusHost, owner, spender))
+    }
+
+    fn approve__0x095ea7b3(&mut self, spender: Address, value: U256) -> Result<bool, Vec<u8>> {
+        erc20::approve(&mut StylusHost, spender, value)
+    }
+
+    fn balance_of__0x70a08231(&self, owner: Address) -> Result<U256, Vec<u8>> {
+        Ok(erc20::balance_of(&StylusHost, owner))
+    }
+
+    fn mint__0x40c10f19(&mut self, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        erc20::mint(&mut StylusHost, to, value)
+    }
+
+    fn total_supply__0x18160ddd(&self) -> Result<U256, Vec<u8>> {
+        Ok(erc20::total_supply(&StylusHost))
+    }
+
+    fn transfer__0xa9059cbb(&mut self, to: Address, value: U256) -> Result<bool, Vec<u8>> {
+        erc20::transfer(&mut StylusHost, to, value)
+    }
+
+    fn transfer_from__0x23b872dd(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<bool, Vec<u8>> {
+        erc20::transfer_from(&mut StylusHost, from, to, value)
+    }
+}
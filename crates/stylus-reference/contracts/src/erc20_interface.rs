@@ -0,0 +1,92 @@
+// Generated by stylus-bindgen
+#![allow(clippy::all)]
+use stylus_sdk::{
+    alloy_primitives::{Address, U256},
+    prelude::*,
+};
+
+/// Functions of the `Erc20` interface, as a Stylus contract implements them.
+///
+/// Implement this for `Erc20`; its `#[public]` block routes every selector of the ABI here.
+pub trait Erc20Interface {
+    // Original: allowance(address,address)
+    fn allowance__0xdd62ed3e(&self, owner: Address, spender: Address) -> Result<U256, Vec<u8>>;
+
+    // Original: approve(address,uint256)
+    fn approve__0x095ea7b3(&mut self, spender: Address, value: U256) -> Result<bool, Vec<u8>>;
+
+    // Original: balanceOf(address)
+    fn balance_of__0x70a08231(&self, owner: Address) -> Result<U256, Vec<u8>>;
+
+    // Original: mint(address,uint256)
+    fn mint__0x40c10f19(&mut self, to: Address, value: U256) -> Result<(), Vec<u8>>;
+
+    // Original: totalSupply()
+    fn total_supply__0x18160ddd(&self) -> Result<U256, Vec<u8>>;
+
+    // Original: transfer(address,uint256)
+    fn transfer__0xa9059cbb(&mut self, to: Address, value: U256) -> Result<bool, Vec<u8>>;
+
+    // Original: transferFrom(address,address,uint256)
+    fn transfer_from__0x23b872dd(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<bool, Vec<u8>>;
+}
+
+/// Contract storage; add fields as needed.
+#[storage]
+#[entrypoint]
+pub struct Erc20 {}
+
+#[public]
+impl Erc20 {
+    // Original: allowance(address,address)
+    #[selector(name = "allowance")]
+    pub fn allowance__0xdd62ed3e(&self, owner: Address, spender: Address) -> Result<U256, Vec<u8>> {
+        <Self as Erc20Interface>::allowance__0xdd62ed3e(self, owner, spender)
+    }
+
+    // Original: approve(address,uint256)
+    #[selector(name = "approve")]
+    pub fn approve__0x095ea7b3(&mut self, spender: Address, value: U256) -> Result<bool, Vec<u8>> {
+        <Self as Erc20Interface>::approve__0x095ea7b3(self, spender, value)
+    }
+
+    // Original: balanceOf(address)
+    #[selector(name = "balanceOf")]
+    pub fn balance_of__0x70a08231(&self, owner: Address) -> Result<U256, Vec<u8>> {
+        <Self as Erc20Interface>::balance_of__0x70a08231(self, owner)
+    }
+
+    // Original: mint(address,uint256)
+    #[selector(name = "mint")]
+    pub fn mint__0x40c10f19(&mut self, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        <Self as Erc20Interface>::mint__0x40c10f19(self, to, value)
+    }
+
+    // Original: totalSupply()
+    #[selector(name = "totalSupply")]
+    pub fn total_supply__0x18160ddd(&self) -> Result<U256, Vec<u8>> {
+        <Self as Erc20Interface>::total_supply__0x18160ddd(self)
+    }
+
+    // Original: transfer(address,uint256)
+    #[selector(name = "transfer")]
+    pub fn transfer__0xa9059cbb(&mut self, to: Address, value: U256) -> Result<bool, Vec<u8>> {
+        <Self as Erc20Interface>::transfer__0xa9059cbb(self, to, value)
+    }
+
+    // Original: transferFrom(address,address,uint256)
+    #[selector(name = "transferFrom")]
+    pub fn transfer_from__0x23b872dd(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<bool, Vec<u8>> {
+        <Self as Erc20Interface>::transfer_from__0x23b872dd(self, from, to, value)
+    }
+}
@@ -0,0 +1,81 @@
+//! [`Erc721Interface`] on [`stylus_reference::erc721`].
+
+use crate::erc721_interface::{Erc721, Erc721Interface};
+use stylus_reference::{erc165, erc721, StylusHost};
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, FixedBytes, U256},
+};
+
+impl Erc721Interface for Erc721 {
+    fn approve__0x095ea7b3(&mut self, to: Address, token_id: U256) -> Result<(), Vec<u8>> {
+        erc721::approve(&mut StylusHost, to, token_id)
+    }
+
+    fn balance_of__0x70a08231(&self, owner: Address) -> Result<U256, Vec<u8>> {
+        erc721::balance_of(&StylusHost, owner)
+    }
+
+    fn get_approved__0x081812fc(&self, token_id: U256) -> Result<Address, Vec<u8>> {
+        erc721::get_approved(&StylusHost, token_id)
+    }
+
+    fn is_approved_for_all__0xe985e9c5(
+        &self,
+        owner: Address,
+        operator: Address,
+    ) -> Result<bool, Vec<u8>> {
+        Ok(erc721::is_approved_for_all(&StylusHost, owner, operator))
+    }
+
+    fn mint__0x40c10f19(&mut self, to: Address, token_id: U256) -> Result<(), Vec<u8>> {
+        erc721::mint(&mut StylusHost, to, token_id)
+    }
+
+    fn owner_of__0x6352211e(&self, token_id: U256) -> Result<Address, Vec<u8>> {
+        erc721::owner_of(&StylusHost, token_id)
+    }
+
+    fn safe_transfer_from__0x42842e0e(
+        &mut self,
+        from: Address,
+        to: Address,
+        token_id: U256,
+    ) -> Result<(), Vec<u8>> {
+        erc721::safe_transfer_from(&mut StylusHost, from, to, token_id, &[])
+    }
+
+    fn safe_transfer_from__0xb88d4fde(
+        &mut self,
+        from: Address,
+        to: Address,
+        token_id: U256,
+        data: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        erc721::safe_transfer_from(&mut StylusHost, from, to, token_id, &data)
+    }
+
+    fn set_approval_for_all__0xa22cb465(
+        &mut self,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), Vec<u8>> {
+        erc721::set_approval_for_all(&mut StylusHost, operator, approved)
+    }
+
+    fn supports_interface__0x01ffc9a7(&self, interface_id: FixedBytes<4>) -> Result<bool, Vec<u8>> {
+        Ok(erc165::supports_interface(
+            interface_id.0,
+            &[erc721::INTERFACE_ID],
+        ))
+    }
+
+    fn transfer_from__0x23b872dd(
+        &mut self,
+        from: Address,
+        to: Address,
+        token_id: U256,
+    ) -> Result<(), Vec<u8>> {
+        erc721::transfer_from(&mut StylusHost, from, to, token_id)
+    }
+}
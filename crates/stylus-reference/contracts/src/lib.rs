@@ -0,0 +1,49 @@
+//! The `stylus-reference` tokens as deployable Stylus contracts.
+//!
+//! The `*_interface` modules are generated by `stylus-bindgen --mode implement` from `abis/` (see
+//! the workspace `sipb.toml`): a `#[storage]` entrypoint struct, a `#[public]` block exporting
+//! the ABI's selectors and a trait it routes to. The `erc20`, `erc721` and `erc1155` modules
+//! implement those traits with the reference logic, which keeps OpenZeppelin's storage layout.
+//!
+//! Every contract has its own `#[entrypoint]`, so each wasm enables exactly one feature:
+//!
+//! ```bash
+//! cargo build --release --target wasm32-unknown-unknown --features erc20
+//! ```
+//!
+//! The ABIs add an unrestricted `mint`, like the Solidity fixtures: these are test tokens. With
+//! `export-abi`, the crate builds natively and `tests/selectors.rs` checks the interface
+//! stylus-sdk exports against the ABI.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+extern crate alloc;
+
+#[cfg(not(any(feature = "erc20", feature = "erc721", feature = "erc1155")))]
+compile_error!("enable one of the `erc20`, `erc721` and `erc1155` features");
+
+#[cfg(any(
+    all(feature = "erc20", feature = "erc721"),
+    all(feature = "erc20", feature = "erc1155"),
+    all(feature = "erc721", feature = "erc1155"),
+))]
+compile_error!("the `erc20`, `erc721` and `erc1155` features each add an entrypoint; enable one");
+
+#[cfg(feature = "erc1155")]
+mod erc1155;
+#[cfg(feature = "erc1155")]
+mod erc1155_interface;
+#[cfg(feature = "erc20")]
+mod erc20;
+#[cfg(feature = "erc20")]
+mod erc20_interface;
+#[cfg(feature = "erc721")]
+mod erc721;
+#[cfg(feature = "erc721")]
+mod erc721_interface;
+
+#[cfg(feature = "erc1155")]
+pub use erc1155_interface::Erc1155;
+#[cfg(feature = "erc20")]
+pub use erc20_interface::Erc20;
+#[cfg(feature = "erc721")]
+pub use erc721_interface::Erc721;
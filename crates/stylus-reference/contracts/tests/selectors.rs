@@ -0,0 +1,73 @@
+//! The `#[public]` block of each contract exports exactly the selectors of its ABI, read from the
+//! Solidity interface stylus-sdk generates for it.
+
+#![cfg(feature = "export-abi")]
+
+use alloy_json_abi::JsonAbi;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::marker::PhantomData;
+use stylus_sdk::abi::export::GenerateAbi;
+use stylus_sdk::alloy_primitives::keccak256;
+
+struct Interface<T>(PhantomData<T>);
+
+impl<T: GenerateAbi> fmt::Display for Interface<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt_abi(f)
+    }
+}
+
+/// Selectors of the `function name(type arg, ..) ...;` lines of `T`'s interface.
+fn exported<T: GenerateAbi>() -> BTreeSet<[u8; 4]> {
+    let interface = Interface::<T>(PhantomData).to_string();
+    interface
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("function "))
+        .map(|function| {
+            let (name, rest) = function.split_once('(').unwrap();
+            let params = &rest[..rest.find(')').unwrap()];
+            let types: Vec<&str> = params
+                .split(',')
+                .filter_map(|param| param.split_whitespace().next())
+                .collect();
+            let signature = format!("{}({})", name, types.join(","));
+            keccak256(signature)[..4].try_into().unwrap()
+        })
+        .collect()
+}
+
+fn expected(name: &str) -> BTreeSet<[u8; 4]> {
+    let path = format!("{}/abis/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+    let abi: JsonAbi = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    abi.functions()
+        .map(|function| function.selector().0)
+        .collect()
+}
+
+#[cfg(feature = "erc20")]
+#[test]
+fn erc20_exports_its_abi() {
+    assert_eq!(
+        exported::<stylus_reference_contracts::Erc20>(),
+        expected("erc20")
+    );
+}
+
+#[cfg(feature = "erc721")]
+#[test]
+fn erc721_exports_its_abi() {
+    assert_eq!(
+        exported::<stylus_reference_contracts::Erc721>(),
+        expected("erc721")
+    );
+}
+
+#[cfg(feature = "erc1155")]
+#[test]
+fn erc1155_exports_its_abi() {
+    assert_eq!(
+        exported::<stylus_reference_contracts::Erc1155>(),
+        expected("erc1155")
+    );
+}
@@ -0,0 +1,312 @@
+//! ERC1155 with the functions of `abis/erc1155.json`, plus ERC165 `supportsInterface`.
+//!
+//! Transfers to contracts require `onERC1155Received` / `onERC1155BatchReceived` to return their
+//! selector. Tokens are created with [`mint`].
+
+use crate::{
+    erc165,
+    host::{check_call, nested_slot, revert, word, HostExt},
+    Host,
+};
+use alloy_primitives::{Address, FixedBytes, U256};
+use alloy_sol_types::{sol, Panic, SolCall, SolValue};
+use stylus_interfaces::{
+    erc1155::Calls,
+    transport::{CallKind, Request},
+};
+
+sol! {
+    event TransferSingle(
+        address indexed operator,
+        address indexed from,
+        address indexed to,
+        uint256 id,
+        uint256 value
+    );
+    event TransferBatch(
+        address indexed operator,
+        address indexed from,
+        address indexed to,
+        uint256[] ids,
+        uint256[] values
+    );
+    event ApprovalForAll(address indexed account, address indexed operator, bool approved);
+
+    error ERC1155InsufficientBalance(address sender, uint256 balance, uint256 needed, uint256 tokenId);
+    error ERC1155InvalidSender(address sender);
+    error ERC1155InvalidReceiver(address receiver);
+    error ERC1155MissingApprovalForAll(address operator, address owner);
+    error ERC1155InvalidOperator(address operator);
+    error ERC1155InvalidArrayLength(uint256 idsLength, uint256 valuesLength);
+
+    function onERC1155Received(address operator, address from, uint256 id, uint256 value, bytes data)
+        returns (bytes4);
+    function onERC1155BatchReceived(
+        address operator,
+        address from,
+        uint256[] ids,
+        uint256[] values,
+        bytes data
+    ) returns (bytes4);
+}
+
+/// ERC1155 interface ID: the XOR of the selectors in `abis/erc1155.json`.
+pub const INTERFACE_ID: [u8; 4] = [0xd9, 0xb6, 0x7a, 0x26];
+
+/// `mapping(uint256 id => mapping(address account => uint256))`
+const BALANCES: U256 = U256::from_limbs([0, 0, 0, 0]);
+/// `mapping(address => mapping(address => bool))`
+const OPERATOR_APPROVALS: U256 = U256::from_limbs([1, 0, 0, 0]);
+
+/// Handles `calldata`; unknown selectors and bad arguments revert without data.
+pub fn route(host: &mut impl Host, calldata: &[u8]) -> Result<Vec<u8>, Vec<u8>> {
+    if erc165::is_supports_interface(calldata) {
+        return erc165::route(host, calldata, &[INTERFACE_ID]);
+    }
+    match check_call(host, Calls::abi_decode(calldata))? {
+        Calls::BalanceOf__0x00fdd58e { account, id } => {
+            return Ok(balance_of(host, account, id).abi_encode());
+        }
+        Calls::BalanceOfBatch__0x4e1273f4 { accounts, ids } => {
+            return balance_of_batch(host, &accounts, &ids).map(|balances| balances.abi_encode());
+        }
+        Calls::IsApprovedForAll__0xe985e9c5 { account, operator } => {
+            return Ok(is_approved_for_all(host, account, operator).abi_encode());
+        }
+        Calls::SafeBatchTransferFrom__0x2eb2c2d6 {
+            from,
+            to,
+            ids,
+            values,
+            data,
+        } => {
+            safe_batch_transfer_from(host, from, to, &ids, &values, &data)?;
+        }
+        Calls::SafeTransferFrom__0xf242432a {
+            from,
+            to,
+            id,
+            value,
+            data,
+        } => {
+            safe_transfer_from(host, from, to, id, value, &data)?;
+        }
+        Calls::SetApprovalForAll__0xa22cb465 { operator, approved } => {
+            set_approval_for_all(host, operator, approved)?;
+        }
+    }
+    Ok(Vec::new())
+}
+
+pub fn balance_of(host: &impl Host, account: Address, id: U256) -> U256 {
+    host.load_u256(nested_slot(BALANCES, word(id), account.into_word()))
+}
+
+pub fn balance_of_batch(
+    host: &impl Host,
+    accounts: &[Address],
+    ids: &[U256],
+) -> Result<Vec<U256>, Vec<u8>> {
+    if accounts.len() != ids.len() {
+        return revert(ERC1155InvalidArrayLength {
+            idsLength: U256::from(ids.len()),
+            valuesLength: U256::from(accounts.len()),
+        });
+    }
+    let balances = accounts.iter().zip(ids);
+    Ok(balances
+        .map(|(account, id)| balance_of(host, *account, *id))
+        .collect())
+}
+
+pub fn is_approved_for_all(host: &impl Host, account: Address, operator: Address) -> bool {
+    host.load_bool(nested_slot(
+        OPERATOR_APPROVALS,
+        account.into_word(),
+        operator.into_word(),
+    ))
+}
+
+pub fn set_approval_for_all(
+    host: &mut impl Host,
+    operator: Address,
+    approved: bool,
+) -> Result<(), Vec<u8>> {
+    if operator.is_zero() {
+        return revert(ERC1155InvalidOperator { operator });
+    }
+    let account = host.sender();
+    let slot = nested_slot(
+        OPERATOR_APPROVALS,
+        account.into_word(),
+        operator.into_word(),
+    );
+    host.store_bool(slot, approved);
+    host.emit_event(&ApprovalForAll {
+        account,
+        operator,
+        approved,
+    });
+    Ok(())
+}
+
+pub fn safe_transfer_from(
+    host: &mut impl Host,
+    from: Address,
+    to: Address,
+    id: U256,
+    value: U256,
+    data: &[u8],
+) -> Result<(), Vec<u8>> {
+    safe_batch_transfer_from(host, from, to, &[id], &[value], data)
+}
+
+pub fn safe_batch_transfer_from(
+    host: &mut impl Host,
+    from: Address,
+    to: Address,
+    ids: &[U256],
+    values: &[U256],
+    data: &[u8],
+) -> Result<(), Vec<u8>> {
+    let sender = host.sender();
+    if from != sender && !is_approved_for_all(host, from, sender) {
+        return revert(ERC1155MissingApprovalForAll {
+            operator: sender,
+            owner: from,
+        });
+    }
+    if to.is_zero() {
+        return revert(ERC1155InvalidReceiver { receiver: to });
+    }
+    if from.is_zero() {
+        return revert(ERC1155InvalidSender { sender: from });
+    }
+    update_with_acceptance_check(host, from, to, ids, values, data)
+}
+
+/// Creates `value` of token `id` for `to`, calling its receiver hook if it is a contract.
+pub fn mint(
+    host: &mut impl Host,
+    to: Address,
+    id: U256,
+    value: U256,
+    data: &[u8],
+) -> Result<(), Vec<u8>> {
+    if to.is_zero() {
+        return revert(ERC1155InvalidReceiver { receiver: to });
+    }
+    update_with_acceptance_check(host, Address::ZERO, to, &[id], &[value], data)
+}
+
+/// Moves `values` of `ids` from `from` to `to`; the zero address mints or burns. A single id
+/// emits `TransferSingle`, anything else `TransferBatch`.
+fn update(
+    host: &mut impl Host,
+    from: Address,
+    to: Address,
+    ids: &[U256],
+    values: &[U256],
+) -> Result<(), Vec<u8>> {
+    if ids.len() != values.len() {
+        return revert(ERC1155InvalidArrayLength {
+            idsLength: U256::from(ids.len()),
+            valuesLength: U256::from(values.len()),
+        });
+    }
+    for (&id, &value) in ids.iter().zip(values) {
+        if !from.is_zero() {
+            let balance = balance_of(host, from, id);
+            if balance < value {
+                return revert(ERC1155InsufficientBalance {
+                    sender: from,
+                    balance,
+                    needed: value,
+                    tokenId: id,
+                });
+            }
+            let slot = nested_slot(BALANCES, word(id), from.into_word());
+            host.store_u256(slot, balance - value);
+        }
+        if !to.is_zero() {
+            let balance = balance_of(host, to, id);
+            let Some(balance) = balance.checked_add(value) else {
+                return revert(Panic {
+                    code: U256::from(0x11),
+                });
+            };
+            host.store_u256(nested_slot(BALANCES, word(id), to.into_word()), balance);
+        }
+    }
+    let operator = host.sender();
+    if let ([id], [value]) = (ids, values) {
+        host.emit_event(&TransferSingle {
+            operator,
+            from,
+            to,
+            id: *id,
+            value: *value,
+        });
+    } else {
+        host.emit_event(&TransferBatch {
+            operator,
+            from,
+            to,
+            ids: ids.to_vec(),
+            values: values.to_vec(),
+        });
+    }
+    Ok(())
+}
+
+fn update_with_acceptance_check(
+    host: &mut impl Host,
+    from: Address,
+    to: Address,
+    ids: &[U256],
+    values: &[U256],
+    data: &[u8],
+) -> Result<(), Vec<u8>> {
+    update(host, from, to, ids, values)?;
+    if to.is_zero() || !host.has_code(to) {
+        return Ok(());
+    }
+    let operator = host.sender();
+    let (calldata, selector) = if let ([id], [value]) = (ids, values) {
+        let call = onERC1155ReceivedCall {
+            operator,
+            from,
+            id: *id,
+            value: *value,
+            data: data.to_vec().into(),
+        };
+        (call.abi_encode(), onERC1155ReceivedCall::SELECTOR)
+    } else {
+        let call = onERC1155BatchReceivedCall {
+            operator,
+            from,
+            ids: ids.to_vec(),
+            values: values.to_vec(),
+            data: data.to_vec().into(),
+        };
+        (call.abi_encode(), onERC1155BatchReceivedCall::SELECTOR)
+    };
+    let result = host.send(Request {
+        address: to,
+        calldata: &calldata,
+        kind: CallKind::Call,
+        value: U256::ZERO,
+        gas: None,
+        max_return: None,
+    });
+    match result {
+        // Both hooks return a single `bytes4`.
+        Ok(output) => match <FixedBytes<4>>::abi_decode(&output, true) {
+            Ok(accepted) if accepted == FixedBytes(selector) => Ok(()),
+            _ => revert(ERC1155InvalidReceiver { receiver: to }),
+        },
+        // A receiver reverting without reason is treated as one that doesn't implement the hook.
+        Err(reason) if reason.is_empty() => revert(ERC1155InvalidReceiver { receiver: to }),
+        Err(reason) => Err(reason),
+    }
+}
@@ -0,0 +1,33 @@
+//! ERC165 with the function of `abis/ierc165.json`: `supportsInterface`.
+//!
+//! The token modules answer `supportsInterface` through [`route`] with their own interface IDs.
+
+use crate::{host::check_call, Host};
+use alloy_sol_types::SolValue;
+use stylus_interfaces::ierc165::Calls;
+
+/// `supportsInterface(bytes4)`, the ERC165 interface ID.
+pub const INTERFACE_ID: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+
+/// Handles `calldata` for a contract implementing `interfaces` besides ERC165 itself.
+pub fn route(
+    host: &mut impl Host,
+    calldata: &[u8],
+    interfaces: &[[u8; 4]],
+) -> Result<Vec<u8>, Vec<u8>> {
+    match check_call(host, Calls::abi_decode(calldata))? {
+        Calls::SupportsInterface__0x01ffc9a7 { interfaceId } => {
+            Ok(supports_interface(interfaceId.0, interfaces).abi_encode())
+        }
+    }
+}
+
+/// Whether a contract implementing `interfaces` reports `interface_id`; never `0xffffffff`.
+pub fn supports_interface(interface_id: [u8; 4], interfaces: &[[u8; 4]]) -> bool {
+    interface_id == INTERFACE_ID || interfaces.contains(&interface_id)
+}
+
+/// Whether `calldata` is a `supportsInterface` call, which token contracts hand to [`route`].
+pub(crate) fn is_supports_interface(calldata: &[u8]) -> bool {
+    calldata.starts_with(&stylus_interfaces::ierc165::selectors::supports_interface__0x01ffc9a7)
+}
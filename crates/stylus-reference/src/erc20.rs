@@ -0,0 +1,169 @@
+//! ERC20 with the functions of `abis/erc20.json`, the six EIP-20 requires.
+//!
+//! Tokens enter circulation through [`mint`], which a deployment wires up as it sees fit.
+
+use crate::{
+    host::{check_call, mapping_slot, nested_slot, revert, HostExt},
+    Host,
+};
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, Panic, SolValue};
+use stylus_interfaces::erc20::Calls;
+
+sol! {
+    event Transfer(address indexed from, address indexed to, uint256 value);
+    event Approval(address indexed owner, address indexed spender, uint256 value);
+
+    error ERC20InsufficientBalance(address sender, uint256 balance, uint256 needed);
+    error ERC20InsufficientAllowance(address spender, uint256 allowance, uint256 needed);
+    error ERC20InvalidSender(address sender);
+    error ERC20InvalidReceiver(address receiver);
+    error ERC20InvalidApprover(address approver);
+    error ERC20InvalidSpender(address spender);
+}
+
+/// `mapping(address => uint256)`
+const BALANCES: U256 = U256::from_limbs([0, 0, 0, 0]);
+/// `mapping(address => mapping(address => uint256))`
+const ALLOWANCES: U256 = U256::from_limbs([1, 0, 0, 0]);
+const TOTAL_SUPPLY: U256 = U256::from_limbs([2, 0, 0, 0]);
+
+/// Handles `calldata`; unknown selectors and bad arguments revert without data.
+pub fn route(host: &mut impl Host, calldata: &[u8]) -> Result<Vec<u8>, Vec<u8>> {
+    match check_call(host, Calls::abi_decode(calldata))? {
+        Calls::Allowance__0xdd62ed3e { owner, spender } => {
+            Ok(allowance(host, owner, spender).abi_encode())
+        }
+        Calls::Approve__0x095ea7b3 { spender, value } => {
+            approve(host, spender, value).map(|ok| ok.abi_encode())
+        }
+        Calls::BalanceOf__0x70a08231 { owner } => Ok(balance_of(host, owner).abi_encode()),
+        Calls::TotalSupply__0x18160ddd => Ok(total_supply(host).abi_encode()),
+        Calls::Transfer__0xa9059cbb { to, value } => {
+            transfer(host, to, value).map(|ok| ok.abi_encode())
+        }
+        Calls::TransferFrom__0x23b872dd { from, to, value } => {
+            transfer_from(host, from, to, value).map(|ok| ok.abi_encode())
+        }
+    }
+}
+
+pub fn balance_of(host: &impl Host, owner: Address) -> U256 {
+    host.load_u256(mapping_slot(BALANCES, owner.into_word()))
+}
+
+pub fn total_supply(host: &impl Host) -> U256 {
+    host.load_u256(TOTAL_SUPPLY)
+}
+
+/// Allowance of `spender` over `owner`'s tokens.
+pub fn allowance(host: &impl Host, owner: Address, spender: Address) -> U256 {
+    host.load_u256(nested_slot(
+        ALLOWANCES,
+        owner.into_word(),
+        spender.into_word(),
+    ))
+}
+
+pub fn transfer(host: &mut impl Host, to: Address, value: U256) -> Result<bool, Vec<u8>> {
+    let from = host.sender();
+    if from.is_zero() {
+        return revert(ERC20InvalidSender { sender: from });
+    }
+    if to.is_zero() {
+        return revert(ERC20InvalidReceiver { receiver: to });
+    }
+    update(host, from, to, value)?;
+    Ok(true)
+}
+
+/// Moves `value` of `from`'s tokens to `to` on behalf of the sender, spending its allowance.
+/// An allowance of `U256::MAX` is never spent.
+pub fn transfer_from(
+    host: &mut impl Host,
+    from: Address,
+    to: Address,
+    value: U256,
+) -> Result<bool, Vec<u8>> {
+    let spender = host.sender();
+    let allowance = allowance(host, from, spender);
+    if allowance != U256::MAX {
+        if allowance < value {
+            return revert(ERC20InsufficientAllowance {
+                spender,
+                allowance,
+                needed: value,
+            });
+        }
+        let slot = nested_slot(ALLOWANCES, from.into_word(), spender.into_word());
+        host.store_u256(slot, allowance - value);
+    }
+    if from.is_zero() {
+        return revert(ERC20InvalidSender { sender: from });
+    }
+    if to.is_zero() {
+        return revert(ERC20InvalidReceiver { receiver: to });
+    }
+    update(host, from, to, value)?;
+    Ok(true)
+}
+
+pub fn approve(host: &mut impl Host, spender: Address, value: U256) -> Result<bool, Vec<u8>> {
+    let owner = host.sender();
+    if owner.is_zero() {
+        return revert(ERC20InvalidApprover { approver: owner });
+    }
+    if spender.is_zero() {
+        return revert(ERC20InvalidSpender { spender });
+    }
+    let slot = nested_slot(ALLOWANCES, owner.into_word(), spender.into_word());
+    host.store_u256(slot, value);
+    host.emit_event(&Approval {
+        owner,
+        spender,
+        value,
+    });
+    Ok(true)
+}
+
+/// Creates `value` tokens for `to`.
+pub fn mint(host: &mut impl Host, to: Address, value: U256) -> Result<(), Vec<u8>> {
+    if to.is_zero() {
+        return revert(ERC20InvalidReceiver { receiver: to });
+    }
+    update(host, Address::ZERO, to, value)
+}
+
+/// Moves `value` from `from` to `to`; the zero address mints or burns.
+fn update(host: &mut impl Host, from: Address, to: Address, value: U256) -> Result<(), Vec<u8>> {
+    if from.is_zero() {
+        let supply = total_supply(host);
+        let Some(supply) = supply.checked_add(value) else {
+            // Arithmetic overflow, as solc's checked arithmetic reports it.
+            return revert(Panic {
+                code: U256::from(0x11),
+            });
+        };
+        host.store_u256(TOTAL_SUPPLY, supply);
+    } else {
+        let balance = balance_of(host, from);
+        if balance < value {
+            return revert(ERC20InsufficientBalance {
+                sender: from,
+                balance,
+                needed: value,
+            });
+        }
+        host.store_u256(mapping_slot(BALANCES, from.into_word()), balance - value);
+    }
+    if to.is_zero() {
+        let supply = total_supply(host);
+        host.store_u256(TOTAL_SUPPLY, supply - value);
+    } else {
+        // Cannot overflow: balances sum to the total supply, which was checked above.
+        let balance = balance_of(host, to);
+        host.store_u256(mapping_slot(BALANCES, to.into_word()), balance + value);
+    }
+    host.emit_event(&Transfer { from, to, value });
+    Ok(())
+}
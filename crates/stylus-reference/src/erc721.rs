@@ -0,0 +1,280 @@
+//! ERC721 with the functions of `abis/erc721.json`, plus ERC165 `supportsInterface`.
+//!
+//! Safe transfers to contracts require `onERC721Received` to return its selector. Tokens are
+//! created with [`mint`].
+
+use crate::{
+    erc165,
+    host::{check_call, mapping_slot, nested_slot, revert, word, HostExt},
+    Host,
+};
+use alloy_primitives::{Address, FixedBytes, U256};
+use alloy_sol_types::{sol, SolCall, SolValue};
+use stylus_interfaces::{
+    erc721::Calls,
+    transport::{CallKind, Request},
+};
+
+sol! {
+    event Transfer(address indexed from, address indexed to, uint256 indexed tokenId);
+    event Approval(address indexed owner, address indexed approved, uint256 indexed tokenId);
+    event ApprovalForAll(address indexed owner, address indexed operator, bool approved);
+
+    error ERC721InvalidOwner(address owner);
+    error ERC721NonexistentToken(uint256 tokenId);
+    error ERC721IncorrectOwner(address sender, uint256 tokenId, address owner);
+    error ERC721InvalidSender(address sender);
+    error ERC721InvalidReceiver(address receiver);
+    error ERC721InsufficientApproval(address operator, uint256 tokenId);
+    error ERC721InvalidApprover(address approver);
+    error ERC721InvalidOperator(address operator);
+
+    function onERC721Received(address operator, address from, uint256 tokenId, bytes data)
+        returns (bytes4);
+}
+
+/// ERC721 interface ID: the XOR of the selectors in `abis/erc721.json`.
+pub const INTERFACE_ID: [u8; 4] = [0x80, 0xac, 0x58, 0xcd];
+
+/// `mapping(uint256 => address)`
+const OWNERS: U256 = U256::from_limbs([0, 0, 0, 0]);
+/// `mapping(address => uint256)`
+const BALANCES: U256 = U256::from_limbs([1, 0, 0, 0]);
+/// `mapping(uint256 => address)`
+const TOKEN_APPROVALS: U256 = U256::from_limbs([2, 0, 0, 0]);
+/// `mapping(address => mapping(address => bool))`
+const OPERATOR_APPROVALS: U256 = U256::from_limbs([3, 0, 0, 0]);
+
+/// Handles `calldata`; unknown selectors and bad arguments revert without data.
+pub fn route(host: &mut impl Host, calldata: &[u8]) -> Result<Vec<u8>, Vec<u8>> {
+    if erc165::is_supports_interface(calldata) {
+        return erc165::route(host, calldata, &[INTERFACE_ID]);
+    }
+    match check_call(host, Calls::abi_decode(calldata))? {
+        Calls::Approve__0x095ea7b3 { to, tokenId } => {
+            approve(host, to, tokenId)?;
+        }
+        Calls::BalanceOf__0x70a08231 { owner } => {
+            return balance_of(host, owner).map(|balance| balance.abi_encode());
+        }
+        Calls::GetApproved__0x081812fc { tokenId } => {
+            return get_approved(host, tokenId).map(|approved| approved.abi_encode());
+        }
+        Calls::IsApprovedForAll__0xe985e9c5 { owner, operator } => {
+            return Ok(is_approved_for_all(host, owner, operator).abi_encode());
+        }
+        Calls::OwnerOf__0x6352211e { tokenId } => {
+            return owner_of(host, tokenId).map(|owner| owner.abi_encode());
+        }
+        Calls::SafeTransferFrom__0x42842e0e { from, to, tokenId } => {
+            safe_transfer_from(host, from, to, tokenId, &[])?;
+        }
+        Calls::SafeTransferFrom__0xb88d4fde {
+            from,
+            to,
+            tokenId,
+            data,
+        } => {
+            safe_transfer_from(host, from, to, tokenId, &data)?;
+        }
+        Calls::SetApprovalForAll__0xa22cb465 { operator, approved } => {
+            set_approval_for_all(host, operator, approved)?;
+        }
+        Calls::TransferFrom__0x23b872dd { from, to, tokenId } => {
+            transfer_from(host, from, to, tokenId)?;
+        }
+    }
+    Ok(Vec::new())
+}
+
+pub fn balance_of(host: &impl Host, owner: Address) -> Result<U256, Vec<u8>> {
+    if owner.is_zero() {
+        return revert(ERC721InvalidOwner { owner });
+    }
+    Ok(host.load_u256(mapping_slot(BALANCES, owner.into_word())))
+}
+
+pub fn owner_of(host: &impl Host, token_id: U256) -> Result<Address, Vec<u8>> {
+    match owner(host, token_id) {
+        owner if owner.is_zero() => revert(ERC721NonexistentToken { tokenId: token_id }),
+        owner => Ok(owner),
+    }
+}
+
+pub fn get_approved(host: &impl Host, token_id: U256) -> Result<Address, Vec<u8>> {
+    owner_of(host, token_id)?;
+    Ok(host.load_address(mapping_slot(TOKEN_APPROVALS, word(token_id))))
+}
+
+pub fn is_approved_for_all(host: &impl Host, owner: Address, operator: Address) -> bool {
+    host.load_bool(nested_slot(
+        OPERATOR_APPROVALS,
+        owner.into_word(),
+        operator.into_word(),
+    ))
+}
+
+/// Approves `to` for `token_id`; the sender must own it or be an operator of its owner.
+pub fn approve(host: &mut impl Host, to: Address, token_id: U256) -> Result<(), Vec<u8>> {
+    let sender = host.sender();
+    let owner = owner_of(host, token_id)?;
+    if sender != owner && !is_approved_for_all(host, owner, sender) {
+        return revert(ERC721InvalidApprover { approver: sender });
+    }
+    host.store_address(mapping_slot(TOKEN_APPROVALS, word(token_id)), to);
+    host.emit_event(&Approval {
+        owner,
+        approved: to,
+        tokenId: token_id,
+    });
+    Ok(())
+}
+
+pub fn set_approval_for_all(
+    host: &mut impl Host,
+    operator: Address,
+    approved: bool,
+) -> Result<(), Vec<u8>> {
+    if operator.is_zero() {
+        return revert(ERC721InvalidOperator { operator });
+    }
+    let owner = host.sender();
+    let slot = nested_slot(OPERATOR_APPROVALS, owner.into_word(), operator.into_word());
+    host.store_bool(slot, approved);
+    host.emit_event(&ApprovalForAll {
+        owner,
+        operator,
+        approved,
+    });
+    Ok(())
+}
+
+pub fn transfer_from(
+    host: &mut impl Host,
+    from: Address,
+    to: Address,
+    token_id: U256,
+) -> Result<(), Vec<u8>> {
+    if to.is_zero() {
+        return revert(ERC721InvalidReceiver { receiver: to });
+    }
+    let sender = host.sender();
+    let previous_owner = update(host, to, token_id, sender)?;
+    if previous_owner != from {
+        return revert(ERC721IncorrectOwner {
+            sender: from,
+            tokenId: token_id,
+            owner: previous_owner,
+        });
+    }
+    Ok(())
+}
+
+pub fn safe_transfer_from(
+    host: &mut impl Host,
+    from: Address,
+    to: Address,
+    token_id: U256,
+    data: &[u8],
+) -> Result<(), Vec<u8>> {
+    transfer_from(host, from, to, token_id)?;
+    let operator = host.sender();
+    check_on_received(host, operator, from, to, token_id, data)
+}
+
+/// Creates `token_id` for `to` without a receiver check.
+pub fn mint(host: &mut impl Host, to: Address, token_id: U256) -> Result<(), Vec<u8>> {
+    if to.is_zero() {
+        return revert(ERC721InvalidReceiver { receiver: to });
+    }
+    let previous_owner = update(host, to, token_id, Address::ZERO)?;
+    if !previous_owner.is_zero() {
+        return revert(ERC721InvalidSender {
+            sender: Address::ZERO,
+        });
+    }
+    Ok(())
+}
+
+fn owner(host: &impl Host, token_id: U256) -> Address {
+    host.load_address(mapping_slot(OWNERS, word(token_id)))
+}
+
+/// Moves `token_id` to `to`, checking that `auth` may do so unless it is zero. Returns the
+/// previous owner.
+fn update(
+    host: &mut impl Host,
+    to: Address,
+    token_id: U256,
+    auth: Address,
+) -> Result<Address, Vec<u8>> {
+    let from = owner(host, token_id);
+    if !auth.is_zero() {
+        if from.is_zero() {
+            return revert(ERC721NonexistentToken { tokenId: token_id });
+        }
+        let approved = host.load_address(mapping_slot(TOKEN_APPROVALS, word(token_id)));
+        if auth != from && !is_approved_for_all(host, from, auth) && auth != approved {
+            return revert(ERC721InsufficientApproval {
+                operator: auth,
+                tokenId: token_id,
+            });
+        }
+    }
+    if !from.is_zero() {
+        host.store_address(mapping_slot(TOKEN_APPROVALS, word(token_id)), Address::ZERO);
+        let slot = mapping_slot(BALANCES, from.into_word());
+        let balance = host.load_u256(slot);
+        host.store_u256(slot, balance - U256::from(1));
+    }
+    if !to.is_zero() {
+        let slot = mapping_slot(BALANCES, to.into_word());
+        let balance = host.load_u256(slot);
+        host.store_u256(slot, balance + U256::from(1));
+    }
+    host.store_address(mapping_slot(OWNERS, word(token_id)), to);
+    host.emit_event(&Transfer {
+        from,
+        to,
+        tokenId: token_id,
+    });
+    Ok(from)
+}
+
+/// Calls `onERC721Received` on `to` if it has code; accounts without code always accept.
+fn check_on_received(
+    host: &mut impl Host,
+    operator: Address,
+    from: Address,
+    to: Address,
+    token_id: U256,
+    data: &[u8],
+) -> Result<(), Vec<u8>> {
+    if !host.has_code(to) {
+        return Ok(());
+    }
+    let call = onERC721ReceivedCall {
+        operator,
+        from,
+        tokenId: token_id,
+        data: data.to_vec().into(),
+    };
+    let calldata = call.abi_encode();
+    let result = host.send(Request {
+        address: to,
+        calldata: &calldata,
+        kind: CallKind::Call,
+        value: U256::ZERO,
+        gas: None,
+        max_return: None,
+    });
+    match result {
+        Ok(output) => match <FixedBytes<4>>::abi_decode(&output, true) {
+            Ok(accepted) if accepted == FixedBytes(onERC721ReceivedCall::SELECTOR) => Ok(()),
+            _ => revert(ERC721InvalidReceiver { receiver: to }),
+        },
+        // A receiver reverting without reason is treated as one that doesn't implement the hook.
+        Err(reason) if reason.is_empty() => revert(ERC721InvalidReceiver { receiver: to }),
+        Err(reason) => Err(reason),
+    }
+}
@@ -0,0 +1,139 @@
+//! What reference contracts need from the chain, and storage helpers on top of it.
+
+use alloy_primitives::{keccak256, Address, LogData, B256, U256};
+use alloy_sol_types::{SolError, SolEvent};
+use stylus_interfaces::transport::{Request, StylusTransport, Transport};
+use stylus_sdk::{
+    evm, msg,
+    storage::{GlobalStorage, StorageCache},
+};
+
+/// Environment of a running reference contract. Calls to other contracts go through
+/// [`Transport`].
+pub trait Host: Transport {
+    /// `msg.sender` of the current call.
+    fn sender(&self) -> Address;
+
+    /// `msg.value` of the current call.
+    fn value(&self) -> U256;
+
+    fn load(&self, slot: U256) -> B256;
+
+    fn store(&mut self, slot: U256, value: B256);
+
+    fn emit(&mut self, log: LogData);
+}
+
+/// The Stylus VM: storage cache, `msg` and `raw_log` hostios.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StylusHost;
+
+impl Transport for StylusHost {
+    fn send(&self, request: Request<'_>) -> Result<Vec<u8>, Vec<u8>> {
+        // Receiver hooks may read this contract's state, so it has to be written back first.
+        StorageCache::flush();
+        StylusTransport.send(request)
+    }
+
+    unsafe fn send_delegate(&self, request: Request<'_>) -> Result<Vec<u8>, Vec<u8>> {
+        // SAFETY: forwarded unchanged; the caller upholds the contract.
+        unsafe { StylusTransport.send_delegate(request) }
+    }
+
+    fn has_code(&self, address: Address) -> bool {
+        StylusTransport.has_code(address)
+    }
+}
+
+impl Host for StylusHost {
+    fn sender(&self) -> Address {
+        msg::sender()
+    }
+
+    fn value(&self) -> U256 {
+        msg::value()
+    }
+
+    fn load(&self, slot: U256) -> B256 {
+        StorageCache::get_word(slot)
+    }
+
+    fn store(&mut self, slot: U256, value: B256) {
+        // SAFETY: reference contracts only access storage through the host, so no storage
+        // types hold cached references to this slot.
+        unsafe { StorageCache::set_word(slot, value) }
+    }
+
+    fn emit(&mut self, log: LogData) {
+        // `raw_log` only fails for more than four topics, which no event here has.
+        let _ = evm::raw_log(log.topics(), &log.data);
+    }
+}
+
+/// Typed storage access and event emission for every [`Host`].
+pub(crate) trait HostExt: Host {
+    fn load_u256(&self, slot: U256) -> U256 {
+        U256::from_be_bytes(self.load(slot).0)
+    }
+
+    fn store_u256(&mut self, slot: U256, value: U256) {
+        self.store(slot, B256::from(value.to_be_bytes::<32>()));
+    }
+
+    fn load_address(&self, slot: U256) -> Address {
+        Address::from_word(self.load(slot))
+    }
+
+    fn store_address(&mut self, slot: U256, value: Address) {
+        self.store(slot, value.into_word());
+    }
+
+    fn load_bool(&self, slot: U256) -> bool {
+        !self.load(slot).is_zero()
+    }
+
+    fn store_bool(&mut self, slot: U256, value: bool) {
+        self.store_u256(slot, U256::from(value as u8));
+    }
+
+    fn emit_event(&mut self, event: &impl SolEvent) {
+        let topics = event
+            .encode_topics()
+            .into_iter()
+            .map(|topic| topic.0)
+            .collect();
+        self.emit(LogData::new_unchecked(topics, event.encode_data().into()));
+    }
+}
+
+impl<H: Host + ?Sized> HostExt for H {}
+
+/// Slot of `mapping[key]` for a mapping declared at `slot`, laid out like Solidity does.
+pub(crate) fn mapping_slot(slot: U256, key: B256) -> U256 {
+    let mut preimage = [0; 64];
+    preimage[..32].copy_from_slice(key.as_slice());
+    preimage[32..].copy_from_slice(&slot.to_be_bytes::<32>());
+    U256::from_be_bytes(keccak256(preimage).0)
+}
+
+/// Slot of `mapping[outer][inner]` for a nested mapping declared at `slot`.
+pub(crate) fn nested_slot(slot: U256, outer: B256, inner: B256) -> U256 {
+    mapping_slot(mapping_slot(slot, outer), inner)
+}
+
+pub(crate) fn word(value: U256) -> B256 {
+    B256::from(value.to_be_bytes::<32>())
+}
+
+/// Revert data for `error`.
+pub(crate) fn revert<T>(error: impl SolError) -> Result<T, Vec<u8>> {
+    Err(error.abi_encode())
+}
+
+/// Rejects calldata that doesn't decode and ether sent to non-payable functions, like solc does.
+pub(crate) fn check_call<C, E>(host: &impl Host, call: Result<C, E>) -> Result<C, Vec<u8>> {
+    match call {
+        Ok(call) if host.value().is_zero() => Ok(call),
+        _ => Err(Vec::new()),
+    }
+}
@@ -0,0 +1,19 @@
+//! Reference Stylus implementations of the interface packs.
+//!
+//! [`erc20`], [`erc721`], [`erc1155`] and [`erc165`] each implement one ABI in `abis/`, with
+//! OpenZeppelin's events, custom errors and receiver checks. Their `route` functions dispatch
+//! calldata through the pack's `Calls` enum, so they answer exactly the selectors the bindings
+//! call. Contract logic reaches its environment through a [`Host`]: [`StylusHost`] on chain and
+//! [`MemoryHost`](memory::MemoryHost) in native tests.
+//!
+//! The `contracts` crate next to this one deploys them: `#[public]` skeletons generated by
+//! `stylus-bindgen --mode implement`, implemented with these modules on [`StylusHost`].
+
+pub mod erc1155;
+pub mod erc165;
+pub mod erc20;
+pub mod erc721;
+mod host;
+pub mod memory;
+
+pub use host::{Host, StylusHost};
@@ -0,0 +1,113 @@
+//! Reference contracts simulated on an [`InProcessTransport`], for native tests.
+
+use crate::Host;
+use alloy_primitives::{Address, LogData, B256, U256};
+use core::cell::RefCell;
+use std::{collections::BTreeMap, rc::Rc};
+use stylus_interfaces::transport::{InProcessTransport, Request, Transport};
+
+/// Storage and logs of one simulated contract.
+///
+/// Calls that revert leave both untouched, as on chain.
+#[derive(Default)]
+pub struct MemoryContract {
+    storage: RefCell<BTreeMap<U256, B256>>,
+    logs: RefCell<Vec<LogData>>,
+}
+
+impl MemoryContract {
+    /// Deploys a contract at `address` whose calls are handled by `route`, e.g.
+    /// `|host, calldata| erc20::route(host, calldata)`.
+    pub fn deploy(
+        transport: &mut InProcessTransport,
+        address: Address,
+        route: impl Fn(&mut MemoryHost<'_>, &[u8]) -> Result<Vec<u8>, Vec<u8>> + 'static,
+    ) -> Rc<Self> {
+        let contract = Rc::new(Self::default());
+        let state = Rc::clone(&contract);
+        transport.register(address, move |transport, request| {
+            let storage = state.storage.borrow().clone();
+            let logs = state.logs.borrow().len();
+            let mut host = MemoryHost {
+                contract: &state,
+                transport,
+                value: request.value,
+            };
+            let result = route(&mut host, request.calldata);
+            if result.is_err() {
+                *state.storage.borrow_mut() = storage;
+                state.logs.borrow_mut().truncate(logs);
+            }
+            result
+        });
+        contract
+    }
+
+    /// Host for calling internal functions such as `erc20::mint` directly, as `transport`'s
+    /// current sender.
+    pub fn host<'a>(&'a self, transport: &'a InProcessTransport) -> MemoryHost<'a> {
+        MemoryHost {
+            contract: self,
+            transport,
+            value: U256::ZERO,
+        }
+    }
+
+    /// Logs emitted since the last `take_logs`, oldest first.
+    pub fn take_logs(&self) -> Vec<LogData> {
+        self.logs.take()
+    }
+
+    /// Reads a raw storage slot.
+    pub fn load(&self, slot: U256) -> B256 {
+        self.storage
+            .borrow()
+            .get(&slot)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// [`Host`] of a [`MemoryContract`] handling one call.
+pub struct MemoryHost<'a> {
+    contract: &'a MemoryContract,
+    transport: &'a InProcessTransport,
+    value: U256,
+}
+
+impl Transport for MemoryHost<'_> {
+    fn send(&self, request: Request<'_>) -> Result<Vec<u8>, Vec<u8>> {
+        self.transport.send(request)
+    }
+
+    unsafe fn send_delegate(&self, request: Request<'_>) -> Result<Vec<u8>, Vec<u8>> {
+        // SAFETY: forwarded unchanged; the caller upholds the contract.
+        unsafe { self.transport.send_delegate(request) }
+    }
+
+    fn has_code(&self, address: Address) -> bool {
+        self.transport.has_code(address)
+    }
+}
+
+impl Host for MemoryHost<'_> {
+    fn sender(&self) -> Address {
+        self.transport.sender()
+    }
+
+    fn value(&self) -> U256 {
+        self.value
+    }
+
+    fn load(&self, slot: U256) -> B256 {
+        self.contract.load(slot)
+    }
+
+    fn store(&mut self, slot: U256, value: B256) {
+        self.contract.storage.borrow_mut().insert(slot, value);
+    }
+
+    fn emit(&mut self, log: LogData) {
+        self.contract.logs.borrow_mut().push(log);
+    }
+}
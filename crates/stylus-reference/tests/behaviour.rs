@@ -0,0 +1,351 @@
+//! The reference contracts driven through the generated bindings on an `InProcessTransport`.
+
+use alloy_primitives::{address, keccak256, Address, FixedBytes, U256};
+use alloy_sol_types::SolValue;
+use std::rc::Rc;
+use stylus_interfaces::{
+    erc1155, erc20, erc721, ierc165, transport::InProcessTransport, CallError,
+};
+use stylus_reference::{
+    erc1155 as ref1155, erc20 as ref20, erc721 as ref721, memory::MemoryContract,
+};
+
+const TOKEN: Address = address!("0000000000000000000000000000000000007041");
+const RECEIVER: Address = address!("00000000000000000000000000000000000000fe");
+const ALICE: Address = address!("00000000000000000000000000000000000000a1");
+const BOB: Address = address!("00000000000000000000000000000000000000b0");
+
+fn expect_error(result: Result<impl core::fmt::Debug, CallError>, signature: &str) {
+    match result {
+        Err(CallError::Revert { data, .. }) => assert_eq!(
+            data[..4],
+            keccak256(signature)[..4],
+            "expected {}",
+            signature
+        ),
+        other => panic!("expected {}, got {:?}", signature, other),
+    }
+}
+
+/// Registers a contract at `RECEIVER` that answers every call with `answer`.
+fn receiver(transport: &mut InProcessTransport, answer: Result<Vec<u8>, Vec<u8>>) {
+    transport.register(RECEIVER, move |_, _| answer.clone());
+}
+
+fn topic0(signature: &str) -> FixedBytes<32> {
+    keccak256(signature)
+}
+
+mod erc20_token {
+    use super::*;
+
+    fn deploy(transport: &mut InProcessTransport) -> Rc<MemoryContract> {
+        let contract =
+            MemoryContract::deploy(transport, TOKEN, |host, data| ref20::route(host, data));
+        transport.set_caller(ALICE);
+        ref20::mint(&mut contract.host(transport), ALICE, U256::from(100)).unwrap();
+        contract.take_logs();
+        contract
+    }
+
+    #[test]
+    fn transfer_moves_balance_and_emits() {
+        let mut transport = InProcessTransport::new();
+        let contract = deploy(&mut transport);
+        let token = erc20::Contract::with_transport(TOKEN, &transport);
+
+        assert_eq!(token.transfer__0xa9059cbb(BOB, U256::from(30)), Ok(true));
+        assert_eq!(token.balance_of__0x70a08231(ALICE), Ok(U256::from(70)));
+        assert_eq!(token.balance_of__0x70a08231(BOB), Ok(U256::from(30)));
+        let logs = contract.take_logs();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(
+            logs[0].topics()[0],
+            topic0("Transfer(address,address,uint256)")
+        );
+        assert_eq!(logs[0].topics()[2], BOB.into_word());
+    }
+
+    #[test]
+    fn failed_transfer_reverts_with_custom_error() {
+        let mut transport = InProcessTransport::new();
+        let contract = deploy(&mut transport);
+        let token = erc20::Contract::with_transport(TOKEN, &transport);
+
+        expect_error(
+            token.transfer__0xa9059cbb(BOB, U256::from(101)),
+            "ERC20InsufficientBalance(address,uint256,uint256)",
+        );
+        expect_error(
+            token.transfer__0xa9059cbb(Address::ZERO, U256::from(1)),
+            "ERC20InvalidReceiver(address)",
+        );
+        assert_eq!(token.balance_of__0x70a08231(ALICE), Ok(U256::from(100)));
+        assert!(contract.take_logs().is_empty());
+    }
+
+    #[test]
+    fn approve_records_allowance() {
+        let mut transport = InProcessTransport::new();
+        let contract = deploy(&mut transport);
+        let token = erc20::Contract::with_transport(TOKEN, &transport);
+
+        assert_eq!(token.approve__0x095ea7b3(BOB, U256::from(5)), Ok(true));
+        assert_eq!(token.allowance__0xdd62ed3e(ALICE, BOB), Ok(U256::from(5)));
+        assert_eq!(
+            contract.take_logs()[0].topics()[0],
+            topic0("Approval(address,address,uint256)")
+        );
+    }
+
+    #[test]
+    fn transfer_from_spends_allowance() {
+        let mut transport = InProcessTransport::new();
+        deploy(&mut transport);
+        let token = erc20::Contract::with_transport(TOKEN, &transport);
+        token.approve__0x095ea7b3(BOB, U256::from(40)).unwrap();
+
+        transport.set_caller(BOB);
+        assert_eq!(
+            token.transfer_from__0x23b872dd(ALICE, BOB, U256::from(30)),
+            Ok(true)
+        );
+        assert_eq!(token.balance_of__0x70a08231(BOB), Ok(U256::from(30)));
+        assert_eq!(token.allowance__0xdd62ed3e(ALICE, BOB), Ok(U256::from(10)));
+        expect_error(
+            token.transfer_from__0x23b872dd(ALICE, BOB, U256::from(11)),
+            "ERC20InsufficientAllowance(address,uint256,uint256)",
+        );
+        assert_eq!(token.total_supply__0x18160ddd(), Ok(U256::from(100)));
+    }
+
+    #[test]
+    fn value_is_rejected() {
+        let mut transport = InProcessTransport::new();
+        deploy(&mut transport);
+        let token = erc20::Contract::with_transport(TOKEN, &transport).with(erc20::CallOpts {
+            value: U256::from(1),
+            ..erc20::CallOpts::default()
+        });
+        assert_eq!(
+            token.transfer__0xa9059cbb(BOB, U256::from(1)),
+            Err(CallError::EmptyRevert)
+        );
+    }
+}
+
+mod erc721_token {
+    use super::*;
+
+    const ID: U256 = U256::from_limbs([7, 0, 0, 0]);
+
+    fn deploy(transport: &mut InProcessTransport) -> Rc<MemoryContract> {
+        let contract =
+            MemoryContract::deploy(transport, TOKEN, |host, data| ref721::route(host, data));
+        transport.set_caller(ALICE);
+        ref721::mint(&mut contract.host(transport), ALICE, ID).unwrap();
+        contract
+    }
+
+    #[test]
+    fn ownership_and_approvals() {
+        let mut transport = InProcessTransport::new();
+        deploy(&mut transport);
+        let nft = erc721::Contract::with_transport(TOKEN, &transport);
+
+        assert_eq!(nft.owner_of__0x6352211e(ID), Ok(ALICE));
+        assert_eq!(nft.balance_of__0x70a08231(ALICE), Ok(U256::from(1)));
+        expect_error(
+            nft.owner_of__0x6352211e(U256::from(8)),
+            "ERC721NonexistentToken(uint256)",
+        );
+
+        nft.approve__0x095ea7b3(BOB, ID).unwrap();
+        assert_eq!(nft.get_approved__0x081812fc(ID), Ok(BOB));
+        transport.set_caller(BOB);
+        nft.transfer_from__0x23b872dd(ALICE, BOB, ID).unwrap();
+        assert_eq!(nft.owner_of__0x6352211e(ID), Ok(BOB));
+        assert_eq!(nft.get_approved__0x081812fc(ID), Ok(Address::ZERO));
+
+        transport.set_caller(ALICE);
+        expect_error(
+            nft.transfer_from__0x23b872dd(BOB, ALICE, ID),
+            "ERC721InsufficientApproval(address,uint256)",
+        );
+        transport.set_caller(BOB);
+        nft.set_approval_for_all__0xa22cb465(ALICE, true).unwrap();
+        assert_eq!(nft.is_approved_for_all__0xe985e9c5(BOB, ALICE), Ok(true));
+        transport.set_caller(ALICE);
+        nft.transfer_from__0x23b872dd(BOB, ALICE, ID).unwrap();
+        assert_eq!(nft.owner_of__0x6352211e(ID), Ok(ALICE));
+    }
+
+    #[test]
+    fn safe_transfer_checks_receiver() {
+        let mut transport = InProcessTransport::new();
+        receiver(
+            &mut transport,
+            Ok(FixedBytes([0x15, 0x0b, 0x7a, 0x02]).abi_encode()),
+        );
+        deploy(&mut transport);
+        let nft = erc721::Contract::with_transport(TOKEN, &transport);
+
+        nft.safe_transfer_from__0xb88d4fde(ALICE, RECEIVER, ID, b"hi".to_vec())
+            .unwrap();
+        assert_eq!(nft.owner_of__0x6352211e(ID), Ok(RECEIVER));
+    }
+
+    #[test]
+    fn safe_transfer_to_non_receiver_reverts() {
+        let mut transport = InProcessTransport::new();
+        receiver(&mut transport, Ok(Vec::new()));
+        let contract = deploy(&mut transport);
+        contract.take_logs();
+        let nft = erc721::Contract::with_transport(TOKEN, &transport);
+
+        expect_error(
+            nft.safe_transfer_from__0x42842e0e(ALICE, RECEIVER, ID),
+            "ERC721InvalidReceiver(address)",
+        );
+        assert_eq!(nft.owner_of__0x6352211e(ID), Ok(ALICE));
+        assert!(contract.take_logs().is_empty());
+        // Accounts without code are always accepted.
+        nft.safe_transfer_from__0x42842e0e(ALICE, BOB, ID).unwrap();
+    }
+
+    #[test]
+    fn supports_erc165_and_erc721() {
+        let mut transport = InProcessTransport::new();
+        deploy(&mut transport);
+        let contract = ierc165::Contract::with_transport(TOKEN, &transport);
+        for (id, supported) in [
+            (ref721::INTERFACE_ID, true),
+            (stylus_reference::erc165::INTERFACE_ID, true),
+            (ref1155::INTERFACE_ID, false),
+            ([0xff; 4], false),
+        ] {
+            assert_eq!(
+                contract.supports_interface__0x01ffc9a7(FixedBytes(id)),
+                Ok(supported)
+            );
+        }
+    }
+}
+
+mod erc1155_token {
+    use super::*;
+
+    fn deploy(transport: &mut InProcessTransport) -> Rc<MemoryContract> {
+        let contract =
+            MemoryContract::deploy(transport, TOKEN, |host, data| ref1155::route(host, data));
+        transport.set_caller(ALICE);
+        let mut host = contract.host(transport);
+        ref1155::mint(&mut host, ALICE, U256::from(1), U256::from(10), &[]).unwrap();
+        ref1155::mint(&mut host, ALICE, U256::from(2), U256::from(20), &[]).unwrap();
+        contract.take_logs();
+        contract
+    }
+
+    #[test]
+    fn transfers_and_batch_balances() {
+        let mut transport = InProcessTransport::new();
+        receiver(
+            &mut transport,
+            Ok(FixedBytes([0xf2, 0x3a, 0x6e, 0x61]).abi_encode()),
+        );
+        let contract = deploy(&mut transport);
+        let token = erc1155::Contract::with_transport(TOKEN, &transport);
+
+        token
+            .safe_transfer_from__0xf242432a(ALICE, RECEIVER, U256::from(1), U256::from(4), vec![])
+            .unwrap();
+        token
+            .safe_batch_transfer_from__0x2eb2c2d6(
+                ALICE,
+                BOB,
+                vec![U256::from(1), U256::from(2)],
+                vec![U256::from(1), U256::from(5)],
+                vec![],
+            )
+            .unwrap();
+        assert_eq!(
+            token.balance_of_batch__0x4e1273f4(
+                vec![ALICE, RECEIVER, BOB, ALICE],
+                vec![U256::from(1), U256::from(1), U256::from(2), U256::from(2)],
+            ),
+            Ok(vec![
+                U256::from(5),
+                U256::from(4),
+                U256::from(5),
+                U256::from(15)
+            ])
+        );
+        let topics: Vec<_> = contract
+            .take_logs()
+            .iter()
+            .map(|log| log.topics()[0])
+            .collect();
+        assert_eq!(
+            topics,
+            [
+                topic0("TransferSingle(address,address,address,uint256,uint256)"),
+                topic0("TransferBatch(address,address,address,uint256[],uint256[])"),
+            ]
+        );
+    }
+
+    #[test]
+    fn batch_to_receiver_needs_batch_hook() {
+        let mut transport = InProcessTransport::new();
+        // Accepts single transfers only.
+        receiver(
+            &mut transport,
+            Ok(FixedBytes([0xf2, 0x3a, 0x6e, 0x61]).abi_encode()),
+        );
+        deploy(&mut transport);
+        let token = erc1155::Contract::with_transport(TOKEN, &transport);
+
+        expect_error(
+            token.safe_batch_transfer_from__0x2eb2c2d6(
+                ALICE,
+                RECEIVER,
+                vec![U256::from(1), U256::from(2)],
+                vec![U256::from(1), U256::from(1)],
+                vec![],
+            ),
+            "ERC1155InvalidReceiver(address)",
+        );
+        assert_eq!(
+            token.balance_of__0x00fdd58e(ALICE, U256::from(1)),
+            Ok(U256::from(10))
+        );
+    }
+
+    #[test]
+    fn operators_and_errors() {
+        let mut transport = InProcessTransport::new();
+        deploy(&mut transport);
+        let token = erc1155::Contract::with_transport(TOKEN, &transport);
+
+        transport.set_caller(BOB);
+        expect_error(
+            token.safe_transfer_from__0xf242432a(ALICE, BOB, U256::from(1), U256::from(1), vec![]),
+            "ERC1155MissingApprovalForAll(address,address)",
+        );
+        transport.set_caller(ALICE);
+        token.set_approval_for_all__0xa22cb465(BOB, true).unwrap();
+        assert_eq!(token.is_approved_for_all__0xe985e9c5(ALICE, BOB), Ok(true));
+        transport.set_caller(BOB);
+        expect_error(
+            token.safe_transfer_from__0xf242432a(ALICE, BOB, U256::from(1), U256::from(11), vec![]),
+            "ERC1155InsufficientBalance(address,uint256,uint256,uint256)",
+        );
+        token
+            .safe_transfer_from__0xf242432a(ALICE, BOB, U256::from(1), U256::from(10), vec![])
+            .unwrap();
+        expect_error(
+            token.balance_of_batch__0x4e1273f4(vec![ALICE], vec![]),
+            "ERC1155InvalidArrayLength(uint256,uint256)",
+        );
+    }
+}
@@ -0,0 +1,98 @@
+//! The reference contracts answer exactly the selectors of the ABIs in `abis/`.
+
+use alloy_json_abi::{Function, JsonAbi};
+use alloy_primitives::U256;
+use stylus_interfaces::transport::InProcessTransport;
+use stylus_reference::{
+    erc1155, erc165, erc20, erc721,
+    memory::{MemoryContract, MemoryHost},
+};
+
+type Route = fn(&mut MemoryHost<'_>, &[u8]) -> Result<Vec<u8>, Vec<u8>>;
+
+fn abi(name: &str) -> JsonAbi {
+    let path = format!("{}/../../abis/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+    let json = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    serde_json::from_str(&json).unwrap()
+}
+
+fn routes() -> [(&'static str, Route); 4] {
+    [
+        ("erc20", |host, calldata| erc20::route(host, calldata)),
+        ("erc721", |host, calldata| erc721::route(host, calldata)),
+        ("erc1155", |host, calldata| erc1155::route(host, calldata)),
+        ("ierc165", |host, calldata| {
+            erc165::route(host, calldata, &[])
+        }),
+    ]
+}
+
+/// Calls `route` on a fresh contract.
+fn call(route: Route, calldata: &[u8]) -> Result<Vec<u8>, Vec<u8>> {
+    let transport = InProcessTransport::new();
+    let contract = MemoryContract::default();
+    let result = route(&mut contract.host(&transport), calldata);
+    result
+}
+
+/// Calldata for `function` whose arguments are zero; dynamic ones are empty.
+fn zero_args(function: &Function) -> Vec<u8> {
+    let mut head = function.selector().to_vec();
+    let mut tail = Vec::new();
+    for input in &function.inputs {
+        let dynamic = input.ty.ends_with("[]") || input.ty == "bytes" || input.ty == "string";
+        if dynamic {
+            let offset = U256::from(32 * function.inputs.len() + tail.len());
+            head.extend_from_slice(&offset.to_be_bytes::<32>());
+            tail.extend_from_slice(&[0; 32]);
+        } else {
+            head.extend_from_slice(&[0; 32]);
+        }
+    }
+    [head, tail].concat()
+}
+
+#[test]
+fn every_abi_function_is_routed() {
+    for (name, route) in routes() {
+        for function in abi(name).functions() {
+            let calldata = zero_args(function);
+            // Only undecodable calldata and unknown selectors revert without data.
+            assert_ne!(
+                call(route, &calldata),
+                Err(Vec::new()),
+                "{}: {} is not routed",
+                name,
+                function.signature()
+            );
+        }
+    }
+}
+
+#[test]
+fn unknown_selectors_revert_without_data() {
+    for (name, route) in routes() {
+        assert_eq!(
+            call(route, &[0xde, 0xad, 0xbe, 0xef]),
+            Err(Vec::new()),
+            "{}",
+            name
+        );
+        assert_eq!(call(route, &[]), Err(Vec::new()), "{}", name);
+    }
+}
+
+#[test]
+fn interface_ids_match_abis() {
+    for (name, interface_id) in [
+        ("erc721", erc721::INTERFACE_ID),
+        ("erc1155", erc1155::INTERFACE_ID),
+        ("ierc165", erc165::INTERFACE_ID),
+    ] {
+        let id = abi(name).functions().fold([0; 4], |id, function| {
+            let selector = function.selector().0;
+            core::array::from_fn(|i| id[i] ^ selector[i])
+        });
+        assert_eq!(id, interface_id, "{}", name);
+    }
+}